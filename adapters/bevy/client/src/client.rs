@@ -96,6 +96,20 @@ impl<'w> Client<'w> {
     pub fn disable_replication(&mut self, entity: &Entity) {
         self.client.disable_replication(entity);
     }
+
+    // Authority
+
+    pub fn request_authority(&mut self, entity: &Entity) {
+        self.client.request_authority(entity);
+    }
+
+    pub fn release_authority(&mut self, entity: &Entity) {
+        self.client.release_authority(entity);
+    }
+
+    pub fn has_authority(&self, entity: &Entity) -> bool {
+        self.client.has_authority(entity)
+    }
 }
 
 impl<'w> EntityAndGlobalEntityConverter<Entity> for Client<'w> {
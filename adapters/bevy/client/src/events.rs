@@ -68,6 +68,15 @@ pub struct SpawnEntityEvent(pub Entity);
 // DespawnEntityEvent
 pub struct DespawnEntityEvent(pub Entity);
 
+// AuthorityGrantedEvent
+pub struct AuthorityGrantedEvent(pub Entity);
+
+// AuthorityDeniedEvent
+pub struct AuthorityDeniedEvent(pub Entity);
+
+// AuthorityRevokedEvent
+pub struct AuthorityRevokedEvent(pub Entity);
+
 // InsertComponentEvent
 pub struct InsertComponentEvents {
     inner: HashMap<ComponentKind, Vec<Entity>>,
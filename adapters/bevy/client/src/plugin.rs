@@ -8,9 +8,10 @@ use naia_client::{Client, ClientConfig};
 
 use super::{
     events::{
-        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent,
-        SpawnEntityEvent, UpdateComponentEvents,
+        AuthorityDeniedEvent, AuthorityGrantedEvent, AuthorityRevokedEvent, ClientTickEvent,
+        ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, InsertComponentEvents,
+        MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent, SpawnEntityEvent,
+        UpdateComponentEvents,
     },
     systems::before_receive_events,
 };
@@ -67,6 +68,9 @@ impl PluginType for Plugin {
             .add_event::<MessageEvents>()
             .add_event::<SpawnEntityEvent>()
             .add_event::<DespawnEntityEvent>()
+            .add_event::<AuthorityGrantedEvent>()
+            .add_event::<AuthorityDeniedEvent>()
+            .add_event::<AuthorityRevokedEvent>()
             .add_event::<InsertComponentEvents>()
             .add_event::<UpdateComponentEvents>()
             .add_event::<RemoveComponentEvents>()
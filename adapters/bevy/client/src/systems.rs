@@ -5,6 +5,7 @@ use bevy_ecs::{
     event::Events,
     world::{Mut, World},
 };
+use bevy_hierarchy::BuildWorldChildren;
 
 use naia_bevy_shared::{HostSyncEvent, WorldMutType, WorldProxyMut};
 use naia_client::Client;
@@ -13,16 +14,18 @@ use crate::ServerOwned;
 
 mod naia_events {
     pub use naia_client::{
-        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        RejectEvent, ServerTickEvent, SpawnEntityEvent,
+        AuthorityDeniedEvent, AuthorityGrantedEvent, AuthorityRevokedEvent, ClientTickEvent,
+        ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityParentRemovedEvent,
+        EntityParentSetEvent, ErrorEvent, RejectEvent, ServerTickEvent, SpawnEntityEvent,
     };
 }
 
 mod bevy_events {
     pub use crate::events::{
-        ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent,
-        SpawnEntityEvent, UpdateComponentEvents,
+        AuthorityDeniedEvent, AuthorityGrantedEvent, AuthorityRevokedEvent, ClientTickEvent,
+        ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, InsertComponentEvents,
+        MessageEvents, RejectEvent, RemoveComponentEvents, ServerTickEvent, SpawnEntityEvent,
+        UpdateComponentEvents,
     };
 }
 
@@ -150,6 +153,36 @@ pub fn before_receive_events(world: &mut World) {
                 }
             }
 
+            // Authority Granted Event
+            if events.has::<naia_events::AuthorityGrantedEvent>() {
+                let mut authority_granted_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::AuthorityGrantedEvent>>()
+                    .unwrap();
+                for entity in events.read::<naia_events::AuthorityGrantedEvent>() {
+                    authority_granted_event_writer.send(bevy_events::AuthorityGrantedEvent(entity));
+                }
+            }
+
+            // Authority Denied Event
+            if events.has::<naia_events::AuthorityDeniedEvent>() {
+                let mut authority_denied_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::AuthorityDeniedEvent>>()
+                    .unwrap();
+                for entity in events.read::<naia_events::AuthorityDeniedEvent>() {
+                    authority_denied_event_writer.send(bevy_events::AuthorityDeniedEvent(entity));
+                }
+            }
+
+            // Authority Revoked Event
+            if events.has::<naia_events::AuthorityRevokedEvent>() {
+                let mut authority_revoked_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::AuthorityRevokedEvent>>()
+                    .unwrap();
+                for entity in events.read::<naia_events::AuthorityRevokedEvent>() {
+                    authority_revoked_event_writer.send(bevy_events::AuthorityRevokedEvent(entity));
+                }
+            }
+
             // Insert Component Event
             if events.has_inserts() {
                 let inserts = events.take_inserts().unwrap();
@@ -178,6 +211,20 @@ pub fn before_receive_events(world: &mut World) {
 
                 remove_component_event_writer.send(bevy_events::RemoveComponentEvents::new(removes));
             }
+
+            // Entity Parent Set Event
+            for (child, parent) in events.read::<naia_events::EntityParentSetEvent>() {
+                if world.get_entity(child).is_some() && world.get_entity(parent).is_some() {
+                    world.entity_mut(child).set_parent(parent);
+                }
+            }
+
+            // Entity Parent Removed Event
+            for child in events.read::<naia_events::EntityParentRemovedEvent>() {
+                if let Some(mut child_mut) = world.get_entity_mut(child) {
+                    child_mut.remove_parent();
+                }
+            }
         }
     });
 }
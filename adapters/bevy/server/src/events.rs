@@ -93,6 +93,40 @@ pub struct SpawnEntityEvent(pub UserKey, pub Entity);
 // DespawnEntityEvent
 pub struct DespawnEntityEvent(pub UserKey, pub Entity);
 
+// EntityScopeEnterEvent
+pub struct EntityScopeEnterEvent(pub UserKey, pub Entity);
+
+// EntityScopeExitEvent
+pub struct EntityScopeExitEvent(pub UserKey, pub Entity);
+
+// AuthorityRequestEvent
+pub struct AuthorityRequestEvent(pub UserKey, pub Entity);
+
+// AuthorityReleaseEvent
+pub struct AuthorityReleaseEvent(pub UserKey, pub Entity);
+
+// EntitySpawnAckedEvent
+pub struct EntitySpawnAckedEvent(pub UserKey, pub Entity);
+
+// ComponentInsertAckedEvents
+pub struct ComponentInsertAckedEvents {
+    inner: HashMap<ComponentKind, Vec<(UserKey, Entity)>>,
+}
+
+impl ComponentInsertAckedEvents {
+    pub fn new(inner: HashMap<ComponentKind, Vec<(UserKey, Entity)>>) -> Self {
+        Self { inner }
+    }
+    pub fn read<C: Replicate>(&self) -> Vec<(UserKey, Entity)> {
+        let component_kind = ComponentKind::of::<C>();
+        if let Some(components) = self.inner.get(&component_kind) {
+            return components.clone();
+        }
+
+        return Vec::new();
+    }
+}
+
 // InsertComponentEvent
 pub struct InsertComponentEvents {
     inner: HashMap<ComponentKind, Vec<(UserKey, Entity)>>,
@@ -3,16 +3,17 @@ use std::{ops::DerefMut, sync::Mutex};
 use bevy_app::{App, Plugin as PluginType};
 use bevy_ecs::{entity::Entity, schedule::IntoSystemConfig};
 
-use naia_bevy_shared::{BeforeReceiveEvents, Protocol, SharedPlugin};
+use naia_bevy_shared::{BeforeReceiveEvents, HostSyncChangeTracking, Protocol, SharedPlugin};
 use naia_server::{Server, ServerConfig};
 
 use super::{
     events::{
-        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent,
-        UpdateComponentEvents,
+        AuthEvents, AuthorityReleaseEvent, AuthorityRequestEvent, ComponentInsertAckedEvents,
+        ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityScopeEnterEvent,
+        EntityScopeExitEvent, EntitySpawnAckedEvent, ErrorEvent, InsertComponentEvents,
+        MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent, UpdateComponentEvents,
     },
-    systems::before_receive_events,
+    systems::{before_receive_events, sync_hierarchy},
 };
 
 struct PluginConfig {
@@ -50,7 +51,9 @@ impl PluginType for Plugin {
         world_data.add_systems(app);
         app.insert_resource(world_data);
 
-        let server = Server::<Entity>::new(config.server_config, config.protocol.into());
+        let protocol = config.protocol.into();
+        let entity_hierarchy = protocol.entity_hierarchy;
+        let server = Server::<Entity>::new(config.server_config, protocol);
 
         app
             // SHARED PLUGIN //
@@ -66,10 +69,20 @@ impl PluginType for Plugin {
             .add_event::<AuthEvents>()
             .add_event::<SpawnEntityEvent>()
             .add_event::<DespawnEntityEvent>()
+            .add_event::<EntityScopeEnterEvent>()
+            .add_event::<EntityScopeExitEvent>()
+            .add_event::<AuthorityRequestEvent>()
+            .add_event::<AuthorityReleaseEvent>()
+            .add_event::<EntitySpawnAckedEvent>()
+            .add_event::<ComponentInsertAckedEvents>()
             .add_event::<InsertComponentEvents>()
             .add_event::<UpdateComponentEvents>()
             .add_event::<RemoveComponentEvents>()
             // SYSTEMS //
             .add_system(before_receive_events.in_set(BeforeReceiveEvents));
+
+        if entity_hierarchy {
+            app.add_system(sync_hierarchy.in_set(HostSyncChangeTracking));
+        }
     }
 }
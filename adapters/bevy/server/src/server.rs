@@ -57,6 +57,22 @@ impl<'w> Server<'w> {
         self.server.broadcast_message::<C, M>(message);
     }
 
+    pub fn broadcast_except<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+        self.server.broadcast_except::<C, M>(user_key, message);
+    }
+
+    pub fn broadcast_to_scope_of<C: Channel, M: Message>(&mut self, entity: &Entity, message: &M) {
+        self.server.broadcast_to_scope_of::<C, M>(entity, message);
+    }
+
+    pub fn broadcast_filtered<C: Channel, M: Message, F: FnMut(&UserKey) -> bool>(
+        &mut self,
+        message: &M,
+        filter: F,
+    ) {
+        self.server.broadcast_filtered::<C, M, F>(message, filter);
+    }
+
     pub fn receive_tick_buffer_messages(&mut self, tick: &Tick) -> TickBufferMessages {
         self.server.receive_tick_buffer_messages(tick)
     }
@@ -138,6 +154,24 @@ impl<'w> Server<'w> {
     pub fn disable_replication(&mut self, entity: &Entity) {
         self.server.disable_replication(entity);
     }
+
+    // Authority
+
+    pub fn grant_authority(&mut self, user_key: &UserKey, entity: &Entity) {
+        self.server.grant_authority(user_key, entity);
+    }
+
+    pub fn deny_authority(&mut self, user_key: &UserKey, entity: &Entity) {
+        self.server.deny_authority(user_key, entity);
+    }
+
+    pub fn revoke_authority(&mut self, entity: &Entity) {
+        self.server.revoke_authority(entity);
+    }
+
+    pub fn entity_authority(&self, entity: &Entity) -> Option<UserKey> {
+        self.server.entity_authority(entity)
+    }
 }
 
 impl<'w> EntityAndGlobalEntityConverter<Entity> for Server<'w> {
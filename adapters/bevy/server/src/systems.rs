@@ -3,26 +3,33 @@ use std::ops::DerefMut;
 use bevy_ecs::{
     entity::Entity,
     event::Events,
+    query::{Changed, With},
+    removal_detection::RemovedComponents,
+    system::{Query, ResMut},
     world::{Mut, World},
 };
+use bevy_hierarchy::Parent;
 
-use naia_bevy_shared::{HostSyncEvent, WorldMutType, WorldProxy, WorldProxyMut};
+use naia_bevy_shared::{HostOwned, HostSyncEvent, WorldMutType, WorldProxy, WorldProxyMut};
 use naia_server::{EntityOwner, Server};
 
 use crate::ClientOwned;
 
 mod naia_events {
     pub use naia_server::{
-        ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, InsertComponentEvent,
-        RemoveComponentEvent, SpawnEntityEvent, TickEvent, UpdateComponentEvent,
+        AuthorityReleaseEvent, AuthorityRequestEvent, ConnectEvent, DespawnEntityEvent,
+        DisconnectEvent, EntityScopeEnterEvent, EntityScopeExitEvent, EntitySpawnAckedEvent,
+        ErrorEvent, InsertComponentEvent, RemoveComponentEvent, SpawnEntityEvent, TickEvent,
+        UpdateComponentEvent,
     };
 }
 
 mod bevy_events {
     pub use crate::events::{
-        AuthEvents, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent,
-        InsertComponentEvents, MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent,
-        UpdateComponentEvents,
+        AuthEvents, AuthorityReleaseEvent, AuthorityRequestEvent, ComponentInsertAckedEvents,
+        ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityScopeEnterEvent,
+        EntityScopeExitEvent, EntitySpawnAckedEvent, ErrorEvent, InsertComponentEvents,
+        MessageEvents, RemoveComponentEvents, SpawnEntityEvent, TickEvent, UpdateComponentEvents,
     };
 }
 
@@ -145,6 +152,65 @@ pub fn before_receive_events(world: &mut World) {
                 }
             }
 
+            // Entity Scope Enter Event
+            if events.has::<naia_events::EntityScopeEnterEvent>() {
+                let mut scope_enter_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityScopeEnterEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::EntityScopeEnterEvent>() {
+                    scope_enter_event_writer.send(bevy_events::EntityScopeEnterEvent(user_key, entity));
+                }
+            }
+
+            // Entity Scope Exit Event
+            if events.has::<naia_events::EntityScopeExitEvent>() {
+                let mut scope_exit_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntityScopeExitEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::EntityScopeExitEvent>() {
+                    scope_exit_event_writer.send(bevy_events::EntityScopeExitEvent(user_key, entity));
+                }
+            }
+
+            // Authority Request Event
+            if events.has::<naia_events::AuthorityRequestEvent>() {
+                let mut authority_request_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::AuthorityRequestEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::AuthorityRequestEvent>() {
+                    authority_request_event_writer.send(bevy_events::AuthorityRequestEvent(user_key, entity));
+                }
+            }
+
+            // Authority Release Event
+            if events.has::<naia_events::AuthorityReleaseEvent>() {
+                let mut authority_release_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::AuthorityReleaseEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::AuthorityReleaseEvent>() {
+                    authority_release_event_writer.send(bevy_events::AuthorityReleaseEvent(user_key, entity));
+                }
+            }
+
+            // Entity Spawn Acked Event
+            if events.has::<naia_events::EntitySpawnAckedEvent>() {
+                let mut spawn_acked_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::EntitySpawnAckedEvent>>()
+                    .unwrap();
+                for (user_key, entity) in events.read::<naia_events::EntitySpawnAckedEvent>() {
+                    spawn_acked_event_writer.send(bevy_events::EntitySpawnAckedEvent(user_key, entity));
+                }
+            }
+
+            // Component Insert Acked Event
+            if events.has_insert_acks() {
+                let insert_acks = events.take_insert_acks().unwrap();
+                let mut insert_acked_event_writer = world
+                    .get_resource_mut::<Events<bevy_events::ComponentInsertAckedEvents>>()
+                    .unwrap();
+                insert_acked_event_writer.send(bevy_events::ComponentInsertAckedEvents::new(insert_acks));
+            }
+
             // Insert Component Event
             if events.has_inserts() {
                 let inserts = events.take_inserts().unwrap();
@@ -180,3 +246,26 @@ pub fn before_receive_events(world: &mut World) {
         }
     });
 }
+
+/// Mirrors changes to the Bevy hierarchy of Server-owned Entities into the
+/// Server, which replicates them to Clients
+pub fn sync_hierarchy(
+    mut server: ResMut<Server<Entity>>,
+    parents_q: Query<(Entity, &Parent), (Changed<Parent>, With<HostOwned>)>,
+    mut removed_parents: RemovedComponents<Parent>,
+) {
+    for child in removed_parents.iter() {
+        if server.entity_parent(&child).is_some() {
+            server.remove_entity_parent(&child);
+        }
+    }
+    for (child, parent) in parents_q.iter() {
+        let parent = parent.get();
+        if server.entity_owner(&child) != EntityOwner::Server
+            || server.entity_owner(&parent) != EntityOwner::Server
+        {
+            continue;
+        }
+        server.set_entity_parent(&child, &parent);
+    }
+}
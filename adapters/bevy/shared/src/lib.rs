@@ -1,13 +1,14 @@
 pub use naia_shared::{
-    sequence_greater_than, BitReader, BitWrite, BitWriter, Channel, ChannelDirection, ChannelKind,
-    ChannelMode, ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate,
+    sequence_greater_than, BitReader, BitWrite, BitWriter, BitsEncoding, Channel, ChannelDirection,
+    ChannelKind, ChannelMode, ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate,
     ConstBitLength, DiffMask, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    EntityProperty, GlobalEntity, LinkConditionerConfig, LocalEntity,
-    LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
+    EntityProperty, FieldEncoding, GlobalEntity, LinkConditionerConfig, LinkConditionerSchedule,
+    LocalEntity, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
     MessageBevy as Message, MessageBuilder, MessageContainer, MessageKind, MessageKinds, Named,
-    OwnedBitReader, Property, PropertyMutate, PropertyMutator, Random, ReliableSettings,
+    NestedProperty, NestedReplicateBevy as NestedReplicate, OwnedBitReader, Property,
+    PropertyMutate, PropertyMutator, QuantizedFloat, QuantizedQuaternion, Random, ReliableSettings,
     ReplicaDynMut, ReplicaDynRef, ReplicateBevy as Replicate, ReplicateBuilder, SerdeBevy as Serde,
-    SerdeErr, Tick, TickBufferSettings, UnsignedInteger, WorldMutType, WorldRefType,
+    SerdeErr, Tick, TickBufferSettings, UnitVector, UnsignedInteger, WorldMutType, WorldRefType,
     MTU_SIZE_BYTES,
 };
 
@@ -28,6 +29,6 @@ pub use components::HostOwned;
 pub use plugin::SharedPlugin;
 pub use protocol::Protocol;
 pub use protocol_plugin::ProtocolPlugin;
-pub use system_set::{BeforeReceiveEvents, ReceiveEvents};
+pub use system_set::{BeforeReceiveEvents, HostSyncChangeTracking, ReceiveEvents};
 pub use world_data::WorldData;
 pub use world_proxy::{WorldMut, WorldProxy, WorldProxyMut, WorldRef};
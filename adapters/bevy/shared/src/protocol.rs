@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use naia_shared::{
     Channel, ChannelDirection, ChannelMode, ComponentKind, CompressionConfig,
-    LinkConditionerConfig, Message, Protocol as InnerProtocol, Replicate,
+    LinkConditionerConfig, LinkConditionerSchedule, Message, Protocol as InnerProtocol, Replicate,
 };
 
 use crate::{ProtocolPlugin, WorldData};
@@ -41,11 +41,21 @@ impl Protocol {
         self
     }
 
+    pub fn link_condition_schedule(&mut self, schedule: LinkConditionerSchedule) -> &mut Self {
+        self.inner.link_condition_schedule(schedule);
+        self
+    }
+
     pub fn enable_client_authoritative_entities(&mut self) -> &mut Self {
         self.inner.enable_client_authoritative_entities();
         self
     }
 
+    pub fn enable_entity_hierarchy(&mut self) -> &mut Self {
+        self.inner.enable_entity_hierarchy();
+        self
+    }
+
     pub fn rtc_endpoint(&mut self, path: String) -> &mut Self {
         self.inner.rtc_endpoint(path);
         self
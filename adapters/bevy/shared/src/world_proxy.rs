@@ -2,6 +2,7 @@ use bevy_ecs::{
     entity::Entity,
     world::{Mut, World},
 };
+use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
 use std::any::TypeId;
 
 use naia_shared::{
@@ -157,6 +158,21 @@ impl<'w> WorldMutType<Entity> for WorldMut<'w> {
         let mut world_data = world_data_unchecked_mut(self.world);
         world_data.despawn_entity(entity);
 
+        // detach from the hierarchy first, so no dangling references are left behind
+        if let Some(parent) = self.world.get::<Parent>(*entity).map(|parent| parent.get()) {
+            if self.world.get_entity(parent).is_some() {
+                self.world.entity_mut(*entity).remove_parent();
+            }
+        }
+        if let Some(children) = self.world.get::<Children>(*entity) {
+            let children: Vec<Entity> = children.iter().copied().collect();
+            for child in children {
+                if let Some(mut child_mut) = self.world.get_entity_mut(child) {
+                    child_mut.remove::<Parent>();
+                }
+            }
+        }
+
         self.world.despawn(*entity);
     }
 
@@ -1,12 +1,13 @@
 pub use naia_shared::{
-    BitReader, BitWrite, BitWriter, Channel, ChannelDirection, ChannelMode, ComponentFieldUpdate,
-    ComponentKind, ComponentKinds, ComponentUpdate, ConstBitLength, DiffMask, EntityProperty,
-    GlobalEntity, LinkConditionerConfig, LocalEntity, LocalEntityAndGlobalEntityConverter,
-    LocalEntityAndGlobalEntityConverterMut, MessageBuilder, MessageContainer,
-    MessageHecs as Message, MessageKind, MessageKinds, Named, OwnedBitReader, Property,
-    PropertyMutate, PropertyMutator, Random, ReliableSettings, ReplicaDynMut, ReplicaDynRef,
-    ReplicateBuilder, ReplicateHecs as Replicate, SerdeErr, SerdeHecs as Serde, TickBufferSettings,
-    UnsignedInteger,
+    BitReader, BitWrite, BitWriter, BitsEncoding, Channel, ChannelDirection, ChannelMode,
+    ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate, ConstBitLength, DiffMask,
+    EntityProperty, FieldEncoding, GlobalEntity, LinkConditionerConfig, LinkConditionerSchedule,
+    LocalEntity, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
+    MessageBuilder, MessageContainer, MessageHecs as Message, MessageKind, MessageKinds, Named,
+    NestedProperty, NestedReplicateHecs as NestedReplicate, OwnedBitReader, Property,
+    PropertyMutate, PropertyMutator, QuantizedFloat, QuantizedQuaternion, Random, ReliableSettings,
+    ReplicaDynMut, ReplicaDynRef, ReplicateBuilder, ReplicateHecs as Replicate, SerdeErr,
+    SerdeHecs as Serde, TickBufferSettings, UnitVector, UnsignedInteger, VarIntEncoding,
 };
 
 mod component_access;
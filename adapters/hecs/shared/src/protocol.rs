@@ -4,8 +4,8 @@ use hecs::World;
 
 use naia_shared::{
     Channel, ChannelDirection, ChannelMode, ComponentKind, CompressionConfig,
-    LinkConditionerConfig, Message, Protocol as InnerProtocol, ProtocolPlugin, Replicate,
-    SocketConfig,
+    LinkConditionerConfig, LinkConditionerSchedule, Message, Protocol as InnerProtocol,
+    ProtocolPlugin, Replicate, SocketConfig,
 };
 
 use crate::{WorldData, WorldWrapper};
@@ -53,6 +53,11 @@ impl Protocol {
         self
     }
 
+    pub fn link_condition_schedule(&mut self, schedule: LinkConditionerSchedule) -> &mut Self {
+        self.inner.link_condition_schedule(schedule);
+        self
+    }
+
     pub fn rtc_endpoint(&mut self, path: String) -> &mut Self {
         self.inner.rtc_endpoint(path);
         self
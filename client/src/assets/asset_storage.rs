@@ -0,0 +1,12 @@
+use naia_shared::AssetHash;
+
+/// Local storage for Assets offered by the Server, keyed by content hash.
+/// Assets found here are not downloaded again.
+pub trait AssetStorage: Send + Sync {
+    /// Returns whether the Asset with the given hash is stored
+    fn has(&self, hash: &AssetHash) -> bool;
+    /// Retrieves the bytes of the Asset with the given hash, if stored
+    fn load(&self, hash: &AssetHash) -> Option<Vec<u8>>;
+    /// Stores the bytes of the Asset with the given hash
+    fn store(&mut self, hash: &AssetHash, bytes: &[u8]);
+}
@@ -0,0 +1,53 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use log::warn;
+
+use naia_shared::AssetHash;
+
+use super::asset_storage::AssetStorage;
+
+/// Persists Assets as files inside a local directory, one file per Asset
+/// named after its content hash
+pub struct DirectoryAssetStorage {
+    directory: PathBuf,
+}
+
+impl DirectoryAssetStorage {
+    /// Uses the given directory as the Asset cache, creating it if necessary
+    pub fn new<P: AsRef<Path>>(directory: P) -> io::Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+        Ok(Self { directory })
+    }
+
+    fn asset_path(&self, hash: &AssetHash) -> PathBuf {
+        self.directory.join(hash.to_hex())
+    }
+}
+
+impl AssetStorage for DirectoryAssetStorage {
+    fn has(&self, hash: &AssetHash) -> bool {
+        self.asset_path(hash).is_file()
+    }
+
+    fn load(&self, hash: &AssetHash) -> Option<Vec<u8>> {
+        fs::read(self.asset_path(hash)).ok()
+    }
+
+    fn store(&mut self, hash: &AssetHash, bytes: &[u8]) {
+        // write to a temporary file first, so that an interrupted write never
+        // leaves a truncated Asset behind under its final name
+        let final_path = self.asset_path(hash);
+        let temp_path = final_path.with_extension("part");
+        let result = fs::write(&temp_path, bytes).and_then(|_| fs::rename(&temp_path, &final_path));
+        if let Err(error) = result {
+            warn!(
+                "Client Error: Cannot store Asset {} in cache: {}",
+                hash, error
+            );
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use js_sys::Uint8Array;
+use log::warn;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{IdbCursorWithValue, IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode};
+
+use naia_shared::AssetHash;
+
+use super::asset_storage::AssetStorage;
+
+const OBJECT_STORE_NAME: &str = "assets";
+
+struct IndexedDbInner {
+    database: Option<IdbDatabase>,
+    assets: HashMap<AssetHash, Vec<u8>>,
+    pending_writes: Vec<AssetHash>,
+}
+
+impl IndexedDbInner {
+    fn write(&self, hash: &AssetHash) {
+        let Some(database) = &self.database else {
+            return;
+        };
+        let Some(bytes) = self.assets.get(hash) else {
+            return;
+        };
+        let result = database
+            .transaction_with_str_and_mode(OBJECT_STORE_NAME, IdbTransactionMode::Readwrite)
+            .and_then(|transaction| transaction.object_store(OBJECT_STORE_NAME))
+            .and_then(|store| {
+                let value = Uint8Array::from(bytes.as_slice());
+                store.put_with_key(&value, &JsValue::from_str(&hash.to_hex()))
+            });
+        if result.is_err() {
+            warn!("Client Error: Cannot store Asset {} in IndexedDB", hash);
+        }
+    }
+}
+
+/// Persists Assets in the browser's IndexedDB.
+///
+/// IndexedDB is asynchronous, so all stored Assets are loaded into memory once
+/// the database opens. Assets offered before then are downloaded again.
+pub struct IndexedDbAssetStorage {
+    inner: Rc<RefCell<IndexedDbInner>>,
+}
+
+impl IndexedDbAssetStorage {
+    /// Opens (or creates) the IndexedDB database with the given name
+    pub fn new(database_name: &str) -> Self {
+        let inner = Rc::new(RefCell::new(IndexedDbInner {
+            database: None,
+            assets: HashMap::new(),
+            pending_writes: Vec::new(),
+        }));
+
+        let open_request = web_sys::window()
+            .and_then(|window| window.indexed_db().ok().flatten())
+            .and_then(|factory| factory.open_with_u32(database_name, 1).ok());
+        let Some(open_request) = open_request else {
+            warn!("Client Error: IndexedDB is unavailable, Assets will only be cached in memory");
+            return Self { inner };
+        };
+
+        Self::on_upgrade_needed(&open_request);
+        Self::on_open(&open_request, inner.clone());
+
+        Self { inner }
+    }
+
+    fn on_upgrade_needed(open_request: &IdbOpenDbRequest) {
+        let request = open_request.clone();
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            let Ok(result) = request.result() else {
+                return;
+            };
+            let database: IdbDatabase = result.unchecked_into();
+            if database.create_object_store(OBJECT_STORE_NAME).is_err() {
+                warn!("Client Error: Cannot create IndexedDB object store for Assets");
+            }
+        });
+        open_request.set_onupgradeneeded(Some(callback.as_ref().unchecked_ref()));
+        callback.forget();
+    }
+
+    fn on_open(open_request: &IdbOpenDbRequest, inner: Rc<RefCell<IndexedDbInner>>) {
+        let request = open_request.clone();
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            let Ok(result) = request.result() else {
+                return;
+            };
+            let database: IdbDatabase = result.unchecked_into();
+
+            let cursor_request = database
+                .transaction_with_str(OBJECT_STORE_NAME)
+                .and_then(|transaction| transaction.object_store(OBJECT_STORE_NAME))
+                .and_then(|store| store.open_cursor());
+            match cursor_request {
+                Ok(cursor_request) => Self::load_all(&cursor_request, inner.clone()),
+                Err(_) => warn!("Client Error: Cannot read Assets from IndexedDB"),
+            }
+
+            let mut inner_ref = inner.borrow_mut();
+            inner_ref.database = Some(database);
+            for hash in std::mem::take(&mut inner_ref.pending_writes) {
+                inner_ref.write(&hash);
+            }
+        });
+        open_request.set_onsuccess(Some(callback.as_ref().unchecked_ref()));
+        callback.forget();
+    }
+
+    fn load_all(cursor_request: &IdbRequest, inner: Rc<RefCell<IndexedDbInner>>) {
+        let request = cursor_request.clone();
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            let Ok(result) = request.result() else {
+                return;
+            };
+            if result.is_null() || result.is_undefined() {
+                // finished iterating
+                return;
+            }
+            let cursor: IdbCursorWithValue = result.unchecked_into();
+
+            let hash = cursor
+                .key()
+                .ok()
+                .and_then(|key| key.as_string())
+                .and_then(|key| AssetHash::from_hex(&key));
+            let value = cursor.value().ok();
+            if let (Some(hash), Some(value)) = (hash, value) {
+                let bytes = Uint8Array::new(&value).to_vec();
+                inner.borrow_mut().assets.entry(hash).or_insert(bytes);
+            }
+
+            if cursor.continue_().is_err() {
+                warn!("Client Error: Cannot read Assets from IndexedDB");
+            }
+        });
+        cursor_request.set_onsuccess(Some(callback.as_ref().unchecked_ref()));
+        callback.forget();
+    }
+}
+
+impl AssetStorage for IndexedDbAssetStorage {
+    fn has(&self, hash: &AssetHash) -> bool {
+        self.inner.borrow().assets.contains_key(hash)
+    }
+
+    fn load(&self, hash: &AssetHash) -> Option<Vec<u8>> {
+        self.inner.borrow().assets.get(hash).cloned()
+    }
+
+    fn store(&mut self, hash: &AssetHash, bytes: &[u8]) {
+        let mut inner = self.inner.borrow_mut();
+        inner.assets.insert(*hash, bytes.to_vec());
+        if inner.database.is_some() {
+            inner.write(hash);
+        } else {
+            inner.pending_writes.push(*hash);
+        }
+    }
+}
+
+unsafe impl Send for IndexedDbAssetStorage {}
+unsafe impl Sync for IndexedDbAssetStorage {}
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use naia_shared::AssetHash;
+
+use super::asset_storage::AssetStorage;
+
+/// Keeps Assets in memory only, they will be downloaded again every session.
+/// This is the default storage used by the Client.
+#[derive(Default)]
+pub struct MemoryAssetStorage {
+    assets: HashMap<AssetHash, Vec<u8>>,
+}
+
+impl MemoryAssetStorage {
+    pub fn new() -> Self {
+        Self {
+            assets: HashMap::new(),
+        }
+    }
+}
+
+impl AssetStorage for MemoryAssetStorage {
+    fn has(&self, hash: &AssetHash) -> bool {
+        self.assets.contains_key(hash)
+    }
+
+    fn load(&self, hash: &AssetHash) -> Option<Vec<u8>> {
+        self.assets.get(hash).cloned()
+    }
+
+    fn store(&mut self, hash: &AssetHash, bytes: &[u8]) {
+        self.assets.insert(*hash, bytes.to_vec());
+    }
+}
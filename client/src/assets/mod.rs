@@ -0,0 +1,16 @@
+mod asset_storage;
+mod memory_storage;
+
+pub use asset_storage::AssetStorage;
+pub use memory_storage::MemoryAssetStorage;
+
+cfg_if! {
+    if #[cfg(all(target_arch = "wasm32", feature = "wbindgen"))] {
+        mod indexed_db_storage;
+        pub use indexed_db_storage::IndexedDbAssetStorage;
+    }
+    else if #[cfg(not(target_arch = "wasm32"))] {
+        mod directory_storage;
+        pub use directory_storage::DirectoryAssetStorage;
+    }
+}
@@ -1,4 +1,4 @@
-use std::{hash::Hash, net::SocketAddr};
+use std::{collections::HashSet, hash::Hash, io::Write, net::SocketAddr};
 
 use log::warn;
 
@@ -13,7 +13,15 @@ pub use naia_shared::{
     Timestamp, WorldMutType, WorldRefType,
 };
 
+use naia_shared::{
+    AssetChannel, AssetData, AssetHash, AssetOffer, AssetRequest, CaptureWriter,
+    ComponentCorrection, EntityAuthorityAction, EntityAuthorityChannel, EntityAuthorityMessage,
+    EntityAuthorityUpdate, EntityHierarchyChannel, EntityParentRemoved, EntityParentSet,
+    MessageKind, SerdeErr, ValidationChannel,
+};
+
 use crate::{
+    assets::{AssetStorage, MemoryAssetStorage},
     connection::{
         base_time_manager::BaseTimeManager,
         connection::Connection,
@@ -22,7 +30,8 @@ use crate::{
     },
     transport::Socket,
     world::{
-        entity_mut::EntityMut, entity_owner::EntityOwner, global_world_manager::GlobalWorldManager,
+        authority_manager::AuthorityManager, entity_mut::EntityMut, entity_owner::EntityOwner,
+        global_world_manager::GlobalWorldManager,
     },
 };
 
@@ -42,6 +51,10 @@ pub struct Client<E: Copy + Eq + Hash + Send + Sync> {
     manual_disconnect: bool,
     // World
     global_world_manager: GlobalWorldManager<E>,
+    authority_manager: AuthorityManager<E>,
+    // Assets
+    asset_storage: Box<dyn AssetStorage>,
+    requested_assets: HashSet<AssetHash>,
     // Events
     incoming_events: Events<E>,
 }
@@ -74,6 +87,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             manual_disconnect: false,
             // World
             global_world_manager: GlobalWorldManager::new(),
+            authority_manager: AuthorityManager::new(),
+            // Assets
+            asset_storage: Box::new(MemoryAssetStorage::new()),
+            requested_assets: HashSet::new(),
             // Events
             incoming_events: Events::new(),
         }
@@ -165,6 +182,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                     warn!("Error reading from buffered packet!");
                 }
 
+                // updates from the Server to Entities this Client has authority over
+                // should not be sent back to the Server
+                let diff_masks = self.authority_manager.copy_diff_masks();
+
                 // receive packets, process into events
                 connection.process_packets(
                     &mut self.global_world_manager,
@@ -173,6 +194,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                     &mut self.incoming_events,
                 );
 
+                self.authority_manager.restore_diff_masks(diff_masks);
+
                 let mut index_tick = prev_receiving_tick.wrapping_add(1);
                 loop {
                     self.incoming_events.push_server_tick(index_tick);
@@ -185,6 +208,15 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
             }
 
             if let Some((prev_sending_tick, current_sending_tick)) = sending_tick_happened {
+                // send changes to Entities this Client has authority over
+                Self::send_authority_updates(
+                    &self.protocol,
+                    &self.global_world_manager,
+                    &self.authority_manager,
+                    connection,
+                    &world,
+                );
+
                 // send outgoing packets
                 let now = Instant::now();
 
@@ -212,6 +244,23 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
                 .send(&self.protocol.message_kinds, &mut self.io);
         }
 
+        if self.protocol.asset_cache && self.is_connected() {
+            self.receive_asset_messages();
+        }
+
+        if self.protocol.client_authoritative_entities && self.is_connected() {
+            self.receive_component_corrections(&mut world);
+        }
+
+        if self.protocol.entity_authority && self.is_connected() {
+            self.receive_authority_messages();
+            self.sync_authority_components(&mut world);
+        }
+
+        if self.protocol.entity_hierarchy && self.is_connected() {
+            self.receive_hierarchy_messages();
+        }
+
         std::mem::take(&mut self.incoming_events)
     }
 
@@ -355,6 +404,58 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         return EntityOwner::Local;
     }
 
+    // Authority
+
+    /// Asks the Server for authority over a Server Entity. The response arrives
+    /// as an `AuthorityGrantedEvent` or an `AuthorityDeniedEvent`
+    pub fn request_authority(&mut self, entity: &E) {
+        self.check_entity_authority_allowed();
+
+        if self.entity_owner(entity) != EntityOwner::Server {
+            panic!("Can only request authority over Entities owned by the Server");
+        }
+        if !self.authority_manager.request(entity) {
+            return;
+        }
+        self.send_authority_message(entity, EntityAuthorityAction::Request);
+    }
+
+    /// Gives up authority over an Entity. Its Components stay in the World, and
+    /// will again be updated by the Server
+    pub fn release_authority(&mut self, entity: &E) {
+        self.check_entity_authority_allowed();
+
+        if !self.authority_manager.has_authority(entity) {
+            return;
+        }
+        self.remove_authority(entity);
+        self.send_authority_message(entity, EntityAuthorityAction::Release);
+    }
+
+    /// Returns whether or not this Client currently holds authority over an
+    /// Entity
+    pub fn has_authority(&self, entity: &E) -> bool {
+        self.authority_manager.has_authority(entity)
+    }
+
+    // Assets
+
+    /// Sets the storage used to cache Assets offered by the Server. Assets
+    /// found in this storage will not be downloaded again.
+    pub fn set_asset_storage<S: AssetStorage + 'static>(&mut self, storage: S) {
+        self.asset_storage = Box::new(storage);
+    }
+
+    /// Retrieves the bytes of the cached Asset with the given content hash
+    pub fn cached_asset(&self, hash: &AssetHash) -> Option<Vec<u8>> {
+        self.asset_storage.load(hash)
+    }
+
+    /// Returns whether or not the Asset with the given content hash is cached
+    pub fn has_cached_asset(&self, hash: &AssetHash) -> bool {
+        self.asset_storage.has(hash)
+    }
+
     // Connection
 
     /// Get the address currently associated with the Server
@@ -423,6 +524,41 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
         self.io.incoming_bandwidth()
     }
 
+    // Packet capture
+
+    /// Records every packet the Client sends & receives from now on, as it
+    /// crosses the wire, with its timestamp & the Server's address. Read the
+    /// capture back with `PacketCapture::read`, and replay it with
+    /// `Client::replay`
+    pub fn start_capture(
+        &mut self,
+        writer: impl Write + Send + Sync + 'static,
+    ) -> Result<(), std::io::Error> {
+        let timestamp = self.handshake_manager.pre_connection_timestamp();
+        let capture = CaptureWriter::new(writer, &timestamp.to_le_bytes())?;
+        self.io.set_capture(Some(capture));
+        Ok(())
+    }
+
+    /// Connects to a capture recorded with `Client::start_capture`, in place
+    /// of a socket, identifying its handshake as the captured Client did. The
+    /// Client receives each captured packet at the point of the capture it
+    /// was received at, on a clock simulated for the calling thread, see
+    /// `CaptureReplay`
+    #[cfg(all(feature = "transport_replay", not(target_arch = "wasm32")))]
+    pub fn replay(&mut self, capture: naia_shared::PacketCapture) {
+        if let Ok(timestamp) = capture.handshake_secret().try_into() {
+            self.handshake_manager
+                .set_pre_connection_timestamp(u64::from_le_bytes(timestamp));
+        }
+        self.connect(crate::transport::replay::Socket::new(capture));
+    }
+
+    /// Stops recording packets, flushing the capture
+    pub fn stop_capture(&mut self) {
+        self.io.set_capture(None);
+    }
+
     // Crate-Public methods
 
     /// Despawns the Entity, if it exists.
@@ -532,6 +668,374 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
     // Private methods
 
+    fn receive_asset_messages(&mut self) {
+        let Some(mut asset_messages) = self
+            .incoming_events
+            .take_channel_messages(&ChannelKind::of::<AssetChannel>())
+        else {
+            return;
+        };
+
+        // store any downloaded Assets first, so that offers in this batch can use them
+        for message in asset_messages
+            .remove(&MessageKind::of::<AssetData>())
+            .unwrap_or_default()
+        {
+            let data = message.to_boxed_any().downcast::<AssetData>().unwrap();
+            self.requested_assets.remove(&data.hash);
+            if AssetHash::of(&data.bytes) != data.hash {
+                // a later offer of this Asset will request it again
+                warn!("Client Error: received Asset bytes which do not match their hash");
+                continue;
+            }
+            self.asset_storage.store(&data.hash, &data.bytes);
+            self.incoming_events.push_asset_ready(&data.hash);
+        }
+
+        for message in asset_messages
+            .remove(&MessageKind::of::<AssetOffer>())
+            .unwrap_or_default()
+        {
+            let offer = message.to_boxed_any().downcast::<AssetOffer>().unwrap();
+            if self.asset_storage.has(&offer.hash) {
+                self.incoming_events.push_asset_ready(&offer.hash);
+            } else if self.requested_assets.insert(offer.hash) {
+                self.send_message_inner(
+                    &ChannelKind::of::<AssetChannel>(),
+                    Box::new(AssetRequest::new(&offer.hash)),
+                );
+            }
+        }
+    }
+
+    /// Reverts changes to this Client's Entities which the Server has rejected
+    /// or clamped
+    fn receive_component_corrections<W: WorldMutType<E>>(&mut self, world: &mut W) {
+        let Some(mut correction_messages) = self
+            .incoming_events
+            .take_channel_messages(&ChannelKind::of::<ValidationChannel>())
+        else {
+            return;
+        };
+
+        for message in correction_messages
+            .remove(&MessageKind::of::<ComponentCorrection>())
+            .unwrap_or_default()
+        {
+            let correction = message
+                .to_boxed_any()
+                .downcast::<ComponentCorrection>()
+                .unwrap();
+            let Some(entity) = correction.entity.get(&self.global_world_manager) else {
+                continue;
+            };
+            if self
+                .apply_component_correction(world, &entity, &correction)
+                .is_err()
+            {
+                warn!("Client Error: cannot read malformed Component correction");
+            }
+        }
+    }
+
+    fn apply_component_correction<W: WorldMutType<E>>(
+        &mut self,
+        world: &mut W,
+        entity: &E,
+        correction: &ComponentCorrection,
+    ) -> Result<(), SerdeErr> {
+        let mut reader = BitReader::new(&correction.bytes);
+
+        if correction.remove {
+            let component_kind = ComponentKind::de(&self.protocol.component_kinds, &mut reader)?;
+            if world.has_component_of_kind(entity, &component_kind) {
+                self.remove_component_worldless(entity, &component_kind);
+                world.remove_component_of_kind(entity, &component_kind);
+            }
+            return Ok(());
+        }
+
+        let update = self
+            .protocol
+            .component_kinds
+            .read_create_update(&mut reader)?;
+        let component_kind = update.kind;
+        if !world.has_component_of_kind(entity, &component_kind) {
+            return Ok(());
+        }
+        let Some(connection) = self.server_connection.as_mut() else {
+            return Ok(());
+        };
+
+        // the corrected values are the Server's already, do not send them back
+        let diff_handler = &mut connection
+            .base
+            .host_world_manager
+            .world_channel
+            .diff_handler;
+        let current_mask = diff_handler
+            .diff_mask(entity, &component_kind)
+            .map(|mask| mask.clone());
+
+        let converter = EntityConverter::new(
+            &self.global_world_manager,
+            &connection.base.local_world_manager,
+        );
+        // the Client's own Components are host-owned, so are written to
+        // through the authority read path
+        let result = match world.component_mut_of_kind(entity, &component_kind) {
+            Some(mut component) => component.read_apply_authority_update(&converter, update),
+            None => Ok(()),
+        };
+
+        if let Some(current_mask) = current_mask {
+            diff_handler.clear_diff_mask(entity, &component_kind);
+            diff_handler.or_diff_mask(entity, &component_kind, &current_mask);
+        }
+
+        result
+    }
+
+    fn receive_authority_messages(&mut self) {
+        let Some(mut authority_messages) = self
+            .incoming_events
+            .take_channel_messages(&ChannelKind::of::<EntityAuthorityChannel>())
+        else {
+            return;
+        };
+
+        for message in authority_messages
+            .remove(&MessageKind::of::<EntityAuthorityMessage>())
+            .unwrap_or_default()
+        {
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityAuthorityMessage>()
+                .unwrap();
+            let Some(entity) = message.entity.get(&self.global_world_manager) else {
+                continue;
+            };
+            match message.action {
+                EntityAuthorityAction::Grant => {
+                    self.authority_manager.grant(&entity);
+                    self.incoming_events.push_authority_grant(&entity);
+                }
+                EntityAuthorityAction::Deny => {
+                    self.authority_manager.deny(&entity);
+                    self.incoming_events.push_authority_deny(&entity);
+                }
+                EntityAuthorityAction::Revoke => {
+                    self.remove_authority(&entity);
+                    self.incoming_events.push_authority_revoke(&entity);
+                }
+                EntityAuthorityAction::Request | EntityAuthorityAction::Release => {
+                    warn!("Client Error: Server sent an Authority action only a Client may send");
+                }
+            }
+        }
+    }
+
+    fn receive_hierarchy_messages(&mut self) {
+        let Some(mut hierarchy_messages) = self
+            .incoming_events
+            .take_channel_messages(&ChannelKind::of::<EntityHierarchyChannel>())
+        else {
+            return;
+        };
+
+        for message in hierarchy_messages
+            .remove(&MessageKind::of::<EntityParentSet>())
+            .unwrap_or_default()
+        {
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityParentSet>()
+                .unwrap();
+            let (Some(child), Some(parent)) = (
+                message.child.get(&self.global_world_manager),
+                message.parent.get(&self.global_world_manager),
+            ) else {
+                continue;
+            };
+            self.incoming_events.push_parent_set(&child, &parent);
+        }
+
+        for message in hierarchy_messages
+            .remove(&MessageKind::of::<EntityParentRemoved>())
+            .unwrap_or_default()
+        {
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityParentRemoved>()
+                .unwrap();
+            let Some(child) = message.child.get(&self.global_world_manager) else {
+                continue;
+            };
+            self.incoming_events.push_parent_remove(&child);
+        }
+    }
+
+    /// Replaces the Components of Entities this Client has authority over with
+    /// host-owned copies, so that changes to them can be tracked and sent to the
+    /// Server. While authority is held, updates from the Server are read into
+    /// the host-owned copies, and once it ends they are replaced with
+    /// remote-owned copies again.
+    fn sync_authority_components<W: WorldMutType<E>>(&mut self, world: &mut W) {
+        for entity in self.authority_manager.granted_entities() {
+            if !world.has_entity(&entity) {
+                // Entity has been despawned, or has left this Client's scope
+                self.remove_authority(&entity);
+                continue;
+            }
+            let Some(connection) = self.server_connection.as_mut() else {
+                return;
+            };
+            let remote_world_manager = &mut connection.base.remote_world_manager;
+
+            let world_component_kinds = world.component_kinds(&entity);
+            for component_kind in self.authority_manager.component_kinds(&entity) {
+                if !world_component_kinds.contains(&component_kind)
+                    || !remote_world_manager.has_authority_component(&entity, &component_kind)
+                {
+                    // Component has been removed, or removed & inserted again by the Server
+                    self.authority_manager
+                        .untrack_component(&entity, &component_kind);
+                    self.global_world_manager
+                        .deregister_authority_component(&entity, &component_kind);
+                    remote_world_manager.remove_authority_component(&entity, &component_kind);
+                }
+            }
+
+            for component_kind in world_component_kinds {
+                if remote_world_manager.has_authority_component(&entity, &component_kind) {
+                    continue;
+                }
+                let Some(remote_component) =
+                    world.remove_component_of_kind(&entity, &component_kind)
+                else {
+                    continue;
+                };
+                let mut host_component = remote_component.copy_to_box();
+                let receiver = self
+                    .global_world_manager
+                    .register_authority_component(&entity, host_component.as_mut());
+                world.insert_boxed_component(&entity, host_component);
+                self.authority_manager
+                    .track_component(&entity, &component_kind, receiver);
+                remote_world_manager.insert_authority_component(&entity, &component_kind);
+            }
+        }
+
+        let Some(connection) = self.server_connection.as_mut() else {
+            return;
+        };
+        for (entity, component_kind) in connection.base.remote_world_manager.authority_components()
+        {
+            if self.authority_manager.has_authority(&entity) {
+                continue;
+            }
+            connection
+                .base
+                .remote_world_manager
+                .remove_authority_component(&entity, &component_kind);
+            let Some(host_component) = world.remove_component_of_kind(&entity, &component_kind)
+            else {
+                continue;
+            };
+            match Self::remote_copy(
+                &self.protocol,
+                &self.global_world_manager,
+                connection,
+                host_component.as_ref(),
+            ) {
+                Ok(remote_component) => world.insert_boxed_component(&entity, remote_component),
+                Err(_) => warn!("Client Error: cannot copy Component once authority has ended"),
+            }
+        }
+    }
+
+    /// Copies a host-owned Component into a remote-owned one, by writing it out
+    /// and reading it back in as if it had been received from the Server
+    fn remote_copy(
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        connection: &mut Connection<E>,
+        component: &dyn Replicate,
+    ) -> Result<Box<dyn Replicate>, SerdeErr> {
+        let mut writer = BitWriter::new();
+        let mut converter_mut = EntityConverterMut::new(
+            global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        component.write(&protocol.component_kinds, &mut writer, &mut converter_mut);
+
+        let bytes = writer.to_bytes();
+        let mut reader = BitReader::new(&bytes);
+        let converter =
+            EntityConverter::new(global_world_manager, &connection.base.local_world_manager);
+        protocol.component_kinds.read(&mut reader, &converter)
+    }
+
+    fn send_authority_updates<W: WorldRefType<E>>(
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        authority_manager: &AuthorityManager<E>,
+        connection: &mut Connection<E>,
+        world: &W,
+    ) {
+        for (entity, component_kind, diff_mask) in authority_manager.take_diff_masks() {
+            let Some(component) = world.component_of_kind(&entity, &component_kind) else {
+                continue;
+            };
+
+            let mut converter = EntityConverterMut::new(
+                global_world_manager,
+                &mut connection.base.local_world_manager,
+            );
+
+            let mut writer = BitWriter::new();
+            component_kind.ser(&protocol.component_kinds, &mut writer);
+            component.write_update(&diff_mask, &mut writer, &mut converter);
+
+            let update = EntityAuthorityUpdate::new(
+                global_world_manager,
+                &entity,
+                writer.to_bytes().to_vec(),
+            );
+            let message = MessageContainer::from_write(Box::new(update), &mut converter);
+            connection.base.message_manager.send_message(
+                &protocol.message_kinds,
+                &mut converter,
+                &ChannelKind::of::<EntityAuthorityChannel>(),
+                message,
+            );
+        }
+    }
+
+    fn remove_authority(&mut self, entity: &E) {
+        let Some(component_kinds) = self.authority_manager.remove(entity) else {
+            return;
+        };
+        for component_kind in component_kinds {
+            self.global_world_manager
+                .deregister_authority_component(entity, &component_kind);
+        }
+    }
+
+    fn send_authority_message(&mut self, entity: &E, action: EntityAuthorityAction) {
+        let message = EntityAuthorityMessage::new(&self.global_world_manager, entity, action);
+        self.send_message_inner(
+            &ChannelKind::of::<EntityAuthorityChannel>(),
+            Box::new(message),
+        );
+    }
+
+    fn check_entity_authority_allowed(&self) {
+        if !self.protocol.entity_authority {
+            panic!("Cannot perform this operation: Entity Authority is not enabled! Enable it in the Protocol, with the `enable_entity_authority()` method.")
+        }
+    }
+
     fn check_client_authoritative_allowed(&self) {
         if !self.protocol.client_authoritative_entities {
             panic!("Cannot perform this operation: Client Authoritative Entities are not enabled! Enable them in the Protocol, with the `enable_client_authoritative_entities() method, and note that if you do enable them, to make sure you handle all Spawn/Insert/Update events in the Server, as this may be an attack vector.")
@@ -741,6 +1245,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Client<E> {
 
     fn disconnect_reset_connection(&mut self) {
         self.server_connection = None;
+        self.requested_assets.clear();
+        for (entity, component_kind) in self.authority_manager.clear() {
+            self.global_world_manager
+                .deregister_authority_component(&entity, &component_kind);
+        }
 
         self.io = Io::new(
             &self.client_config.connection.bandwidth_measure_duration,
@@ -3,9 +3,10 @@ use std::hash::Hash;
 use log::warn;
 
 use naia_shared::{
-    BaseConnection, BitReader, BitWriter, ChannelKinds, ComponentKinds, ConnectionConfig,
-    EntityConverter, EntityConverterMut, HostType, HostWorldEvents, Instant, OwnedBitReader,
-    PacketType, Protocol, Serde, SerdeErr, StandardHeader, Tick, WorldMutType, WorldRefType,
+    AcceptAllValidator, BaseConnection, BitReader, BitWriter, ChannelKinds, ComponentKinds,
+    ConnectionConfig, EntityConverter, EntityConverterMut, HostType, HostWorldEvents, Instant,
+    OwnedBitReader, PacketType, Protocol, Serde, SerdeErr, StandardHeader, Tick, UpdateCache,
+    WorldMutType, WorldRefType,
 };
 
 use crate::{
@@ -143,6 +144,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
             &mut self.base.local_world_manager,
             component_kinds,
             world,
+            &mut AcceptAllValidator,
             remote_events,
         );
         incoming_events.receive_world_events(world_events);
@@ -256,6 +258,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 &mut has_written,
                 protocol.client_authoritative_entities,
                 host_world_events,
+                &mut UpdateCache::disabled(),
             );
 
             // send packet
@@ -73,6 +73,18 @@ impl HandshakeManager {
         }
     }
 
+    /// The timestamp the Client's handshake is identified by, which captures
+    /// record so the handshake can be replayed
+    pub fn pre_connection_timestamp(&self) -> Timestamp {
+        self.pre_connection_timestamp
+    }
+
+    /// Identifies the handshake by the given timestamp, as the Client being
+    /// replayed did
+    pub fn set_pre_connection_timestamp(&mut self, timestamp: Timestamp) {
+        self.pre_connection_timestamp = timestamp;
+    }
+
     pub fn set_auth_message(&mut self, auth: MessageContainer) {
         self.auth_message = Some(auth);
     }
@@ -1,7 +1,13 @@
-use std::{net::SocketAddr, time::Duration};
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+use log::warn;
 
 use naia_shared::{
-    BandwidthMonitor, BitReader, CompressionConfig, Decoder, Encoder, OutgoingPacket,
+    BandwidthMonitor, BitReader, CaptureWriter, CompressionConfig, Decoder, Encoder,
+    OutgoingPacket, PacketDirection,
 };
 
 use crate::{
@@ -16,6 +22,7 @@ pub struct Io {
     incoming_bandwidth_monitor: Option<BandwidthMonitor>,
     outgoing_encoder: Option<Encoder>,
     incoming_decoder: Option<Decoder>,
+    capture: Option<CaptureWriter>,
 }
 
 impl Io {
@@ -46,6 +53,7 @@ impl Io {
             incoming_bandwidth_monitor,
             outgoing_encoder,
             incoming_decoder,
+            capture: None,
         }
     }
 
@@ -66,6 +74,11 @@ impl Io {
         self.packet_sender.is_some()
     }
 
+    /// Records every packet sent & received from now on, or stops recording
+    pub fn set_capture(&mut self, capture: Option<CaptureWriter>) {
+        self.capture = capture;
+    }
+
     pub fn send_packet(&mut self, packet: OutgoingPacket) -> Result<(), NaiaClientError> {
         // get payload
         let mut payload = packet.slice();
@@ -80,6 +93,16 @@ impl Io {
             monitor.record_packet(payload.len());
         }
 
+        if self.capture.is_some() {
+            let server_addr = capture_addr(&self.packet_sender);
+            capture_packet(
+                &mut self.capture,
+                PacketDirection::Outgoing,
+                &server_addr,
+                payload,
+            );
+        }
+
         self.packet_sender
             .as_mut()
             .expect("Cannot call Client.send_packet() until you call Client.connect()!")
@@ -100,6 +123,16 @@ impl Io {
                 monitor.record_packet(payload.len());
             }
 
+            if self.capture.is_some() {
+                let server_addr = capture_addr(&self.packet_sender);
+                capture_packet(
+                    &mut self.capture,
+                    PacketDirection::Incoming,
+                    &server_addr,
+                    payload,
+                );
+            }
+
             // Decompression
             if let Some(decoder) = &mut self.incoming_decoder {
                 payload = decoder.decode(payload);
@@ -141,3 +174,28 @@ impl Io {
             .bandwidth();
     }
 }
+
+/// The Server's address, as recorded in a capture. Unspecified while the
+/// transport is still finding it
+fn capture_addr(packet_sender: &Option<Box<dyn PacketSender>>) -> SocketAddr {
+    match packet_sender.as_ref().map(|sender| sender.server_addr()) {
+        Some(ServerAddr::Found(server_addr)) => server_addr,
+        _ => SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0),
+    }
+}
+
+/// Records a packet, stopping the capture if it can't be written
+fn capture_packet(
+    capture: &mut Option<CaptureWriter>,
+    direction: PacketDirection,
+    address: &SocketAddr,
+    payload: &[u8],
+) {
+    let Some(writer) = capture else {
+        return;
+    };
+    if let Err(error) = writer.record(direction, address, payload) {
+        warn!("Packet capture stopped, could not record packet: {}", error);
+        *capture = None;
+    }
+}
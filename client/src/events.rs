@@ -1,8 +1,8 @@
 use std::{collections::HashMap, marker::PhantomData, mem, net::SocketAddr, vec::IntoIter};
 
 use naia_shared::{
-    Channel, ChannelKind, ComponentKind, EntityEvent, Message, MessageContainer, MessageKind,
-    Replicate, Tick,
+    AssetHash, Channel, ChannelKind, ComponentKind, EntityEvent, Message, MessageContainer,
+    MessageKind, Replicate, Tick,
 };
 
 use crate::NaiaClientError;
@@ -20,6 +20,12 @@ pub struct Events<E: Copy> {
     inserts: HashMap<ComponentKind, Vec<E>>,
     removes: HashMap<ComponentKind, Vec<(E, Box<dyn Replicate>)>>,
     updates: HashMap<ComponentKind, Vec<(Tick, E)>>,
+    assets: Vec<AssetHash>,
+    authority_grants: Vec<E>,
+    authority_denies: Vec<E>,
+    authority_revokes: Vec<E>,
+    parent_sets: Vec<(E, E)>,
+    parent_removes: Vec<E>,
     empty: bool,
 }
 
@@ -44,6 +50,12 @@ impl<E: Copy> Events<E> {
             inserts: HashMap::new(),
             removes: HashMap::new(),
             updates: HashMap::new(),
+            assets: Vec::new(),
+            authority_grants: Vec::new(),
+            authority_denies: Vec::new(),
+            authority_revokes: Vec::new(),
+            parent_sets: Vec::new(),
+            parent_removes: Vec::new(),
             empty: true,
         }
     }
@@ -138,6 +150,43 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn take_channel_messages(
+        &mut self,
+        channel_kind: &ChannelKind,
+    ) -> Option<HashMap<MessageKind, Vec<MessageContainer>>> {
+        self.messages.remove(channel_kind)
+    }
+
+    pub(crate) fn push_asset_ready(&mut self, hash: &AssetHash) {
+        self.assets.push(*hash);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_authority_grant(&mut self, entity: &E) {
+        self.authority_grants.push(*entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_authority_deny(&mut self, entity: &E) {
+        self.authority_denies.push(*entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_authority_revoke(&mut self, entity: &E) {
+        self.authority_revokes.push(*entity);
+        self.empty = false;
+    }
+
+    pub(crate) fn push_parent_set(&mut self, child: &E, parent: &E) {
+        self.parent_sets.push((*child, *parent));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_parent_remove(&mut self, child: &E) {
+        self.parent_removes.push(*child);
+        self.empty = false;
+    }
+
     pub(crate) fn push_client_tick(&mut self, tick: Tick) {
         self.client_ticks.push(tick);
         self.empty = false;
@@ -226,6 +275,10 @@ impl<E: Copy> Events<E> {
         self.inserts.clear();
         self.removes.clear();
         self.updates.clear();
+        self.assets.clear();
+        self.authority_grants.clear();
+        self.authority_denies.clear();
+        self.authority_revokes.clear();
         self.empty = true;
     }
 }
@@ -469,3 +522,93 @@ impl<E: Copy, C: Replicate> Event<E> for RemoveComponentEvent<C> {
         events.removes.contains_key(&component_kind)
     }
 }
+
+// Asset Ready Event
+pub struct AssetReadyEvent;
+impl<E: Copy> Event<E> for AssetReadyEvent {
+    type Iter = IntoIter<AssetHash>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.assets);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.assets.is_empty()
+    }
+}
+
+// Authority Granted Event
+pub struct AuthorityGrantedEvent;
+impl<E: Copy> Event<E> for AuthorityGrantedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.authority_grants);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.authority_grants.is_empty()
+    }
+}
+
+// Authority Denied Event
+pub struct AuthorityDeniedEvent;
+impl<E: Copy> Event<E> for AuthorityDeniedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.authority_denies);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.authority_denies.is_empty()
+    }
+}
+
+// Authority Revoked Event
+pub struct AuthorityRevokedEvent;
+impl<E: Copy> Event<E> for AuthorityRevokedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.authority_revokes);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.authority_revokes.is_empty()
+    }
+}
+
+// Entity Parent Set Event
+pub struct EntityParentSetEvent;
+impl<E: Copy> Event<E> for EntityParentSetEvent {
+    type Iter = IntoIter<(E, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.parent_sets);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.parent_sets.is_empty()
+    }
+}
+
+// Entity Parent Removed Event
+pub struct EntityParentRemovedEvent;
+impl<E: Copy> Event<E> for EntityParentRemovedEvent {
+    type Iter = IntoIter<E>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.parent_removes);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.parent_removes.is_empty()
+    }
+}
@@ -13,10 +13,11 @@
 #[macro_use]
 extern crate cfg_if;
 
+pub mod assets;
 pub mod transport;
 pub mod shared {
     pub use naia_shared::{
-        default_channels, sequence_greater_than, EntityRef, Random, SocketConfig, Tick,
+        default_channels, sequence_greater_than, AssetHash, EntityRef, Random, SocketConfig, Tick,
     };
 }
 pub mod internal {
@@ -36,8 +37,9 @@ pub use client_config::ClientConfig;
 pub use command_history::CommandHistory;
 pub use error::NaiaClientError;
 pub use events::{
-    ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, Events,
-    InsertComponentEvent, MessageEvent, RejectEvent, RemoveComponentEvent, ServerTickEvent,
-    SpawnEntityEvent, UpdateComponentEvent,
+    AssetReadyEvent, AuthorityDeniedEvent, AuthorityGrantedEvent, AuthorityRevokedEvent,
+    ClientTickEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityParentRemovedEvent,
+    EntityParentSetEvent, ErrorEvent, Events, InsertComponentEvent, MessageEvent, RejectEvent,
+    RemoveComponentEvent, ServerTickEvent, SpawnEntityEvent, UpdateComponentEvent,
 };
 pub use world::entity_mut::EntityMut;
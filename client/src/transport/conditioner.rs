@@ -1,33 +1,48 @@
-use naia_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use std::sync::{Arc, Mutex};
 
-use super::{server_addr::ServerAddr, PacketReceiver, RecvError};
+use naia_shared::{
+    link_condition_logic::{self, LinkState},
+    Instant, LinkConditionerSchedule, TimeQueue,
+};
+
+use super::{server_addr::ServerAddr, PacketReceiver, PacketSender, RecvError, SendError};
 
 /// Used to receive packets from the Client Socket
 #[derive(Clone)]
 pub struct ConditionedPacketReceiver {
     inner_receiver: Box<dyn PacketReceiver>,
-    link_conditioner_config: LinkConditionerConfig,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_state: LinkState,
     time_queue: TimeQueue<Box<[u8]>>,
     last_payload: Option<Box<[u8]>>,
+    outgoing: OutgoingConditioner,
 }
 
 impl ConditionedPacketReceiver {
-    /// Creates a new ConditionedPacketReceiver
+    /// Creates a new ConditionedPacketReceiver, which also sends the packets
+    /// held by the given OutgoingConditioner as they become due
     pub fn new(
         inner_receiver: Box<dyn PacketReceiver>,
-        link_conditioner_config: &LinkConditionerConfig,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+        outgoing: OutgoingConditioner,
     ) -> Self {
         ConditionedPacketReceiver {
             inner_receiver,
-            link_conditioner_config: link_conditioner_config.clone(),
+            link_conditioner_schedule: link_conditioner_schedule.clone(),
+            started: Instant::now(),
+            link_state: LinkState::new(),
             time_queue: TimeQueue::new(),
             last_payload: None,
+            outgoing,
         }
     }
 }
 
 impl PacketReceiver for ConditionedPacketReceiver {
     fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        self.outgoing.flush();
+
         loop {
             match self.inner_receiver.receive() {
                 Ok(option) => match option {
@@ -35,10 +50,14 @@ impl PacketReceiver for ConditionedPacketReceiver {
                         break;
                     }
                     Some(payload) => {
-                        link_condition_logic::process_packet(
-                            &self.link_conditioner_config,
+                        link_condition_logic::process_incoming_packet(
+                            &self
+                                .link_conditioner_schedule
+                                .config_at(self.started.elapsed()),
+                            &mut self.link_state,
                             &mut self.time_queue,
                             payload.into(),
+                            payload.len(),
                         );
                     }
                 },
@@ -61,3 +80,84 @@ impl PacketReceiver for ConditionedPacketReceiver {
         self.inner_receiver.server_addr()
     }
 }
+
+/// Holds outgoing packets until the time the link conditioner sends them
+#[derive(Clone)]
+pub struct OutgoingConditioner {
+    inner: Arc<Mutex<OutgoingConditionerInner>>,
+}
+
+struct OutgoingConditionerInner {
+    inner_sender: Box<dyn PacketSender>,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_state: LinkState,
+    time_queue: TimeQueue<Box<[u8]>>,
+}
+
+impl OutgoingConditioner {
+    pub fn new(
+        inner_sender: Box<dyn PacketSender>,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(OutgoingConditionerInner {
+                inner_sender,
+                link_conditioner_schedule: link_conditioner_schedule.clone(),
+                started: Instant::now(),
+                link_state: LinkState::new(),
+                time_queue: TimeQueue::new(),
+            })),
+        }
+    }
+
+    /// Sends every held packet which has become due. Packets which fail to
+    /// send are lost, as they would be on a real link
+    pub fn flush(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        while let Some(payload) = inner.time_queue.pop_item() {
+            let _ = inner.inner_sender.send(&payload);
+        }
+    }
+}
+
+/// Used to send packets through the link conditioner
+pub struct ConditionedPacketSender {
+    outgoing: OutgoingConditioner,
+}
+
+impl ConditionedPacketSender {
+    pub fn new(outgoing: OutgoingConditioner) -> Self {
+        Self { outgoing }
+    }
+}
+
+impl PacketSender for ConditionedPacketSender {
+    fn send(&self, payload: &[u8]) -> Result<(), SendError> {
+        {
+            let mut guard = self.outgoing.inner.lock().unwrap();
+            let inner = &mut *guard;
+            link_condition_logic::process_outgoing_packet(
+                &inner
+                    .link_conditioner_schedule
+                    .config_at(inner.started.elapsed()),
+                &mut inner.link_state,
+                &mut inner.time_queue,
+                payload.into(),
+                payload.len(),
+            );
+        }
+        self.outgoing.flush();
+        Ok(())
+    }
+
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> ServerAddr {
+        self.outgoing
+            .inner
+            .lock()
+            .unwrap()
+            .inner_sender
+            .server_addr()
+    }
+}
@@ -9,6 +9,16 @@ cfg_if! {
         mod conditioner;
     } else {}
 }
+cfg_if! {
+    if #[cfg(feature = "transport_websocket")] {
+        pub mod websocket;
+    } else {}
+}
+cfg_if! {
+    if #[cfg(all(feature = "transport_replay", not(target_arch = "wasm32")))] {
+        pub mod replay;
+    } else {}
+}
 
 mod server_addr;
 pub use server_addr::ServerAddr;
@@ -0,0 +1,103 @@
+use std::sync::{Arc, Mutex};
+
+use naia_shared::{CaptureReplay, PacketCapture};
+
+use super::{
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    ServerAddr as TransportAddr, Socket as TransportSocket,
+};
+
+// Socket
+/// Replays the packets a Client received, from a capture recorded with
+/// `Client::start_capture`, at the points of the capture they were received
+/// at. Packets the Client sends are discarded, capture the replay to compare
+/// them. Use `Client::replay` to also identify the handshake as the captured
+/// Client did, which replaying the Server's handshake requires
+pub struct Socket {
+    capture: PacketCapture,
+}
+
+impl Socket {
+    pub fn new(capture: PacketCapture) -> Self {
+        return Self { capture };
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let server_addr = match self.capture.incoming().next() {
+            Some(packet) => TransportAddr::Found(packet.address),
+            None => TransportAddr::Finding,
+        };
+
+        let sender = Box::new(PacketSender::new(server_addr));
+        let receiver = Box::new(PacketReceiver::new(
+            CaptureReplay::new(&self.capture),
+            server_addr,
+        ));
+
+        return (sender, receiver);
+    }
+}
+
+// Packet Sender
+struct PacketSender {
+    server_addr: TransportAddr,
+}
+
+impl PacketSender {
+    pub fn new(server_addr: TransportAddr) -> Self {
+        return Self { server_addr };
+    }
+}
+
+impl TransportSender for PacketSender {
+    /// Discards a packet sent from the Client
+    fn send(&self, _payload: &[u8]) -> Result<(), SendError> {
+        return Ok(());
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        self.server_addr
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    replay: Arc<Mutex<CaptureReplay>>,
+    server_addr: TransportAddr,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl PacketReceiver {
+    pub fn new(replay: CaptureReplay, server_addr: TransportAddr) -> Self {
+        return Self {
+            replay: Arc::new(Mutex::new(replay)),
+            server_addr,
+            last_payload: None,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives the captured packets due at this point of the replay
+    fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        let Some(packet) = self.replay.lock().unwrap().receive() else {
+            return Ok(None);
+        };
+
+        self.last_payload = Some(packet.payload);
+        return Ok(Some(self.last_payload.as_ref().unwrap()));
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        self.server_addr
+    }
+}
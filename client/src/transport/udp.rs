@@ -4,19 +4,19 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use naia_shared::LinkConditionerConfig;
+use naia_shared::{LinkConditionerConfig, LinkConditionerSchedule};
 
 use super::{
-    conditioner::ConditionedPacketReceiver, PacketReceiver as TransportReceiver,
-    PacketSender as TransportSender, RecvError, SendError, ServerAddr as TransportAddr,
-    Socket as TransportSocket,
+    conditioner::{ConditionedPacketReceiver, ConditionedPacketSender, OutgoingConditioner},
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    ServerAddr as TransportAddr, Socket as TransportSocket,
 };
 
 // Socket
 pub struct Socket {
     server_addr: SocketAddr,
     socket: Arc<Mutex<UdpSocket>>,
-    config: Option<LinkConditionerConfig>,
+    config: Option<LinkConditionerSchedule>,
 }
 
 impl Socket {
@@ -35,9 +35,17 @@ impl Socket {
         return Self {
             server_addr: *server_addr,
             socket,
-            config,
+            config: config.map(LinkConditionerSchedule::from),
         };
     }
+
+    /// Creates a Socket which simulates network conditions that change over
+    /// time, as described by the given schedule
+    pub fn with_schedule(server_addr: &SocketAddr, schedule: LinkConditionerSchedule) -> Self {
+        let mut socket = Self::new(server_addr, None);
+        socket.config = Some(schedule);
+        return socket;
+    }
 }
 
 impl Into<Box<dyn TransportSocket>> for Socket {
@@ -48,19 +56,21 @@ impl Into<Box<dyn TransportSocket>> for Socket {
 
 impl TransportSocket for Socket {
     fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
-        let sender = Box::new(PacketSender::new(self.socket.clone(), self.server_addr));
-
-        let receiver: Box<dyn TransportReceiver> = {
-            let inner_receiver =
-                Box::new(PacketReceiver::new(self.socket.clone(), self.server_addr));
-            if let Some(config) = &self.config {
-                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
-            } else {
-                inner_receiver
-            }
-        };
+        let inner_sender = Box::new(PacketSender::new(self.socket.clone(), self.server_addr));
+        let inner_receiver = Box::new(PacketReceiver::new(self.socket.clone(), self.server_addr));
+
+        if let Some(config) = &self.config {
+            let outgoing = OutgoingConditioner::new(inner_sender, config);
+            let sender = Box::new(ConditionedPacketSender::new(outgoing.clone()));
+            let receiver = Box::new(ConditionedPacketReceiver::new(
+                inner_receiver,
+                config,
+                outgoing,
+            ));
+            return (sender, receiver);
+        }
 
-        return (sender, receiver);
+        return (inner_sender, inner_receiver);
     }
 }
 
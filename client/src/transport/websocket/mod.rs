@@ -0,0 +1,14 @@
+//! Connects to the Server over plain WebSockets, for networks where WebRTC is
+//! blocked. Each packet travels as one binary message, and naia's own
+//! reliability runs on top unchanged.
+
+cfg_if! {
+    if #[cfg(all(target_arch = "wasm32", feature = "wbindgen"))] {
+        mod wasm_bindgen;
+        pub use self::wasm_bindgen::Socket;
+    }
+    else if #[cfg(not(target_arch = "wasm32"))] {
+        mod native;
+        pub use native::Socket;
+    }
+}
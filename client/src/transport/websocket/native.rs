@@ -0,0 +1,242 @@
+use std::{
+    io::{BufReader, Write},
+    net::{Shutdown, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{channel, Receiver, Sender, TryRecvError},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use log::warn;
+
+use naia_shared::{
+    websocket::{
+        accept_key, encode_frame, header_value, read_http_head, Message, MessageReader,
+        OPCODE_BINARY, OPCODE_CLOSE, OPCODE_PONG,
+    },
+    Random,
+};
+
+use super::super::{
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    ServerAddr as TransportAddr, Socket as TransportSocket,
+};
+
+type Outgoing = Arc<Mutex<Option<Sender<Vec<u8>>>>>;
+
+// Socket
+pub struct Socket {
+    server_url: String,
+}
+
+impl Socket {
+    /// Takes a url of the form `ws://host:port/path`. TLS (`wss://`) is not
+    /// supported on native Clients.
+    pub fn new(server_url: &str) -> Self {
+        return Self {
+            server_url: server_url.to_string(),
+        };
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let server_addr = Arc::new(Mutex::new(TransportAddr::Finding));
+        let outgoing: Outgoing = Arc::new(Mutex::new(None));
+        let (incoming_sender, incoming_receiver) = channel();
+
+        let thread_server_addr = server_addr.clone();
+        let thread_outgoing = outgoing.clone();
+        thread::spawn(move || {
+            if let Err(error) = run_connection(
+                &self.server_url,
+                thread_server_addr,
+                thread_outgoing,
+                incoming_sender,
+            ) {
+                warn!(
+                    "WebSocket connection to {} failed: {}",
+                    self.server_url, error
+                );
+            }
+        });
+
+        let sender = Box::new(PacketSender::new(outgoing, server_addr.clone()));
+        let receiver = Box::new(PacketReceiver::new(incoming_receiver, server_addr));
+
+        return (sender, receiver);
+    }
+}
+
+/// Connects & upgrades, then forwards the Server's messages until the
+/// connection closes
+fn run_connection(
+    server_url: &str,
+    server_addr: Arc<Mutex<TransportAddr>>,
+    outgoing: Outgoing,
+    incoming: Sender<Box<[u8]>>,
+) -> Result<(), String> {
+    let Some(address) = server_url.strip_prefix("ws://") else {
+        return Err("url must start with ws://".to_string());
+    };
+    let (host, path) = match address.find('/') {
+        Some(index) => (&address[..index], &address[index..]),
+        None => (address, "/"),
+    };
+    let socket_addr = host
+        .to_socket_addrs()
+        .map_err(|error| error.to_string())?
+        .next()
+        .ok_or_else(|| "could not resolve host".to_string())?;
+
+    let stream = TcpStream::connect(socket_addr).map_err(|error| error.to_string())?;
+    let _ = stream.set_nodelay(true);
+    let mut write_stream = stream.try_clone().map_err(|error| error.to_string())?;
+    let mut read_stream = BufReader::new(stream);
+
+    let key: Vec<u8> = (0..16)
+        .map(|_| Random::gen_range_u32(0, 256) as u8)
+        .collect();
+    let key = STANDARD.encode(key);
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+        path,
+        host,
+        key
+    );
+    write_stream
+        .write_all(request.as_bytes())
+        .map_err(|error| error.to_string())?;
+    let head = read_http_head(&mut read_stream).map_err(|error| error.to_string())?;
+    if head.split_whitespace().nth(1) != Some("101") {
+        return Err("server refused to upgrade the connection".to_string());
+    }
+    if header_value(&head, "Sec-WebSocket-Accept") != Some(accept_key(&key).as_str()) {
+        return Err("server accepted the upgrade with the wrong key".to_string());
+    }
+
+    // writes happen on their own thread, so that sending never blocks the
+    // Client
+    let (frame_sender, frame_receiver) = channel::<Vec<u8>>();
+    thread::spawn(move || {
+        for frame in frame_receiver {
+            if write_stream.write_all(&frame).is_err() {
+                break;
+            }
+        }
+        let _ = write_stream.shutdown(Shutdown::Both);
+    });
+    *outgoing.lock().unwrap() = Some(frame_sender.clone());
+    *server_addr.lock().unwrap() = TransportAddr::Found(socket_addr);
+
+    let mut message_reader = MessageReader::from_server();
+    let result = loop {
+        match message_reader.read(&mut read_stream) {
+            Ok(Message::Binary(payload)) => {
+                if incoming.send(payload.into_boxed_slice()).is_err() {
+                    break Ok(());
+                }
+            }
+            Ok(Message::Ping(payload)) => {
+                let _ = frame_sender.send(encode_frame(OPCODE_PONG, &payload, Some(mask())));
+            }
+            Ok(Message::Pong) => {}
+            Ok(Message::Close) => {
+                let _ = frame_sender.send(encode_frame(OPCODE_CLOSE, &[], Some(mask())));
+                break Ok(());
+            }
+            Err(error) => break Err(error.to_string()),
+        }
+    };
+
+    *outgoing.lock().unwrap() = None;
+    result
+}
+
+/// Frames sent by a client must be masked with a fresh key
+fn mask() -> [u8; 4] {
+    Random::gen_range_u32(0, u32::MAX).to_be_bytes()
+}
+
+// Packet Sender
+struct PacketSender {
+    outgoing: Outgoing,
+    server_addr: Arc<Mutex<TransportAddr>>,
+}
+
+impl PacketSender {
+    pub fn new(outgoing: Outgoing, server_addr: Arc<Mutex<TransportAddr>>) -> Self {
+        return Self {
+            outgoing,
+            server_addr,
+        };
+    }
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Client Socket
+    fn send(&self, payload: &[u8]) -> Result<(), SendError> {
+        let outgoing = self.outgoing.lock().unwrap();
+        let Some(frame_sender) = outgoing.as_ref() else {
+            // still connecting, the packet is lost as it would be over UDP
+            return Ok(());
+        };
+        if frame_sender
+            .send(encode_frame(OPCODE_BINARY, payload, Some(mask())))
+            .is_err()
+        {
+            return Err(SendError);
+        }
+        return Ok(());
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        *self.server_addr.lock().unwrap()
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    incoming: Arc<Mutex<Receiver<Box<[u8]>>>>,
+    server_addr: Arc<Mutex<TransportAddr>>,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl PacketReceiver {
+    pub fn new(incoming: Receiver<Box<[u8]>>, server_addr: Arc<Mutex<TransportAddr>>) -> Self {
+        return Self {
+            incoming: Arc::new(Mutex::new(incoming)),
+            server_addr,
+            last_payload: None,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Client Socket
+    fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        let received = self.incoming.lock().unwrap().try_recv();
+        match received {
+            Ok(payload) => {
+                self.last_payload = Some(payload);
+                Ok(Some(self.last_payload.as_ref().unwrap()))
+            }
+            // a closed connection goes quiet, and the Client times out as it
+            // would over UDP
+            Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => Ok(None),
+        }
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        *self.server_addr.lock().unwrap()
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    collections::VecDeque,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+};
+
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{BinaryType, Event, MessageEvent, WebSocket};
+
+use super::super::{
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    ServerAddr as TransportAddr, Socket as TransportSocket,
+};
+
+// Socket
+pub struct Socket {
+    server_url: String,
+}
+
+impl Socket {
+    /// Takes a url of the form `ws://host:port/path` or `wss://host:port/path`
+    pub fn new(server_url: &str) -> Self {
+        return Self {
+            server_url: server_url.to_string(),
+        };
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn connect(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let web_socket = WebSocket::new(&self.server_url).expect("invalid WebSocket url!");
+        web_socket.set_binary_type(BinaryType::Arraybuffer);
+
+        let server_addr = Arc::new(Mutex::new(TransportAddr::Finding));
+        let message_queue = Arc::new(Mutex::new(VecDeque::new()));
+
+        let open_server_addr = server_addr.clone();
+        let found_addr = url_to_addr(&self.server_url);
+        let onopen_func: Box<dyn FnMut(Event)> = Box::new(move |_: Event| {
+            *open_server_addr
+                .lock()
+                .expect("can't borrow 'server_addr' to set address!") =
+                TransportAddr::Found(found_addr);
+        });
+        let onopen_closure = Closure::wrap(onopen_func);
+        web_socket.set_onopen(Some(onopen_closure.as_ref().unchecked_ref()));
+        onopen_closure.forget();
+
+        let message_queue_2 = message_queue.clone();
+        let onmessage_func: Box<dyn FnMut(MessageEvent)> = Box::new(move |evt: MessageEvent| {
+            if let Ok(arraybuf) = evt.data().dyn_into::<js_sys::ArrayBuffer>() {
+                let uarray: js_sys::Uint8Array = js_sys::Uint8Array::new(&arraybuf);
+                let mut body = vec![0; uarray.length() as usize];
+                uarray.copy_to(&mut body[..]);
+                message_queue_2
+                    .lock()
+                    .expect("can't borrow 'message_queue_2' to retrieve message!")
+                    .push_back(body.into_boxed_slice());
+            }
+        });
+        let onmessage_closure = Closure::wrap(onmessage_func);
+        web_socket.set_onmessage(Some(onmessage_closure.as_ref().unchecked_ref()));
+        onmessage_closure.forget();
+
+        let sender = Box::new(PacketSender {
+            web_socket,
+            server_addr: server_addr.clone(),
+        });
+        let receiver = Box::new(PacketReceiver {
+            message_queue,
+            server_addr,
+            last_payload: None,
+        });
+
+        return (sender, receiver);
+    }
+}
+
+/// The browser never resolves the Server's address for us, so an IP address
+/// is read from the url where there is one, otherwise only the port is known
+fn url_to_addr(server_url: &str) -> SocketAddr {
+    let is_secure = server_url.starts_with("wss://");
+    let authority = server_url
+        .split("://")
+        .nth(1)
+        .unwrap_or(server_url)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    if let Ok(addr) = authority.parse::<SocketAddr>() {
+        return addr;
+    }
+    let port = authority
+        .rsplit_once(':')
+        .and_then(|(_, port)| port.parse::<u16>().ok())
+        .unwrap_or(if is_secure { 443 } else { 80 });
+    SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port)
+}
+
+// Packet Sender
+struct PacketSender {
+    web_socket: WebSocket,
+    server_addr: Arc<Mutex<TransportAddr>>,
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Client Socket
+    fn send(&self, payload: &[u8]) -> Result<(), SendError> {
+        if self.web_socket.ready_state() != WebSocket::OPEN {
+            // still connecting, the packet is lost as it would be over WebRTC
+            return Ok(());
+        }
+        self.web_socket
+            .send_with_u8_array(payload)
+            .map_err(|_| SendError)
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        *self
+            .server_addr
+            .lock()
+            .expect("can't borrow 'server_addr' to get address!")
+    }
+}
+
+unsafe impl Send for PacketSender {}
+unsafe impl Sync for PacketSender {}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    message_queue: Arc<Mutex<VecDeque<Box<[u8]>>>>,
+    server_addr: Arc<Mutex<TransportAddr>>,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Client Socket
+    fn receive(&mut self) -> Result<Option<&[u8]>, RecvError> {
+        match self
+            .message_queue
+            .lock()
+            .expect("This should never happen, message_queue should always be available in a single-threaded context")
+            .pop_front()
+        {
+            Some(payload) => {
+                self.last_payload = Some(payload);
+                Ok(Some(self.last_payload.as_ref().unwrap()))
+            }
+            None => Ok(None),
+        }
+    }
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> TransportAddr {
+        *self
+            .server_addr
+            .lock()
+            .expect("can't borrow 'server_addr' to get address!")
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use naia_shared::{ComponentKind, DiffMask, MutReceiver};
+
+/// Tracks which Server Entities this Client has requested or been granted
+/// authority over, and the change trackers of the granted Entities' Components
+pub struct AuthorityManager<E: Copy + Eq + Hash> {
+    requested: HashSet<E>,
+    granted: HashMap<E, HashMap<ComponentKind, MutReceiver>>,
+}
+
+impl<E: Copy + Eq + Hash> AuthorityManager<E> {
+    pub fn new() -> Self {
+        Self {
+            requested: HashSet::new(),
+            granted: HashMap::new(),
+        }
+    }
+
+    /// Returns false if authority was already requested or granted
+    pub fn request(&mut self, entity: &E) -> bool {
+        if self.granted.contains_key(entity) {
+            return false;
+        }
+        self.requested.insert(*entity)
+    }
+
+    pub fn grant(&mut self, entity: &E) {
+        self.requested.remove(entity);
+        self.granted.entry(*entity).or_default();
+    }
+
+    pub fn deny(&mut self, entity: &E) {
+        self.requested.remove(entity);
+    }
+
+    /// Removes authority over the Entity, returning the Component kinds which
+    /// were being tracked
+    pub fn remove(&mut self, entity: &E) -> Option<Vec<ComponentKind>> {
+        self.requested.remove(entity);
+        self.granted
+            .remove(entity)
+            .map(|receivers| receivers.into_keys().collect())
+    }
+
+    pub fn has_authority(&self, entity: &E) -> bool {
+        self.granted.contains_key(entity)
+    }
+
+    pub fn granted_entities(&self) -> Vec<E> {
+        self.granted.keys().copied().collect()
+    }
+
+    pub fn component_kinds(&self, entity: &E) -> Vec<ComponentKind> {
+        self.granted
+            .get(entity)
+            .map(|receivers| receivers.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn track_component(
+        &mut self,
+        entity: &E,
+        component_kind: &ComponentKind,
+        receiver: MutReceiver,
+    ) {
+        if let Some(receivers) = self.granted.get_mut(entity) {
+            receivers.insert(*component_kind, receiver);
+        }
+    }
+
+    pub fn untrack_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        if let Some(receivers) = self.granted.get_mut(entity) {
+            receivers.remove(component_kind);
+        }
+    }
+
+    /// Takes the masks of all Components mutated since the last call, clearing
+    /// them
+    pub fn take_diff_masks(&self) -> Vec<(E, ComponentKind, DiffMask)> {
+        let mut output = Vec::new();
+        for (entity, receivers) in &self.granted {
+            for (component_kind, receiver) in receivers {
+                if receiver.diff_mask_is_clear() {
+                    continue;
+                }
+                let Some(diff_mask) = receiver.mask().map(|mask| mask.clone()) else {
+                    continue;
+                };
+                receiver.clear_mask();
+                output.push((*entity, *component_kind, diff_mask));
+            }
+        }
+        output
+    }
+
+    /// Copies the current masks, so that changes applied from the Server can be
+    /// undone with `restore_diff_masks()` and not sent back to the Server
+    pub fn copy_diff_masks(&self) -> Vec<(MutReceiver, DiffMask)> {
+        let mut output = Vec::new();
+        for receivers in self.granted.values() {
+            for receiver in receivers.values() {
+                if let Some(diff_mask) = receiver.mask().map(|mask| mask.clone()) {
+                    output.push((receiver.clone(), diff_mask));
+                }
+            }
+        }
+        output
+    }
+
+    pub fn restore_diff_masks(&self, diff_masks: Vec<(MutReceiver, DiffMask)>) {
+        for (receiver, diff_mask) in diff_masks {
+            receiver.clear_mask();
+            receiver.or_mask(&diff_mask);
+        }
+    }
+
+    /// Forgets all requests and grants, returning every tracked Component
+    pub fn clear(&mut self) -> Vec<(E, ComponentKind)> {
+        self.requested.clear();
+        let mut output = Vec::new();
+        for (entity, receivers) in self.granted.drain() {
+            for component_kind in receivers.into_keys() {
+                output.push((entity, component_kind));
+            }
+        }
+        output
+    }
+}
@@ -6,8 +6,8 @@ use std::{
 
 use naia_shared::{
     BigMap, ComponentKind, EntityAndGlobalEntityConverter, EntityDoesNotExistError,
-    GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType, MutChannelType, PropertyMutator,
-    Replicate,
+    GlobalDiffHandler, GlobalEntity, GlobalWorldManagerType, MutChannelType, MutReceiver,
+    PropertyMutator, Replicate,
 };
 
 use super::global_entity_record::GlobalEntityRecord;
@@ -108,6 +108,45 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
         component.set_mutator(&prop_mutator);
     }
 
+    // Authority
+
+    /// Tracks changes to a Component of a Server Entity this Client holds
+    /// authority over, returning the receiver of those changes
+    pub fn register_authority_component(
+        &mut self,
+        entity: &E,
+        component: &mut dyn Replicate,
+    ) -> MutReceiver {
+        let component_kind = component.kind();
+        let diff_mask_length: u8 = component.diff_mask_size();
+
+        let mut_sender = self
+            .diff_handler
+            .as_ref()
+            .write()
+            .expect("DiffHandler should be initialized")
+            .register_component(self, entity, &component_kind, diff_mask_length);
+
+        let prop_mutator = PropertyMutator::new(mut_sender);
+
+        component.set_mutator(&prop_mutator);
+
+        self.diff_handler
+            .as_ref()
+            .read()
+            .expect("DiffHandler should be initialized")
+            .receiver(&None, entity, &component_kind)
+            .expect("Component should have just been registered")
+    }
+
+    pub fn deregister_authority_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        self.diff_handler
+            .as_ref()
+            .write()
+            .expect("Haven't initialized DiffHandler")
+            .deregister_component(entity, component_kind);
+    }
+
     // Remove Component
     pub fn host_remove_component(&mut self, entity: &E, component_kind: &ComponentKind) {
         if !self.entity_records.contains_key(entity) {
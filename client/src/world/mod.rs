@@ -1,3 +1,4 @@
+pub mod authority_manager;
 pub mod entity_mut;
 pub mod entity_owner;
 pub mod global_entity_record;
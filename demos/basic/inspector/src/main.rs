@@ -0,0 +1,107 @@
+use std::{env, fs::File, io::BufReader, process};
+
+use naia_basic_demo_shared::protocol;
+use naia_shared::{Decoder, HostType, PacketCapture, PacketDirection, PacketInspector, Protocol};
+
+const USAGE: &str = "\
+usage:
+    naia-basic-inspector-demo capture <server|client> <file>
+        decodes every packet of a capture, recorded by the server or client
+    naia-basic-inspector-demo packet <server|client> <hex>
+        decodes one packet, sent by the server or client";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [command, host, input] = args.as_slice() else {
+        exit_with_usage();
+    };
+    let is_server = match host.as_str() {
+        "server" => true,
+        "client" => false,
+        _ => exit_with_usage(),
+    };
+
+    let protocol = protocol();
+    let inspector = PacketInspector::new(&protocol);
+
+    match command.as_str() {
+        "capture" => {
+            let file = File::open(input).unwrap_or_else(|error| exit(&error.to_string()));
+            let capture = PacketCapture::read(BufReader::new(file))
+                .unwrap_or_else(|error| exit(&error.to_string()));
+
+            for packet in capture.packets() {
+                // the Server receives what the Client sends, and vice versa
+                let sent_by_server = is_server == (packet.direction == PacketDirection::Outgoing);
+                let direction = if sent_by_server {
+                    "server -> client"
+                } else {
+                    "client -> server"
+                };
+                println!(
+                    "[{:.3}s] {} {}",
+                    packet.elapsed.as_secs_f32(),
+                    packet.address,
+                    direction
+                );
+                inspect(&protocol, &inspector, sent_by_server, &packet.payload);
+                println!();
+            }
+        }
+        "packet" => {
+            let Some(payload) = parse_hex(input) else {
+                exit("packet must be given as pairs of hex digits");
+            };
+            inspect(&protocol, &inspector, is_server, &payload);
+        }
+        _ => exit_with_usage(),
+    }
+}
+
+fn inspect(protocol: &Protocol, inspector: &PacketInspector, sent_by_server: bool, payload: &[u8]) {
+    let compression_mode = protocol.compression.as_ref().and_then(|config| {
+        if sent_by_server {
+            config.server_to_client.clone()
+        } else {
+            config.client_to_server.clone()
+        }
+    });
+    let mut decoder;
+    let payload = match compression_mode {
+        Some(compression_mode) => {
+            decoder = Decoder::new(compression_mode);
+            decoder.decode(payload)
+        }
+        None => payload,
+    };
+
+    let sender = if sent_by_server {
+        HostType::Server
+    } else {
+        HostType::Client
+    };
+    match inspector.inspect(sender, payload) {
+        Ok(packet) => println!("{}", packet),
+        Err(_) => println!("too short for a header: {:02x?}", payload),
+    }
+}
+
+fn parse_hex(input: &str) -> Option<Vec<u8>> {
+    let digits: Vec<char> = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let pairs = digits.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| u8::from_str_radix(&pair.iter().collect::<String>(), 16).ok())
+        .collect()
+}
+
+fn exit_with_usage() -> ! {
+    exit(USAGE)
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
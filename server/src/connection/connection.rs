@@ -3,9 +3,11 @@ use std::{hash::Hash, net::SocketAddr};
 use log::warn;
 
 use naia_shared::{
-    BaseConnection, BigMapKey, BitReader, BitWriter, ChannelKinds, ConnectionConfig,
-    EntityConverter, EntityEvent, HostType, HostWorldEvents, Instant, PacketType, Protocol, Serde,
-    SerdeErr, StandardHeader, Tick, WorldMutType, WorldRefType,
+    BaseConnection, BigMapKey, BitReader, BitWriter, ChannelKind, ChannelKinds,
+    ComponentCorrection as CorrectionMessage, ConnectionConfig, DiffMask, EntityAction,
+    EntityConverter, EntityConverterMut, EntityEvent, HostType, HostWorldEvents, Instant,
+    MessageContainer, PacketType, Protocol, Serde, SerdeErr, StandardHeader, Tick, UpdateCache,
+    ValidationChannel, WorldMutType, WorldRefType,
 };
 
 use crate::{
@@ -16,7 +18,10 @@ use crate::{
     events::Events,
     time_manager::TimeManager,
     user::UserKey,
-    world::global_world_manager::GlobalWorldManager,
+    world::{
+        component_validators::{ComponentCorrection, ComponentValidators},
+        global_world_manager::GlobalWorldManager,
+    },
 };
 
 use super::ping_manager::PingManager;
@@ -114,9 +119,23 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         &mut self,
         protocol: &Protocol,
         global_world_manager: &mut GlobalWorldManager<E>,
+        component_validators: &ComponentValidators<E>,
         world: &mut W,
         incoming_events: &mut Events<E>,
     ) {
+        // Receive acknowledgements of replicated Entities
+        for action in self.base.host_world_manager.take_acked_actions() {
+            match action {
+                EntityAction::SpawnEntity(entity, _) => {
+                    incoming_events.push_spawn_ack(&self.user_key, &entity);
+                }
+                EntityAction::InsertComponent(entity, component_kind) => {
+                    incoming_events.push_insert_ack(&self.user_key, &entity, &component_kind);
+                }
+                _ => {}
+            }
+        }
+
         // Receive Message Events
         let messages = self.base.message_manager.receive_messages(
             global_world_manager,
@@ -132,13 +151,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         // read world events
         if protocol.client_authoritative_entities {
             let remote_events = self.base.remote_world_reader.take_incoming_events();
+            let mut validator = component_validators.for_user(&self.user_key);
             let world_events = self.base.remote_world_manager.process_world_events(
                 global_world_manager,
                 &mut self.base.local_world_manager,
                 &protocol.component_kinds,
                 world,
+                &mut validator,
                 remote_events,
             );
+            self.send_corrections(
+                protocol,
+                global_world_manager,
+                world,
+                validator.take_corrections(),
+            );
             for event in &world_events {
                 if let EntityEvent::SpawnEntity(entity) = event {
                     global_world_manager.remote_spawn_entity_record(entity, &self.user_key);
@@ -148,6 +175,55 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         }
     }
 
+    /// Reverts rejected or clamped changes on the Client, by sending it the
+    /// Server's current value of each Component
+    fn send_corrections<W: WorldRefType<E>>(
+        &mut self,
+        protocol: &Protocol,
+        global_world_manager: &GlobalWorldManager<E>,
+        world: &W,
+        corrections: Vec<ComponentCorrection<E>>,
+    ) {
+        for correction in corrections {
+            let mut converter =
+                EntityConverterMut::new(global_world_manager, &mut self.base.local_world_manager);
+            let mut writer = BitWriter::new();
+
+            let (entity, remove) = match correction {
+                ComponentCorrection::Update(entity, component_kind) => {
+                    let Some(component) = world.component_of_kind(&entity, &component_kind) else {
+                        continue;
+                    };
+                    let mut diff_mask = DiffMask::new(component.diff_mask_size());
+                    for index in 0..(component.diff_mask_size() * 8) {
+                        diff_mask.set_bit(index, true);
+                    }
+                    component_kind.ser(&protocol.component_kinds, &mut writer);
+                    component.write_update(&diff_mask, &mut writer, &mut converter);
+                    (entity, false)
+                }
+                ComponentCorrection::Remove(entity, component_kind) => {
+                    component_kind.ser(&protocol.component_kinds, &mut writer);
+                    (entity, true)
+                }
+            };
+
+            let message = CorrectionMessage::new(
+                global_world_manager,
+                &entity,
+                remove,
+                writer.to_bytes().to_vec(),
+            );
+            let message = MessageContainer::from_write(Box::new(message), &mut converter);
+            self.base.message_manager.send_message(
+                &protocol.message_kinds,
+                &mut converter,
+                &ChannelKind::of::<ValidationChannel>(),
+                message,
+            );
+        }
+    }
+
     pub fn tick_buffer_messages(&mut self, tick: &Tick, messages: &mut TickBufferMessages) {
         let channel_messages = self.tick_buffer.receive_messages(tick);
         for (channel_kind, received_messages) in channel_messages {
@@ -166,6 +242,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         world: &W,
         global_world_manager: &GlobalWorldManager<E>,
         time_manager: &TimeManager,
+        update_cache: &mut UpdateCache<E>,
     ) {
         let rtt_millis = self.ping_manager.rtt_average;
         self.base.collect_outgoing_messages(now, &rtt_millis);
@@ -184,6 +261,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 global_world_manager,
                 time_manager,
                 &mut host_world_events,
+                update_cache,
             ) {
                 any_sent = true;
             } else {
@@ -206,6 +284,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
         global_world_manager: &GlobalWorldManager<E>,
         time_manager: &TimeManager,
         host_world_events: &mut HostWorldEvents<E>,
+        update_cache: &mut UpdateCache<E>,
     ) -> bool {
         if host_world_events.has_events() || self.base.message_manager.has_outgoing_messages() {
             let next_packet_index = self.base.next_packet_index();
@@ -241,6 +320,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Connection<E> {
                 &mut has_written,
                 true,
                 host_world_events,
+                update_cache,
             );
 
             // send packet
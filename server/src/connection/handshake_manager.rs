@@ -1,6 +1,9 @@
 use std::{collections::HashMap, hash::Hash, net::SocketAddr};
 
-use ring::{hmac, rand};
+use ring::{
+    hmac,
+    rand::{self, SecureRandom},
+};
 
 pub use naia_shared::{
     wrapping_diff, BaseConnection, BitReader, BitWriter, ConnectionConfig, FakeEntityConverter,
@@ -18,6 +21,7 @@ pub enum HandshakeResult {
 }
 
 pub struct HandshakeManager {
+    connection_hash_secret: Vec<u8>,
     connection_hash_key: hmac::Key,
     require_auth: bool,
     address_to_timestamp_map: HashMap<SocketAddr, Timestamp>,
@@ -26,10 +30,14 @@ pub struct HandshakeManager {
 
 impl HandshakeManager {
     pub fn new(require_auth: bool) -> Self {
-        let connection_hash_key =
-            hmac::Key::generate(hmac::HMAC_SHA256, &rand::SystemRandom::new()).unwrap();
+        let mut connection_hash_secret = vec![0; hmac::HMAC_SHA256.digest_algorithm().output_len];
+        rand::SystemRandom::new()
+            .fill(&mut connection_hash_secret)
+            .unwrap();
+        let connection_hash_key = hmac::Key::new(hmac::HMAC_SHA256, &connection_hash_secret);
 
         Self {
+            connection_hash_secret,
             connection_hash_key,
             require_auth,
             address_to_timestamp_map: HashMap::new(),
@@ -37,6 +45,19 @@ impl HandshakeManager {
         }
     }
 
+    /// The secret the challenge digests are signed with, which captures record
+    /// so their handshakes can be replayed
+    pub fn connection_hash_secret(&self) -> &[u8] {
+        &self.connection_hash_secret
+    }
+
+    /// Signs digests with the given secret, as the Server being replayed did
+    pub fn set_connection_hash_secret(&mut self, secret: &[u8]) {
+        self.connection_hash_secret = secret.to_vec();
+        self.connection_hash_key = hmac::Key::new(hmac::HMAC_SHA256, secret);
+        self.timestamp_digest_map = CacheMap::with_capacity(64);
+    }
+
     // Step 1 of Handshake
     pub fn recv_challenge_request(
         &mut self,
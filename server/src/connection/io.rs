@@ -1,6 +1,11 @@
-use std::{net::SocketAddr, panic, time::Duration};
+use std::{collections::HashMap, net::SocketAddr, panic, time::Duration};
 
-use naia_shared::{CompressionConfig, Decoder, Encoder, OutgoingPacket, OwnedBitReader};
+use log::warn;
+
+use naia_shared::{
+    CaptureWriter, CompressionConfig, Decoder, Encoder, Instant, OutgoingPacket, OwnedBitReader,
+    PacketDirection,
+};
 
 use super::bandwidth_monitor::BandwidthMonitor;
 use crate::{
@@ -8,13 +13,27 @@ use crate::{
     transport::{PacketReceiver, PacketSender},
 };
 
+/// A loaded transport, which Clients may connect through
+struct Transport {
+    packet_sender: Box<dyn PacketSender>,
+    packet_receiver: Box<dyn PacketReceiver>,
+}
+
 pub struct Io {
-    packet_sender: Option<Box<dyn PacketSender>>,
-    packet_receiver: Option<Box<dyn PacketReceiver>>,
+    transports: Vec<Transport>,
+    /// The transport each connected address arrived through, which replies to
+    /// it are routed back through for the life of its connection
+    connection_transports: HashMap<SocketAddr, usize>,
+    /// The transport each address still handshaking last sent a packet
+    /// through, and when
+    handshake_transports: HashMap<SocketAddr, (usize, Instant)>,
+    /// The transport to receive from first
+    next_transport: usize,
     outgoing_bandwidth_monitor: Option<BandwidthMonitor>,
     incoming_bandwidth_monitor: Option<BandwidthMonitor>,
     outgoing_encoder: Option<Encoder>,
     incoming_decoder: Option<Decoder>,
+    capture: Option<CaptureWriter>,
 }
 
 impl Io {
@@ -39,30 +58,60 @@ impl Io {
         });
 
         Io {
-            packet_sender: None,
-            packet_receiver: None,
+            transports: Vec::new(),
+            connection_transports: HashMap::new(),
+            handshake_transports: HashMap::new(),
+            next_transport: 0,
             outgoing_bandwidth_monitor,
             incoming_bandwidth_monitor,
             outgoing_encoder,
             incoming_decoder,
+            capture: None,
         }
     }
 
+    /// Adds a transport to receive packets from. May be called once for each
+    /// transport the Server listens on.
     pub fn load(
         &mut self,
         packet_sender: Box<dyn PacketSender>,
         packet_receiver: Box<dyn PacketReceiver>,
     ) {
-        if self.packet_sender.is_some() {
-            panic!("Packet sender/receiver already loaded! Cannot do this twice!");
+        self.transports.push(Transport {
+            packet_sender,
+            packet_receiver,
+        });
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        !self.transports.is_empty()
+    }
+
+    /// Fixes the transport an address is reached through to the one it
+    /// handshook on, once its connection is established
+    pub fn connect_address(&mut self, address: &SocketAddr) {
+        if let Some((transport_index, _)) = self.handshake_transports.remove(address) {
+            self.connection_transports.insert(*address, transport_index);
         }
+    }
 
-        self.packet_sender = Some(packet_sender);
-        self.packet_receiver = Some(packet_receiver);
+    /// Forgets which transport an address arrived on, once it is rejected or
+    /// disconnected
+    pub fn remove_address(&mut self, address: &SocketAddr) {
+        self.connection_transports.remove(address);
+        self.handshake_transports.remove(address);
     }
 
-    pub fn is_loaded(&self) -> bool {
-        self.packet_sender.is_some()
+    /// Forgets the transports of addresses which haven't sent a handshake
+    /// packet within the timeout
+    pub fn expire_handshakes(&mut self, timeout: &Duration) {
+        self.handshake_transports
+            .retain(|_, (_, last_heard)| last_heard.elapsed() < *timeout);
+    }
+
+    /// Records every packet sent & received from now on, or stops recording
+    pub fn set_capture(&mut self, capture: Option<CaptureWriter>) {
+        self.capture = capture;
     }
 
     pub fn send_packet(
@@ -70,6 +119,13 @@ impl Io {
         address: &SocketAddr,
         packet: OutgoingPacket,
     ) -> Result<(), NaiaServerError> {
+        if self.transports.is_empty() {
+            panic!("Cannot call Server.send_packet() until you call Server.listen()!");
+        }
+        let Some(transport_index) = self.address_transport(address) else {
+            return Err(NaiaServerError::SendError(*address));
+        };
+
         // get payload
         let mut payload = packet.slice();
 
@@ -83,37 +139,106 @@ impl Io {
             monitor.record_packet(address, payload.len());
         }
 
-        self.packet_sender
-            .as_ref()
-            .expect("Cannot call Server.send_packet() until you call Server.listen()!")
+        capture_packet(
+            &mut self.capture,
+            PacketDirection::Outgoing,
+            address,
+            payload,
+        );
+        self.transports[transport_index]
+            .packet_sender
             .send(address, payload)
             .map_err(|_| NaiaServerError::SendError(*address))
     }
 
+    /// The transport replies to an address are routed through
+    fn address_transport(&self, address: &SocketAddr) -> Option<usize> {
+        if let Some(transport_index) = self.connection_transports.get(address) {
+            return Some(*transport_index);
+        }
+        if let Some((transport_index, _)) = self.handshake_transports.get(address) {
+            return Some(*transport_index);
+        }
+        // with a single transport, there is only one way to reach an address
+        if self.transports.len() == 1 {
+            return Some(0);
+        }
+        return None;
+    }
+
+    /// The local addresses of every transport bound to addresses
+    pub fn local_addrs(&self) -> Vec<SocketAddr> {
+        self.transports
+            .iter()
+            .flat_map(|transport| transport.packet_sender.local_addrs())
+            .collect()
+    }
+
+    /// Sends the packets which transports have queued
+    pub fn flush(&mut self) {
+        for transport in &mut self.transports {
+            transport.packet_sender.flush();
+        }
+    }
+
     pub fn recv_reader(&mut self) -> Result<Option<(SocketAddr, OwnedBitReader)>, NaiaServerError> {
-        let receive_result = self
-            .packet_receiver
-            .as_mut()
-            .expect("Cannot call Server.receive_packet() until you call Server.listen()!")
-            .receive();
-
-        match receive_result {
-            Ok(Some((address, mut payload))) => {
-                // Bandwidth monitoring
-                if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
-                    monitor.record_packet(&address, payload.len());
-                }
+        if self.transports.is_empty() {
+            panic!("Cannot call Server.receive_packet() until you call Server.listen()!");
+        }
 
-                // Decompression
-                if let Some(decoder) = &mut self.incoming_decoder {
-                    payload = decoder.decode(payload);
-                }
+        // poll each transport in turn, starting after the last one received from,
+        // so that a busy transport cannot starve the others
+        let transport_count = self.transports.len();
+        for offset in 0..transport_count {
+            let transport_index = (self.next_transport + offset) % transport_count;
+            loop {
+                match self.transports[transport_index].packet_receiver.receive() {
+                    Ok(Some((address, mut payload))) => {
+                        // a connected address is only reachable through the
+                        // transport it connected through, packets claiming to be
+                        // from it on another transport are dropped
+                        if matches!(
+                            self.connection_transports.get(&address),
+                            Some(connection_transport) if *connection_transport != transport_index
+                        ) {
+                            continue;
+                        }
 
-                Ok(Some((address, OwnedBitReader::new(payload))))
+                        self.next_transport = (transport_index + 1) % transport_count;
+                        if !self.connection_transports.contains_key(&address) {
+                            self.handshake_transports
+                                .insert(address, (transport_index, Instant::now()));
+                        }
+
+                        capture_packet(
+                            &mut self.capture,
+                            PacketDirection::Incoming,
+                            &address,
+                            payload,
+                        );
+
+                        // Bandwidth monitoring
+                        if let Some(monitor) = &mut self.incoming_bandwidth_monitor {
+                            monitor.record_packet(&address, payload.len());
+                        }
+
+                        // Decompression
+                        if let Some(decoder) = &mut self.incoming_decoder {
+                            payload = decoder.decode(payload);
+                        }
+
+                        return Ok(Some((address, OwnedBitReader::new(payload))));
+                    }
+                    Ok(None) => break,
+                    Err(_) => {
+                        self.next_transport = (transport_index + 1) % transport_count;
+                        return Err(NaiaServerError::RecvError);
+                    }
+                }
             }
-            Ok(None) => Ok(None),
-            Err(_) => Err(NaiaServerError::RecvError),
         }
+
+        Ok(None)
     }
 
     pub fn bandwidth_monitor_enabled(&self) -> bool {
@@ -174,3 +299,167 @@ impl Io {
             .client_bandwidth(address);
     }
 }
+
+/// Records a packet, stopping the capture if it can't be written
+fn capture_packet(
+    capture: &mut Option<CaptureWriter>,
+    direction: PacketDirection,
+    address: &SocketAddr,
+    payload: &[u8],
+) {
+    let Some(writer) = capture else {
+        return;
+    };
+    if let Err(error) = writer.record(direction, address, payload) {
+        warn!("Packet capture stopped, could not record packet: {}", error);
+        *capture = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use naia_shared::{BitWriter, Instant};
+
+    use super::Io;
+    use crate::transport::{PacketReceiver, PacketSender, RecvError, SendError};
+
+    type SentPackets = Arc<Mutex<Vec<SocketAddr>>>;
+
+    struct TestSender {
+        sent: SentPackets,
+    }
+
+    impl PacketSender for TestSender {
+        fn send(&mut self, address: &SocketAddr, _payload: &[u8]) -> Result<(), SendError> {
+            self.sent.lock().unwrap().push(*address);
+            Ok(())
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestReceiver {
+        incoming: Arc<Mutex<VecDeque<SocketAddr>>>,
+        payload: Vec<u8>,
+    }
+
+    impl PacketReceiver for TestReceiver {
+        fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+            match self.incoming.lock().unwrap().pop_front() {
+                Some(address) => Ok(Some((address, &self.payload))),
+                None => Ok(None),
+            }
+        }
+    }
+
+    type IncomingPackets = Arc<Mutex<VecDeque<SocketAddr>>>;
+
+    fn load_transport(io: &mut Io, incoming: Vec<SocketAddr>) -> (SentPackets, IncomingPackets) {
+        let sent = SentPackets::default();
+        let incoming = IncomingPackets::new(Mutex::new(incoming.into()));
+        io.load(
+            Box::new(TestSender { sent: sent.clone() }),
+            Box::new(TestReceiver {
+                incoming: incoming.clone(),
+                payload: vec![1],
+            }),
+        );
+        (sent, incoming)
+    }
+
+    fn receive_all(io: &mut Io) -> Vec<SocketAddr> {
+        let mut received = Vec::new();
+        while let Ok(Some((address, _))) = io.recv_reader() {
+            received.push(address);
+        }
+        received
+    }
+
+    #[test]
+    fn replies_through_arrival_transport() {
+        let udp_address: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let webrtc_address: SocketAddr = "127.0.0.1:2000".parse().unwrap();
+
+        let mut io = Io::new(&None, &None);
+        let (udp_sent, _) = load_transport(&mut io, vec![udp_address]);
+        let (webrtc_sent, _) = load_transport(&mut io, vec![webrtc_address]);
+
+        assert_eq!(receive_all(&mut io), vec![udp_address, webrtc_address]);
+
+        assert!(io
+            .send_packet(&webrtc_address, BitWriter::new().to_packet())
+            .is_ok());
+        assert!(io
+            .send_packet(&udp_address, BitWriter::new().to_packet())
+            .is_ok());
+        assert_eq!(*udp_sent.lock().unwrap(), vec![udp_address]);
+        assert_eq!(*webrtc_sent.lock().unwrap(), vec![webrtc_address]);
+
+        // an address which never arrived cannot be routed
+        let unknown_address: SocketAddr = "127.0.0.1:3000".parse().unwrap();
+        assert!(io
+            .send_packet(&unknown_address, BitWriter::new().to_packet())
+            .is_err());
+    }
+
+    #[test]
+    fn drops_packets_from_other_transports() {
+        let address: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+
+        let mut io = Io::new(&None, &None);
+        let (udp_sent, _) = load_transport(&mut io, vec![address]);
+        let (webrtc_sent, webrtc_incoming) = load_transport(&mut io, Vec::new());
+
+        receive_all(&mut io);
+        io.connect_address(&address);
+
+        // a packet claiming to be from a connected address, arriving on
+        // another transport, is dropped & doesn't move its connection
+        webrtc_incoming.lock().unwrap().push_back(address);
+        assert!(receive_all(&mut io).is_empty());
+        assert!(io
+            .send_packet(&address, BitWriter::new().to_packet())
+            .is_ok());
+        assert_eq!(*udp_sent.lock().unwrap(), vec![address]);
+        assert!(webrtc_sent.lock().unwrap().is_empty());
+
+        // until it disconnects
+        io.remove_address(&address);
+        assert!(io
+            .send_packet(&address, BitWriter::new().to_packet())
+            .is_err());
+    }
+
+    #[test]
+    fn expires_quiet_handshakes() {
+        let address: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let timeout = Duration::from_secs(30);
+
+        let mut io = Io::new(&None, &None);
+        load_transport(&mut io, vec![address]);
+        load_transport(&mut io, Vec::new());
+
+        let start = Instant::now().inner();
+        Instant::simulate_now(Some(start));
+        receive_all(&mut io);
+
+        Instant::simulate_now(Some(start + timeout / 2));
+        io.expire_handshakes(&timeout);
+        assert!(io
+            .send_packet(&address, BitWriter::new().to_packet())
+            .is_ok());
+
+        Instant::simulate_now(Some(start + timeout));
+        io.expire_handshakes(&timeout);
+        assert!(io
+            .send_packet(&address, BitWriter::new().to_packet())
+            .is_err());
+        Instant::simulate_now(None);
+    }
+}
@@ -23,6 +23,12 @@ pub struct Events<E: Copy> {
     inserts: HashMap<ComponentKind, Vec<(UserKey, E)>>,
     removes: HashMap<ComponentKind, Vec<(UserKey, E, Box<dyn Replicate>)>>,
     updates: HashMap<ComponentKind, Vec<(UserKey, E)>>,
+    authority_requests: Vec<(UserKey, E)>,
+    authority_releases: Vec<(UserKey, E)>,
+    scope_enters: Vec<(UserKey, E)>,
+    scope_exits: Vec<(UserKey, E)>,
+    spawn_acks: Vec<(UserKey, E)>,
+    insert_acks: HashMap<ComponentKind, Vec<(UserKey, E)>>,
     empty: bool,
 }
 
@@ -40,6 +46,12 @@ impl<E: Copy> Events<E> {
             inserts: HashMap::new(),
             removes: HashMap::new(),
             updates: HashMap::new(),
+            authority_requests: Vec::new(),
+            authority_releases: Vec::new(),
+            scope_enters: Vec::new(),
+            scope_exits: Vec::new(),
+            spawn_acks: Vec::new(),
+            insert_acks: HashMap::new(),
             empty: true,
         }
     }
@@ -114,6 +126,18 @@ impl<E: Copy> Events<E> {
         }
     }
 
+    // These methods are exposed for adapter crates ... prefer using Events.read::<SomeEvent>() instead.
+    pub fn has_insert_acks(&self) -> bool {
+        !self.insert_acks.is_empty()
+    }
+    pub fn take_insert_acks(&mut self) -> Option<HashMap<ComponentKind, Vec<(UserKey, E)>>> {
+        if self.insert_acks.is_empty() {
+            return None;
+        } else {
+            return Some(mem::take(&mut self.insert_acks));
+        }
+    }
+
     // Crate-public
 
     pub(crate) fn push_connection(&mut self, user_key: &UserKey) {
@@ -146,6 +170,13 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn take_channel_messages(
+        &mut self,
+        channel_kind: &ChannelKind,
+    ) -> Option<HashMap<MessageKind, Vec<(UserKey, MessageContainer)>>> {
+        self.messages.remove(channel_kind)
+    }
+
     pub(crate) fn push_tick(&mut self, tick: Tick) {
         self.ticks.push(tick);
         self.empty = false;
@@ -210,6 +241,44 @@ impl<E: Copy> Events<E> {
         self.empty = false;
     }
 
+    pub(crate) fn push_authority_request(&mut self, user_key: &UserKey, entity: &E) {
+        self.authority_requests.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_authority_release(&mut self, user_key: &UserKey, entity: &E) {
+        self.authority_releases.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_scope_enter(&mut self, user_key: &UserKey, entity: &E) {
+        self.scope_enters.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_scope_exit(&mut self, user_key: &UserKey, entity: &E) {
+        self.scope_exits.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_spawn_ack(&mut self, user_key: &UserKey, entity: &E) {
+        self.spawn_acks.push((*user_key, *entity));
+        self.empty = false;
+    }
+
+    pub(crate) fn push_insert_ack(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) {
+        self.insert_acks
+            .entry(*component_kind)
+            .or_default()
+            .push((*user_key, *entity));
+        self.empty = false;
+    }
+
     pub(crate) fn receive_entity_events(
         &mut self,
         user_key: &UserKey,
@@ -320,6 +389,66 @@ impl<E: Copy> Event<E> for ErrorEvent {
     }
 }
 
+// Authority Request Event
+pub struct AuthorityRequestEvent;
+impl<E: Copy> Event<E> for AuthorityRequestEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.authority_requests);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.authority_requests.is_empty()
+    }
+}
+
+// Authority Release Event
+pub struct AuthorityReleaseEvent;
+impl<E: Copy> Event<E> for AuthorityReleaseEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.authority_releases);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.authority_releases.is_empty()
+    }
+}
+
+// Entity Scope Enter Event
+pub struct EntityScopeEnterEvent;
+impl<E: Copy> Event<E> for EntityScopeEnterEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.scope_enters);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.scope_enters.is_empty()
+    }
+}
+
+// Entity Scope Exit Event
+pub struct EntityScopeExitEvent;
+impl<E: Copy> Event<E> for EntityScopeExitEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.scope_exits);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.scope_exits.is_empty()
+    }
+}
+
 // Auth Event
 pub struct AuthEvent<M: Message> {
     phantom_m: PhantomData<M>,
@@ -465,6 +594,43 @@ impl<E: Copy, C: Replicate> Event<E> for InsertComponentEvent<C> {
     }
 }
 
+// Entity Spawn Acked Event
+pub struct EntitySpawnAckedEvent;
+impl<E: Copy> Event<E> for EntitySpawnAckedEvent {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let list = std::mem::take(&mut events.spawn_acks);
+        return IntoIterator::into_iter(list);
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        !events.spawn_acks.is_empty()
+    }
+}
+
+// Component Insert Acked Event
+pub struct ComponentInsertAckedEvent<C: Replicate> {
+    phantom_c: PhantomData<C>,
+}
+impl<E: Copy, C: Replicate> Event<E> for ComponentInsertAckedEvent<C> {
+    type Iter = IntoIter<(UserKey, E)>;
+
+    fn iter(events: &mut Events<E>) -> Self::Iter {
+        let component_kind: ComponentKind = ComponentKind::of::<C>();
+        if let Some(list) = events.insert_acks.remove(&component_kind) {
+            return IntoIterator::into_iter(list);
+        }
+
+        return IntoIterator::into_iter(Vec::new());
+    }
+
+    fn has(events: &Events<E>) -> bool {
+        let component_kind: ComponentKind = ComponentKind::of::<C>();
+        events.insert_acks.contains_key(&component_kind)
+    }
+}
+
 // Update Event
 pub struct UpdateComponentEvent<C: Replicate> {
     phantom_c: PhantomData<C>,
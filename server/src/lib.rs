@@ -15,7 +15,9 @@ extern crate cfg_if;
 
 pub mod transport;
 pub mod shared {
-    pub use naia_shared::{default_channels, EntityRef, Random, SocketConfig};
+    pub use naia_shared::{
+        default_channels, AssetHash, EntityRef, Random, SocketConfig, ValidationResult,
+    };
 }
 pub mod internal {
     pub use crate::connection::handshake_manager::{HandshakeManager, HandshakeResult};
@@ -36,9 +38,10 @@ mod world;
 pub use connection::tick_buffer_messages::TickBufferMessages;
 pub use error::NaiaServerError;
 pub use events::{
-    AuthEvent, ConnectEvent, DespawnEntityEvent, DisconnectEvent, ErrorEvent, Events,
-    InsertComponentEvent, MessageEvent, RemoveComponentEvent, SpawnEntityEvent, TickEvent,
-    UpdateComponentEvent,
+    AuthEvent, AuthorityReleaseEvent, AuthorityRequestEvent, ComponentInsertAckedEvent,
+    ConnectEvent, DespawnEntityEvent, DisconnectEvent, EntityScopeEnterEvent, EntityScopeExitEvent,
+    EntitySpawnAckedEvent, ErrorEvent, Events, InsertComponentEvent, MessageEvent,
+    RemoveComponentEvent, SpawnEntityEvent, TickEvent, UpdateComponentEvent,
 };
 pub use room::{RoomKey, RoomMut, RoomRef};
 pub use server::Server;
@@ -210,7 +210,48 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> RoomMut<'s, E> {
 
     pub fn broadcast_message<C: Channel, M: Message>(&mut self, message: &M) {
         let cloned_message = message.clone_box();
-        self.server
-            .room_broadcast_message(&ChannelKind::of::<C>(), &self.key, cloned_message);
+        self.server.room_broadcast_message(
+            &ChannelKind::of::<C>(),
+            &self.key,
+            cloned_message,
+            |_, _| true,
+        );
+    }
+
+    /// Sends a message to every User in the Room but one
+    pub fn broadcast_except<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+        let cloned_message = message.clone_box();
+        self.server.room_broadcast_message(
+            &ChannelKind::of::<C>(),
+            &self.key,
+            cloned_message,
+            |_, other_key| other_key != user_key,
+        );
+    }
+
+    /// Sends a message to every User in the Room an Entity is in scope for
+    pub fn broadcast_to_scope_of<C: Channel, M: Message>(&mut self, entity: &E, message: &M) {
+        let cloned_message = message.clone_box();
+        self.server.room_broadcast_message(
+            &ChannelKind::of::<C>(),
+            &self.key,
+            cloned_message,
+            |server, user_key| server.user_has_entity_in_scope(user_key, entity),
+        );
+    }
+
+    /// Sends a message to every User in the Room accepted by a filter
+    pub fn broadcast_filtered<C: Channel, M: Message, F: FnMut(&UserKey) -> bool>(
+        &mut self,
+        message: &M,
+        mut filter: F,
+    ) {
+        let cloned_message = message.clone_box();
+        self.server.room_broadcast_message(
+            &ChannelKind::of::<C>(),
+            &self.key,
+            cloned_message,
+            |_, user_key| filter(user_key),
+        );
     }
 }
@@ -1,6 +1,7 @@
 use std::{
     collections::{hash_set::Iter, HashMap, HashSet},
     hash::Hash,
+    io::Write,
     net::SocketAddr,
     panic,
     time::Duration,
@@ -12,10 +13,14 @@ use log::warn;
 use bevy_ecs::prelude::Resource;
 
 use naia_shared::{
-    BigMap, BitReader, BitWriter, Channel, ChannelKind, ComponentKind,
-    EntityAndGlobalEntityConverter, EntityConverterMut, EntityDoesNotExistError, EntityRef,
-    GlobalEntity, Instant, Message, MessageContainer, PacketType, Protocol, Replicate, Serde,
-    SerdeErr, SocketConfig, StandardHeader, Tick, Timer, WorldMutType, WorldRefType,
+    AssetChannel, AssetData, AssetHash, AssetOffer, AssetRequest, BigMap, BitReader, BitWriter,
+    CaptureWriter, Channel, ChannelKind, ComponentKind, EntityAndGlobalEntityConverter,
+    EntityAuthorityAction, EntityAuthorityChannel, EntityAuthorityMessage, EntityAuthorityUpdate,
+    EntityConverter, EntityConverterMut, EntityDoesNotExistError, EntityHierarchyChannel,
+    EntityParentRemoved, EntityParentSet, EntityRef, GlobalEntity, Instant, Message,
+    MessageContainer, MessageKind, PacketType, PropertyOverride, Protocol, Replicate, Serde,
+    SerdeErr, SocketConfig, StandardHeader, Tick, Timer, UpdateCache, ValidationResult,
+    WorldMutType, WorldRefType,
 };
 
 use crate::{
@@ -28,8 +33,9 @@ use crate::{
     time_manager::TimeManager,
     transport::Socket,
     world::{
-        entity_mut::EntityMut, entity_owner::EntityOwner, entity_scope_map::EntityScopeMap,
-        global_world_manager::GlobalWorldManager,
+        component_scope_map::ComponentScopeMap, component_validators::ComponentValidators,
+        entity_hierarchy::EntityHierarchy, entity_mut::EntityMut, entity_owner::EntityOwner,
+        entity_scope_map::EntityScopeMap, global_world_manager::GlobalWorldManager,
     },
 };
 
@@ -64,7 +70,13 @@ pub struct Server<E: Copy + Eq + Hash + Send + Sync> {
     // Entities
     entity_room_map: HashMap<E, RoomKey>,
     entity_scope_map: EntityScopeMap<E>,
+    component_scope_map: ComponentScopeMap<E>,
     global_world_manager: GlobalWorldManager<E>,
+    entity_authorities: HashMap<E, UserKey>,
+    entity_hierarchy: EntityHierarchy<E>,
+    component_validators: ComponentValidators<E>,
+    // Assets
+    assets: HashMap<AssetHash, Vec<u8>>,
     // Events
     incoming_events: Events<E>,
     // Ticks
@@ -103,7 +115,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             // Entities
             entity_room_map: HashMap::new(),
             entity_scope_map: EntityScopeMap::new(),
+            component_scope_map: ComponentScopeMap::new(),
             global_world_manager: GlobalWorldManager::new(),
+            entity_authorities: HashMap::new(),
+            entity_hierarchy: EntityHierarchy::new(),
+            component_validators: ComponentValidators::new(),
+            // Assets
+            assets: HashMap::new(),
             // Events
             incoming_events: Events::new(),
             // Ticks
@@ -111,7 +129,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         }
     }
 
-    /// Listen at the given addresses
+    /// Listen on the given transport. May be called once for each transport,
+    /// so that Clients can connect through any of them at the same time.
     pub fn listen<S: Into<Box<dyn Socket>>>(&mut self, socket: S) {
         let boxed_socket: Box<dyn Socket> = socket.into();
         let (packet_sender, packet_receiver) = boxed_socket.listen();
@@ -124,6 +143,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.io.is_loaded()
     }
 
+    /// Returns the local addresses the Server receives on, from every
+    /// transport bound to addresses. Useful for finding the port chosen when
+    /// listening on port 0
+    pub fn local_addrs(&self) -> Vec<SocketAddr> {
+        self.io.local_addrs()
+    }
+
     /// Returns socket config
     pub fn socket_config(&self) -> &SocketConfig {
         &self.protocol.socket
@@ -135,6 +161,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         // Need to run this to maintain connection with all clients, and receive packets
         // until none left
         self.maintain_socket(world);
+        // send any replies queued while receiving
+        self.io.flush();
 
         // tick event
         if self.time_manager.recv_server_tick() {
@@ -202,6 +230,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         }
 
         self.user_connections.insert(user.address, new_connection);
+        self.io.connect_address(&user.address);
         if self.io.bandwidth_monitor_enabled() {
             self.io.register_client(&user.address);
         }
@@ -273,17 +302,86 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     /// Sends a message to all connected users using a given channel
     pub fn broadcast_message<C: Channel, M: Message>(&mut self, message: &M) {
         let cloned_message = M::clone_box(message);
-        self.broadcast_message_inner(&ChannelKind::of::<C>(), cloned_message);
+        let user_keys = self.user_keys();
+        self.broadcast_message_inner(&ChannelKind::of::<C>(), cloned_message, user_keys);
+    }
+
+    /// Sends a message to all connected users but one, using a given channel
+    pub fn broadcast_except<C: Channel, M: Message>(&mut self, user_key: &UserKey, message: &M) {
+        self.broadcast_filtered::<C, M, _>(message, |other_key| other_key != user_key);
+    }
+
+    /// Sends a message to every user an Entity is currently in scope for,
+    /// using a given channel
+    pub fn broadcast_to_scope_of<C: Channel, M: Message>(&mut self, entity: &E, message: &M) {
+        let cloned_message = M::clone_box(message);
+        let user_keys = self
+            .user_keys()
+            .into_iter()
+            .filter(|user_key| self.user_has_entity_in_scope(user_key, entity))
+            .collect();
+        self.broadcast_message_inner(&ChannelKind::of::<C>(), cloned_message, user_keys);
+    }
+
+    /// Sends a message to every connected user accepted by a filter, using a
+    /// given channel
+    pub fn broadcast_filtered<C: Channel, M: Message, F: FnMut(&UserKey) -> bool>(
+        &mut self,
+        message: &M,
+        mut filter: F,
+    ) {
+        let cloned_message = M::clone_box(message);
+        let user_keys = self
+            .user_keys()
+            .into_iter()
+            .filter(|user_key| filter(user_key))
+            .collect();
+        self.broadcast_message_inner(&ChannelKind::of::<C>(), cloned_message, user_keys);
     }
 
-    fn broadcast_message_inner(
+    /// Sends a message to many users, serializing it only once unless it refers
+    /// to Entities
+    pub(crate) fn broadcast_message_inner(
         &mut self,
         channel_kind: &ChannelKind,
         message_box: Box<dyn Message>,
+        user_keys: Vec<UserKey>,
     ) {
-        self.user_keys().iter().for_each(|user_key| {
-            self.send_message_inner(user_key, channel_kind, message_box.clone())
-        })
+        let channel_settings = self.protocol.channel_kinds.channel(channel_kind);
+
+        if !channel_settings.can_send_to_client() {
+            panic!("Cannot send message to Client on this Channel");
+        }
+
+        let message =
+            match MessageContainer::from_write_shared(message_box, &self.protocol.message_kinds) {
+                Ok(message) => message,
+                Err(message_box) => {
+                    for user_key in &user_keys {
+                        self.send_message_inner(user_key, channel_kind, message_box.clone());
+                    }
+                    return;
+                }
+            };
+
+        for user_key in &user_keys {
+            let Some(user) = self.users.get(user_key) else {
+                continue;
+            };
+            let Some(connection) = self.user_connections.get_mut(&user.address) else {
+                continue;
+            };
+            let mut converter = EntityConverterMut::new(
+                &self.global_world_manager,
+                &mut connection.base.local_world_manager,
+            );
+            connection.base.message_manager.send_message(
+                &self.protocol.message_kinds,
+                &mut converter,
+                channel_kind,
+                message.clone(),
+            );
+        }
     }
 
     pub fn receive_tick_buffer_messages(&mut self, tick: &Tick) -> TickBufferMessages {
@@ -337,6 +435,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         // shuffle order of connections in order to avoid priority among users
         fastrand::shuffle(&mut user_addresses);
 
+        // updates shared by several connections are only serialized once
+        let mut update_cache = UpdateCache::new();
+
         for user_address in user_addresses {
             let connection = self.user_connections.get_mut(&user_address).unwrap();
 
@@ -347,8 +448,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 &world,
                 &self.global_world_manager,
                 &self.time_manager,
+                &mut update_cache,
             );
         }
+
+        self.io.flush();
     }
 
     // Entities
@@ -412,6 +516,235 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         return EntityOwner::Local;
     }
 
+    /// Returns whether a Client-owned Entity has been published to other Users
+    pub fn entity_is_published(&self, entity: &E) -> bool {
+        self.global_world_manager.entity_is_published(entity)
+    }
+
+    // Property Overrides
+
+    /// Sets a function which modifies the Component of type `R` on the given
+    /// Entity, as seen by one User only. Properties it changes are replicated
+    /// to that User with the overridden values, while all other Properties
+    /// continue to follow the Server's values. Replaces any previous override.
+    /// Does nothing if the User isn't connected.
+    pub fn set_property_override<R: Replicate>(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        property_override: impl Fn(&mut R) + Send + Sync + 'static,
+    ) {
+        let Some(user) = self.users.get(user_key) else {
+            warn!("Attempting to override Properties for a nonexistent User");
+            return;
+        };
+        let Some(connection) = self.user_connections.get_mut(&user.address) else {
+            return;
+        };
+        let property_override: PropertyOverride = Box::new(move |component: &mut dyn Replicate| {
+            let component = component
+                .to_any_mut()
+                .downcast_mut::<R>()
+                .expect("Component should be of overridden kind");
+            property_override(component);
+        });
+        connection.base.host_world_manager.set_property_override(
+            entity,
+            &ComponentKind::of::<R>(),
+            property_override,
+        );
+    }
+
+    /// Removes a User's override of the Component of type `R`, so that they
+    /// see the Server's values again. Does nothing if the User isn't connected.
+    pub fn clear_property_override<R: Replicate>(&mut self, user_key: &UserKey, entity: &E) {
+        let Some(user) = self.users.get(user_key) else {
+            warn!("Attempting to clear Property overrides of a nonexistent User");
+            return;
+        };
+        let Some(connection) = self.user_connections.get_mut(&user.address) else {
+            return;
+        };
+        connection
+            .base
+            .host_world_manager
+            .clear_property_override(entity, &ComponentKind::of::<R>());
+    }
+
+    // Validation
+
+    /// Registers a function which inspects every insert & update a Client makes
+    /// to a Component of type `R` on one of its own Entities, before it is
+    /// applied to the World. The function may modify the proposed Component
+    /// and return `ValidationResult::Clamp`, or return
+    /// `ValidationResult::Reject` to discard the change. Clamped or rejected
+    /// changes are reverted on the Client. `current` is None for inserts.
+    /// Must be called before the Server begins listening.
+    pub fn validate_component<R: Replicate>(
+        &mut self,
+        validator: impl Fn(&UserKey, &E, Option<&R>, &mut R) -> ValidationResult + Send + Sync + 'static,
+    ) {
+        if !self.protocol.client_authoritative_entities {
+            panic!("Cannot validate Components: Client Authoritative Entities are not enabled! Enable them in the Protocol, with the `enable_client_authoritative_entities()` method.");
+        }
+        if self.is_listening() {
+            panic!("Component validators must be registered before the Server begins listening");
+        }
+        self.component_validators.insert::<R>(validator);
+    }
+
+    // Authority
+
+    /// Gives the User associated with the given UserKey authority over a
+    /// Server-owned Entity which is in their scope. Updates the Client makes to
+    /// the Entity's Components are applied on the Server, and relayed to all
+    /// other Users in scope. Any previous holder's authority is revoked.
+    pub fn grant_authority(&mut self, user_key: &UserKey, entity: &E) {
+        self.check_entity_authority_allowed();
+
+        if self.entity_owner(entity) != EntityOwner::Server {
+            panic!("Can only grant authority over Entities owned by the Server");
+        }
+        if !self.user_has_entity_in_scope(user_key, entity) {
+            warn!("Server Error: cannot grant authority over an Entity which is not in the User's scope");
+            return;
+        }
+
+        if let Some(previous_user_key) = self.entity_authorities.insert(*entity, *user_key) {
+            if previous_user_key != *user_key {
+                self.send_authority_message(
+                    &previous_user_key,
+                    entity,
+                    EntityAuthorityAction::Revoke,
+                );
+            }
+        }
+        self.send_authority_message(user_key, entity, EntityAuthorityAction::Grant);
+    }
+
+    /// Refuses an authority request made by the User associated with the given
+    /// UserKey
+    pub fn deny_authority(&mut self, user_key: &UserKey, entity: &E) {
+        self.check_entity_authority_allowed();
+
+        self.send_authority_message(user_key, entity, EntityAuthorityAction::Deny);
+    }
+
+    /// Takes back authority over an Entity from the User that holds it, if any
+    pub fn revoke_authority(&mut self, entity: &E) {
+        self.check_entity_authority_allowed();
+
+        if let Some(user_key) = self.entity_authorities.remove(entity) {
+            self.send_authority_message(&user_key, entity, EntityAuthorityAction::Revoke);
+        }
+    }
+
+    /// Returns the UserKey of the User holding authority over an Entity, if any
+    pub fn entity_authority(&self, entity: &E) -> Option<UserKey> {
+        self.entity_authorities.get(entity).copied()
+    }
+
+    // Hierarchy
+
+    /// Makes an Entity the child of another. Children are in scope for exactly
+    /// the Users their root ancestor is in scope for, are spawned on Clients
+    /// after their parent, and are despawned along with it.
+    pub fn set_entity_parent(&mut self, child: &E, parent: &E) {
+        self.check_entity_hierarchy_allowed();
+
+        if self.entity_owner(child) != EntityOwner::Server
+            || self.entity_owner(parent) != EntityOwner::Server
+        {
+            panic!("Can only set parents between Entities owned by the Server");
+        }
+        if self.entity_hierarchy.set_parent(child, parent) == Some(*parent) {
+            return;
+        }
+
+        let mut user_keys = Vec::new();
+        for (_, connection) in self.user_connections.iter_mut() {
+            let host_world_manager = &connection.base.host_world_manager;
+            if !host_world_manager.host_has_entity(child) {
+                continue;
+            }
+            if host_world_manager.host_has_entity(parent) {
+                user_keys.push(connection.user_key);
+            } else {
+                // the child now follows its parent's scope
+                Self::despawn_entity_family(
+                    &self.entity_hierarchy,
+                    &mut self.entity_authorities,
+                    &mut self.incoming_events,
+                    connection,
+                    child,
+                );
+            }
+        }
+        for user_key in user_keys {
+            self.send_entity_parent(&user_key, child);
+        }
+    }
+
+    /// Detaches an Entity from its parent. The Entity then stays in scope
+    /// only of the Users who share a Room of its own, and include it in their
+    /// scope.
+    pub fn remove_entity_parent(&mut self, child: &E) {
+        self.check_entity_hierarchy_allowed();
+
+        if self.entity_hierarchy.remove_parent(child).is_some() {
+            self.release_orphaned_entity(child);
+        }
+    }
+
+    /// Returns the parent of an Entity, if any
+    pub fn entity_parent(&self, entity: &E) -> Option<E> {
+        self.entity_hierarchy.parent(entity)
+    }
+
+    /// Returns the children of an Entity
+    pub fn entity_children(&self, entity: &E) -> Vec<E> {
+        self.entity_hierarchy.children(entity)
+    }
+
+    // Assets
+
+    /// Registers the given bytes as an Asset which can be offered to Clients,
+    /// and returns the content hash that identifies it
+    pub fn register_asset(&mut self, bytes: Vec<u8>) -> AssetHash {
+        self.check_asset_cache_allowed();
+
+        let hash = AssetHash::of(&bytes);
+        self.assets.insert(hash, bytes);
+        hash
+    }
+
+    /// Removes a previously registered Asset. Returns true if the Asset existed.
+    pub fn unregister_asset(&mut self, hash: &AssetHash) -> bool {
+        self.assets.remove(hash).is_some()
+    }
+
+    /// Returns whether or not an Asset has been registered with the given hash
+    pub fn has_asset(&self, hash: &AssetHash) -> bool {
+        self.assets.contains_key(hash)
+    }
+
+    /// Offers a registered Asset to the Client associated with the given
+    /// UserKey. The Client only downloads the Asset if it is missing from its
+    /// local cache.
+    pub fn offer_asset(&mut self, user_key: &UserKey, hash: &AssetHash) {
+        self.check_asset_cache_allowed();
+
+        if !self.assets.contains_key(hash) {
+            panic!("Cannot offer an Asset which has not been registered with `Server.register_asset()`");
+        }
+
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<AssetChannel>(),
+            Box::new(AssetOffer::new(hash)),
+        );
+    }
+
     // Users
 
     /// Returns whether or not a User exists for the given RoomKey
@@ -545,6 +878,39 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.io.incoming_bandwidth_from_client(address)
     }
 
+    // Packet capture
+
+    /// Records every packet the Server sends & receives from now on, as it
+    /// crosses the wire, with its timestamp & address. Read the capture back
+    /// with `PacketCapture::read`, and replay it with `Server::replay`.
+    /// Captures hold the secret the Server signs handshakes with, so keep
+    /// them as private as the Server itself
+    pub fn start_capture(
+        &mut self,
+        writer: impl Write + Send + Sync + 'static,
+    ) -> Result<(), std::io::Error> {
+        let capture = CaptureWriter::new(writer, self.handshake_manager.connection_hash_secret())?;
+        self.io.set_capture(Some(capture));
+        Ok(())
+    }
+
+    /// Listens on a capture recorded with `Server::start_capture`, in place
+    /// of a socket, signing handshakes as the captured Server did. The Server
+    /// receives each captured packet at the point of the capture it was
+    /// received at, on a clock simulated for the calling thread, see
+    /// `CaptureReplay`
+    #[cfg(feature = "transport_replay")]
+    pub fn replay(&mut self, capture: naia_shared::PacketCapture) {
+        self.handshake_manager
+            .set_connection_hash_secret(capture.handshake_secret());
+        self.listen(crate::transport::replay::Socket::new(capture));
+    }
+
+    /// Stops recording packets, flushing the capture
+    pub fn stop_capture(&mut self) {
+        self.io.set_capture(None);
+    }
+
     // Ping
     /// Gets the average Round Trip Time measured to the given User's Client
     pub fn rtt(&self, user_key: &UserKey) -> Option<f32> {
@@ -579,6 +945,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             panic!("attempted to de-spawn nonexistent entity");
         }
 
+        // children are despawned along with their parent, before it
+        for descendant in self.entity_hierarchy.descendants(entity).iter().rev() {
+            if world.has_entity(descendant) {
+                world.despawn_entity(descendant);
+            }
+            self.despawn_entity_worldless(descendant);
+        }
+
         // Delete from world
         world.despawn_entity(entity);
 
@@ -586,15 +960,28 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     }
 
     pub fn despawn_entity_worldless(&mut self, entity: &E) {
+        // Authority is gone along with the Entity, the Client cleans up on despawn
+        self.entity_authorities.remove(entity);
+
+        // children which have not been despawned lose their parent
+        for child in self.entity_hierarchy.remove_entity(entity) {
+            self.release_orphaned_entity(&child);
+        }
+
         // TODO: we can make this more efficient in the future by caching which Entities
         // are in each User's scope
         for (_, connection) in self.user_connections.iter_mut() {
             //remove entity from user connection
-            connection.base.host_world_manager.despawn_entity(entity);
+            Self::despawn_connection_entity(&mut self.incoming_events, connection, entity);
+            connection
+                .base
+                .host_world_manager
+                .clear_entity_property_overrides(entity);
         }
 
         // Delete scope
         self.entity_scope_map.remove_entity(entity);
+        self.component_scope_map.remove_entity(entity);
 
         // Delete room cache entry
         self.entity_room_map.remove(entity);
@@ -603,6 +990,249 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         self.global_world_manager.host_despawn_entity(entity);
     }
 
+    /// Makes a Client-owned Entity replicate to other Users it shares a Room
+    /// with. The owning Client keeps write authority, and the Server relays
+    /// its updates onwards.
+    pub(crate) fn publish_entity<W: WorldMutType<E>>(&mut self, world: &mut W, entity: &E) {
+        self.check_client_authoritative_allowed();
+        let EntityOwner::Client(_) = self.entity_owner(entity) else {
+            panic!("Can only publish an Entity owned by a Client");
+        };
+        if !self.global_world_manager.publish_entity(entity) {
+            return;
+        }
+
+        for component_kind in world.component_kinds(entity) {
+            self.publish_component(world, entity, &component_kind);
+        }
+    }
+
+    /// Stops replicating a Client-owned Entity to other Users
+    pub(crate) fn unpublish_entity<W: WorldMutType<E>>(&mut self, world: &mut W, entity: &E) {
+        self.check_client_authoritative_allowed();
+        if !self.global_world_manager.unpublish_entity(entity) {
+            return;
+        }
+
+        for (_, connection) in self.user_connections.iter_mut() {
+            //remove entity from user connection
+            Self::despawn_connection_entity(&mut self.incoming_events, connection, entity);
+        }
+
+        for component_kind in self.global_world_manager.component_kinds(entity).unwrap() {
+            self.global_world_manager
+                .host_remove_component(entity, &component_kind);
+            self.unpublish_component(world, entity, &component_kind);
+        }
+    }
+
+    /// Replaces a Client's Component with a host-owned copy, which is tracked
+    /// for changes like any Server Component. Updates from the owning Client
+    /// are read into the copy through the authority read path.
+    fn publish_component<W: WorldMutType<E>>(
+        &mut self,
+        world: &mut W,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) {
+        let Some(remote_component) = world.remove_component_of_kind(entity, component_kind) else {
+            return;
+        };
+        let mut component = remote_component.copy_to_box();
+        self.insert_component_worldless(entity, component.as_mut());
+        world.insert_boxed_component(entity, component);
+
+        let owner_connection = self
+            .owner_address(entity)
+            .and_then(|address| self.user_connections.get_mut(&address));
+        if let Some(connection) = owner_connection {
+            connection
+                .base
+                .remote_world_manager
+                .insert_authority_component(entity, component_kind);
+        }
+    }
+
+    /// Replaces a published Component with a remote-owned copy once more, read
+    /// back from the Component as if it had been received from the owning
+    /// Client. Components of validated kinds stay host-owned, see
+    /// `validate_component()`.
+    fn unpublish_component<W: WorldMutType<E>>(
+        &mut self,
+        world: &mut W,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) {
+        let Some(host_component) = world.remove_component_of_kind(entity, component_kind) else {
+            return;
+        };
+        let validated = self.component_validators.validates(component_kind);
+        let owner_connection = self
+            .owner_address(entity)
+            .and_then(|address| self.user_connections.get_mut(&address));
+        let Some(connection) = owner_connection else {
+            world.insert_boxed_component(entity, host_component.copy_to_box());
+            return;
+        };
+        connection
+            .base
+            .remote_world_manager
+            .remove_authority_component(entity, component_kind);
+        if validated {
+            // an untracked copy
+            world.insert_boxed_component(entity, host_component.copy_to_box());
+            return;
+        }
+
+        let mut writer = BitWriter::new();
+        let mut converter_mut = EntityConverterMut::new(
+            &self.global_world_manager,
+            &mut connection.base.local_world_manager,
+        );
+        host_component.write(
+            &self.protocol.component_kinds,
+            &mut writer,
+            &mut converter_mut,
+        );
+        let bytes = writer.to_bytes();
+        let mut reader = BitReader::new(&bytes);
+        let converter = EntityConverter::new(
+            &self.global_world_manager,
+            &connection.base.local_world_manager,
+        );
+        match self.protocol.component_kinds.read(&mut reader, &converter) {
+            Ok(remote_component) => world.insert_boxed_component(entity, remote_component),
+            Err(_) => warn!("Server Error: cannot copy Component of unpublished Entity"),
+        }
+    }
+
+    /// The address of the User owning a Client-owned Entity
+    fn owner_address(&self, entity: &E) -> Option<SocketAddr> {
+        let EntityOwner::Client(user_key) = self.entity_owner(entity) else {
+            return None;
+        };
+        self.users.get(&user_key).map(|user| user.address)
+    }
+
+    /// Brings the tracking of published Entities in line with the inserts,
+    /// removes & despawns their owners have made
+    fn sync_published_entities<W: WorldMutType<E>>(&mut self, world: &mut W) {
+        for entity in self.global_world_manager.published_entities() {
+            if !self.global_world_manager.has_entity(&entity) {
+                // despawned by its owner
+                self.global_world_manager.unpublish_entity(&entity);
+                for (_, connection) in self.user_connections.iter_mut() {
+                    Self::despawn_connection_entity(&mut self.incoming_events, connection, &entity);
+                    connection
+                        .base
+                        .host_world_manager
+                        .clear_entity_property_overrides(&entity);
+                }
+                self.entity_scope_map.remove_entity(&entity);
+                self.component_scope_map.remove_entity(&entity);
+                self.entity_room_map.remove(&entity);
+                continue;
+            }
+
+            let tracked_kinds: HashSet<ComponentKind> = self
+                .global_world_manager
+                .component_kinds(&entity)
+                .unwrap()
+                .into_iter()
+                .collect();
+            let world_kinds: HashSet<ComponentKind> = world
+                .component_kinds(&entity)
+                .into_iter()
+                .filter(|component_kind| world.component_of_kind(&entity, component_kind).is_some())
+                .collect();
+
+            for component_kind in world_kinds.difference(&tracked_kinds) {
+                self.publish_component(world, &entity, component_kind);
+            }
+            for component_kind in tracked_kinds.difference(&world_kinds) {
+                self.remove_component_worldless(&entity, component_kind);
+            }
+        }
+    }
+
+    //// Hierarchy
+
+    /// Removes an Entity which has lost its parent from the scope of every
+    /// User it is out of scope of as a root Entity: one not in its Room, or
+    /// which hasn't included it in their scope
+    fn release_orphaned_entity(&mut self, entity: &E) {
+        let room = self
+            .entity_room_map
+            .get(entity)
+            .and_then(|room_key| self.rooms.get(room_key));
+        let mut user_keys = Vec::new();
+        for (_, connection) in self.user_connections.iter_mut() {
+            if !connection.base.host_world_manager.host_has_entity(entity) {
+                continue;
+            }
+            let user_key = connection.user_key;
+            let in_scope = room.is_some_and(|room| room.has_user(&user_key))
+                && self
+                    .global_world_manager
+                    .entity_can_enter_scope(entity, &user_key)
+                && self.entity_scope_map.get(&user_key, entity) == Some(&true);
+            if in_scope {
+                user_keys.push(user_key);
+            } else {
+                Self::despawn_entity_family(
+                    &self.entity_hierarchy,
+                    &mut self.entity_authorities,
+                    &mut self.incoming_events,
+                    connection,
+                    entity,
+                );
+            }
+        }
+        for user_key in user_keys {
+            let message = EntityParentRemoved::new(&self.global_world_manager, entity);
+            self.send_message_inner(
+                &user_key,
+                &ChannelKind::of::<EntityHierarchyChannel>(),
+                Box::new(message),
+            );
+        }
+    }
+
+    fn send_entity_parent(&mut self, user_key: &UserKey, child: &E) {
+        let Some(parent) = self.entity_hierarchy.parent(child) else {
+            return;
+        };
+        let message = EntityParentSet::new(&self.global_world_manager, child, &parent);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<EntityHierarchyChannel>(),
+            Box::new(message),
+        );
+    }
+
+    /// Removes an Entity & all of its descendants from a User's scope,
+    /// children first
+    fn despawn_entity_family(
+        entity_hierarchy: &EntityHierarchy<E>,
+        entity_authorities: &mut HashMap<E, UserKey>,
+        incoming_events: &mut Events<E>,
+        connection: &mut Connection<E>,
+        entity: &E,
+    ) {
+        let mut family = entity_hierarchy.descendants(entity);
+        family.reverse();
+        family.push(*entity);
+
+        for member in family {
+            //remove entity from user connection
+            Self::despawn_connection_entity(incoming_events, connection, &member);
+
+            if entity_authorities.get(&member) == Some(&connection.user_key) {
+                entity_authorities.remove(&member);
+            }
+        }
+    }
+
     //// Entity Scopes
 
     /// Remove all entities from a User's scope
@@ -620,6 +1250,48 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             .insert(*user_key, *entity, is_contained);
     }
 
+    pub(crate) fn user_scope_set_component(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+        is_contained: bool,
+    ) {
+        if !self
+            .component_scope_map
+            .set(user_key, entity, component_kind, is_contained)
+        {
+            return;
+        }
+
+        // update the User's connection, if the Entity is already in scope
+        let Some(user) = self.users.get(user_key) else {
+            return;
+        };
+        let Some(connection) = self.user_connections.get_mut(&user.address) else {
+            return;
+        };
+        let entity_has_component = self
+            .global_world_manager
+            .component_kinds(entity)
+            .map(|component_kinds| component_kinds.contains(component_kind))
+            .unwrap_or(false);
+        if !entity_has_component || !connection.base.host_world_manager.host_has_entity(entity) {
+            return;
+        }
+        if is_contained {
+            connection
+                .base
+                .host_world_manager
+                .insert_component(entity, component_kind);
+        } else {
+            connection
+                .base
+                .host_world_manager
+                .remove_component(entity, component_kind);
+        }
+    }
+
     //// Components
 
     /// Adds a Component to an Entity
@@ -658,6 +1330,12 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
 
         // add component to connections already tracking entity
         for (_, connection) in self.user_connections.iter_mut() {
+            if self
+                .component_scope_map
+                .is_excluded(&connection.user_key, entity, &component_kind)
+            {
+                continue;
+            }
             // insert component into user's connection
             if connection.base.host_world_manager.host_has_entity(entity) {
                 connection
@@ -696,6 +1374,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 .base
                 .host_world_manager
                 .remove_component(entity, &component_kind);
+            connection
+                .base
+                .host_world_manager
+                .clear_property_override(entity, &component_kind);
         }
 
         // cleanup all other loose ends
@@ -750,7 +1432,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         let Some(user) = self.users.get(user_key) else {
             panic!("Attempting to despawn entities for a nonexistent user");
         };
-        let Some (connection) = self.user_connections.get_mut(&user.address) else {
+        let Some(connection) = self.user_connections.get_mut(&user.address) else {
             panic!("Attempting to despawn entities on a nonexistent connection");
         };
 
@@ -766,9 +1448,17 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             panic!("Attempting to delete non-existant user!");
         };
 
-        self.user_connections.remove(&user.address);
+        if let Some(connection) = self.user_connections.remove(&user.address) {
+            // the User's Entities leave their scope along with them
+            for entity in connection.base.host_world_manager.host_entities() {
+                self.incoming_events.push_scope_exit(user_key, &entity);
+            }
+        }
         self.validated_users.remove(&user.address);
+        self.entity_authorities
+            .retain(|_, authority_user_key| authority_user_key != user_key);
         self.entity_scope_map.remove_user(user_key);
+        self.component_scope_map.remove_user(user_key);
         self.handshake_manager.delete_user(&user.address);
 
         // Clean up all user data
@@ -783,6 +1473,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         if self.io.bandwidth_monitor_enabled() {
             self.io.deregister_client(&user.address);
         }
+        self.io.remove_address(&user.address);
 
         return user;
     }
@@ -861,17 +1552,20 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
     }
 
     /// Sends a message to all connected users in a given Room using a given channel
-    pub(crate) fn room_broadcast_message(
+    pub(crate) fn room_broadcast_message<F: FnMut(&Self, &UserKey) -> bool>(
         &mut self,
         channel_kind: &ChannelKind,
         room_key: &RoomKey,
         message_box: Box<dyn Message>,
+        mut filter: F,
     ) {
         if let Some(room) = self.rooms.get(room_key) {
-            let user_keys: Vec<UserKey> = room.user_keys().cloned().collect();
-            for user_key in &user_keys {
-                self.send_message_inner(user_key, channel_kind, message_box.clone())
-            }
+            let user_keys: Vec<UserKey> = room
+                .user_keys()
+                .filter(|user_key| filter(self, user_key))
+                .cloned()
+                .collect();
+            self.broadcast_message_inner(channel_kind, message_box, user_keys);
         }
     }
 
@@ -953,7 +1647,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                         continue;
                     };
 
-                    let Ok(should_continue) = self.maintain_handshake(&address, &header, &mut reader) else {
+                    let Ok(should_continue) =
+                        self.maintain_handshake(&address, &header, &mut reader)
+                    else {
                         warn!("Server Error: cannot read malformed packet");
                         continue;
                     };
@@ -985,6 +1681,18 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         for address in addresses {
             self.process_packets(&address, &mut world);
         }
+
+        if self.protocol.asset_cache {
+            self.handle_asset_requests();
+        }
+
+        if self.protocol.entity_authority {
+            self.handle_authority_messages(&mut world);
+        }
+
+        if self.protocol.client_authoritative_entities {
+            self.sync_published_entities(&mut world);
+        }
     }
 
     fn maintain_handshake(
@@ -1144,16 +1852,222 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
         connection.process_packets(
             &self.protocol,
             &mut self.global_world_manager,
+            &self.component_validators,
             world,
             &mut self.incoming_events,
         );
     }
 
+    fn handle_asset_requests(&mut self) {
+        let Some(mut asset_messages) = self
+            .incoming_events
+            .take_channel_messages(&ChannelKind::of::<AssetChannel>())
+        else {
+            return;
+        };
+        let Some(requests) = asset_messages.remove(&MessageKind::of::<AssetRequest>()) else {
+            return;
+        };
+
+        for (user_key, message) in requests {
+            let request = message.to_boxed_any().downcast::<AssetRequest>().unwrap();
+            let Some(bytes) = self.assets.get(&request.hash) else {
+                warn!(
+                    "Server Error: User requested unknown Asset {}",
+                    request.hash
+                );
+                continue;
+            };
+            let data = AssetData::new(&request.hash, bytes.clone());
+            self.send_message_inner(
+                &user_key,
+                &ChannelKind::of::<AssetChannel>(),
+                Box::new(data),
+            );
+        }
+    }
+
+    fn handle_authority_messages<W: WorldMutType<E>>(&mut self, world: &mut W) {
+        let Some(mut authority_messages) = self
+            .incoming_events
+            .take_channel_messages(&ChannelKind::of::<EntityAuthorityChannel>())
+        else {
+            return;
+        };
+
+        // apply updates first, as a Client may send its last updates & release in the same tick
+        for (user_key, message) in authority_messages
+            .remove(&MessageKind::of::<EntityAuthorityUpdate>())
+            .unwrap_or_default()
+        {
+            let update = message
+                .to_boxed_any()
+                .downcast::<EntityAuthorityUpdate>()
+                .unwrap();
+            let Some(entity) = update.entity.get(&self.global_world_manager) else {
+                continue;
+            };
+            if self.entity_authorities.get(&entity) != Some(&user_key) {
+                // authority may have been revoked while this update was in flight
+                continue;
+            }
+            if self
+                .apply_authority_update(&user_key, &entity, &update.update, world)
+                .is_err()
+            {
+                warn!("Server Error: cannot read malformed Authority update");
+            }
+        }
+
+        for (user_key, message) in authority_messages
+            .remove(&MessageKind::of::<EntityAuthorityMessage>())
+            .unwrap_or_default()
+        {
+            let message = message
+                .to_boxed_any()
+                .downcast::<EntityAuthorityMessage>()
+                .unwrap();
+            let Some(entity) = message.entity.get(&self.global_world_manager) else {
+                continue;
+            };
+            match message.action {
+                EntityAuthorityAction::Request => {
+                    if self.entity_owner(&entity) != EntityOwner::Server {
+                        self.send_authority_message(
+                            &user_key,
+                            &entity,
+                            EntityAuthorityAction::Deny,
+                        );
+                        continue;
+                    }
+                    self.incoming_events
+                        .push_authority_request(&user_key, &entity);
+                }
+                EntityAuthorityAction::Release => {
+                    if self.entity_authorities.get(&entity) == Some(&user_key) {
+                        self.entity_authorities.remove(&entity);
+                        self.incoming_events
+                            .push_authority_release(&user_key, &entity);
+                    }
+                }
+                EntityAuthorityAction::Grant
+                | EntityAuthorityAction::Deny
+                | EntityAuthorityAction::Revoke => {
+                    warn!("Server Error: Client sent an Authority action only the Server may send");
+                }
+            }
+        }
+    }
+
+    fn apply_authority_update<W: WorldMutType<E>>(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        bytes: &[u8],
+        world: &mut W,
+    ) -> Result<(), SerdeErr> {
+        let Some(user) = self.users.get(user_key) else {
+            return Ok(());
+        };
+        let Some(connection) = self.user_connections.get_mut(&user.address) else {
+            return Ok(());
+        };
+
+        let mut reader = BitReader::new(bytes);
+        let update = self
+            .protocol
+            .component_kinds
+            .read_create_update(&mut reader)?;
+        let component_kind = update.kind;
+        if !world.has_component_of_kind(entity, &component_kind) {
+            return Ok(());
+        }
+
+        // applying the update marks the Component as mutated for every User in scope,
+        // but the authority holder already has these values, so their mask is restored
+        let diff_handler = &mut connection
+            .base
+            .host_world_manager
+            .world_channel
+            .diff_handler;
+        let holder_mask = diff_handler
+            .diff_mask(entity, &component_kind)
+            .map(|mask| mask.clone());
+
+        let converter = EntityConverter::new(
+            &self.global_world_manager,
+            &connection.base.local_world_manager,
+        );
+        let result = match world.component_mut_of_kind(entity, &component_kind) {
+            Some(mut component) => component.read_apply_authority_update(&converter, update),
+            None => Ok(()),
+        };
+
+        if let Some(holder_mask) = holder_mask {
+            diff_handler.clear_diff_mask(entity, &component_kind);
+            diff_handler.or_diff_mask(entity, &component_kind, &holder_mask);
+        }
+
+        result
+    }
+
+    fn send_authority_message(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        action: EntityAuthorityAction,
+    ) {
+        let message = EntityAuthorityMessage::new(&self.global_world_manager, entity, action);
+        self.send_message_inner(
+            user_key,
+            &ChannelKind::of::<EntityAuthorityChannel>(),
+            Box::new(message),
+        );
+    }
+
+    pub(crate) fn user_has_entity_in_scope(&self, user_key: &UserKey, entity: &E) -> bool {
+        let Some(user) = self.users.get(user_key) else {
+            return false;
+        };
+        let Some(connection) = self.user_connections.get(&user.address) else {
+            return false;
+        };
+        connection.base.host_world_manager.host_has_entity(entity)
+    }
+
+    fn check_client_authoritative_allowed(&self) {
+        if !self.protocol.client_authoritative_entities {
+            panic!("Cannot perform this operation: Client Authoritative Entities are not enabled! Enable them in the Protocol, with the `enable_client_authoritative_entities()` method.")
+        }
+    }
+
+    fn check_entity_hierarchy_allowed(&self) {
+        if !self.protocol.entity_hierarchy {
+            panic!("Cannot perform this operation: Entity Hierarchy is not enabled! Enable it in the Protocol, with the `enable_entity_hierarchy()` method.")
+        }
+    }
+
+    fn check_entity_authority_allowed(&self) {
+        if !self.protocol.entity_authority {
+            panic!("Cannot perform this operation: Entity Authority is not enabled! Enable it in the Protocol, with the `enable_entity_authority()` method.")
+        }
+    }
+
+    fn check_asset_cache_allowed(&self) {
+        if !self.protocol.asset_cache {
+            panic!("Cannot perform this operation: the Asset Cache is not enabled! Enable it in the Protocol, with the `enable_asset_cache()` method.")
+        }
+    }
+
     fn handle_disconnects<W: WorldMutType<E>>(&mut self, world: &mut W) {
         // disconnects
         if self.timeout_timer.ringing() {
             self.timeout_timer.reset();
 
+            // handshakes which have gone quiet
+            self.io
+                .expire_handshakes(&self.server_config.connection.disconnection_timeout_duration);
+
             let mut user_disconnects: Vec<UserKey> = Vec::new();
 
             for (_, connection) in &mut self.user_connections.iter_mut() {
@@ -1254,7 +2168,24 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
 
     // Entity Scopes
 
+    /// Removes an Entity from a User's scope, recording the transition
+    fn despawn_connection_entity(
+        incoming_events: &mut Events<E>,
+        connection: &mut Connection<E>,
+        entity: &E,
+    ) {
+        if !connection.base.host_world_manager.host_has_entity(entity) {
+            return;
+        }
+        connection.base.host_world_manager.despawn_entity(entity);
+        incoming_events.push_scope_exit(&connection.user_key, entity);
+    }
+
     fn update_entity_scopes<W: WorldRefType<E>>(&mut self, world: &W) {
+        // children entering scope are told of their parent once the Rooms are no
+        // longer borrowed
+        let mut entered_children: Vec<(UserKey, E)> = Vec::new();
+
         for (_, room) in self.rooms.iter_mut() {
             while let Some((removed_user, removed_entity)) = room.pop_entity_removal_queue() {
                 if let Some(user) = self.users.get(&removed_user) {
@@ -1262,11 +2193,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                         // TODO: evaluate whether the Entity really needs to be despawned!
                         // What if the Entity shares another Room with this User? It shouldn't be despawned!
 
-                        //remove entity from user connection
-                        connection
-                            .base
-                            .host_world_manager
-                            .despawn_entity(&removed_entity);
+                        Self::despawn_entity_family(
+                            &self.entity_hierarchy,
+                            &mut self.entity_authorities,
+                            &mut self.incoming_events,
+                            connection,
+                            &removed_entity,
+                        );
                     }
                 }
             }
@@ -1275,13 +2208,19 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
             // list each time
             for user_key in room.user_keys() {
                 for entity in room.entities() {
+                    // children share the scope of their root ancestor
+                    if self.entity_hierarchy.has_parent(entity) {
+                        continue;
+                    }
                     if world.has_entity(entity) {
                         if let Some(user) = self.users.get(user_key) {
                             if let Some(connection) = self.user_connections.get_mut(&user.address) {
-                                let currently_in_scope =
-                                    connection.base.host_world_manager.host_has_entity(entity);
-
-                                let should_be_in_scope = if let Some(in_scope) =
+                                let should_be_in_scope = if !self
+                                    .global_world_manager
+                                    .entity_can_enter_scope(entity, user_key)
+                                {
+                                    false
+                                } else if let Some(in_scope) =
                                     self.entity_scope_map.get(user_key, entity)
                                 {
                                     *in_scope
@@ -1290,21 +2229,63 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                                 };
 
                                 if should_be_in_scope {
-                                    if !currently_in_scope {
+                                    // parents are spawned before their children
+                                    let mut family = vec![*entity];
+                                    family.extend(self.entity_hierarchy.descendants(entity));
+
+                                    for member in family {
+                                        let host_world_manager =
+                                            &mut connection.base.host_world_manager;
+                                        if host_world_manager.host_has_entity(&member)
+                                            || !world.has_entity(&member)
+                                            || !self
+                                                .global_world_manager
+                                                .entity_can_enter_scope(&member, user_key)
+                                        {
+                                            continue;
+                                        }
+                                        let parent = self.entity_hierarchy.parent(&member);
+                                        if let Some(parent) = parent {
+                                            if !host_world_manager.host_has_entity(&parent) {
+                                                continue;
+                                            }
+                                        }
+
                                         let component_kinds = self
                                             .global_world_manager
-                                            .component_kinds(entity)
-                                            .unwrap();
+                                            .component_kinds(&member)
+                                            .unwrap()
+                                            .into_iter()
+                                            .filter(|component_kind| {
+                                                !self.component_scope_map.is_excluded(
+                                                    user_key,
+                                                    &member,
+                                                    component_kind,
+                                                )
+                                            })
+                                            .collect();
                                         // add entity & components to the connections local scope
-                                        connection.base.host_world_manager.init_entity(
+                                        host_world_manager.init_entity(
                                             &mut connection.base.local_world_manager,
-                                            entity,
+                                            &member,
                                             component_kinds,
                                         );
+                                        self.incoming_events.push_scope_enter(user_key, &member);
+
+                                        if parent.is_some() {
+                                            entered_children.push((*user_key, member));
+                                        }
                                     }
-                                } else if currently_in_scope {
+                                } else if connection.base.host_world_manager.host_has_entity(entity)
+                                {
                                     // remove entity from the connections local scope
-                                    connection.base.host_world_manager.despawn_entity(entity);
+                                    Self::despawn_entity_family(
+                                        &self.entity_hierarchy,
+                                        &mut self.entity_authorities,
+                                        &mut self.incoming_events,
+                                        connection,
+                                        entity,
+                                    );
                                 }
                             }
                         }
@@ -1312,6 +2293,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> Server<E> {
                 }
             }
         }
+
+        for (user_key, child) in entered_children {
+            self.send_entity_parent(&user_key, &child);
+        }
     }
 }
 
@@ -1,35 +1,52 @@
-use std::net::SocketAddr;
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
 
-use naia_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use naia_shared::{
+    link_condition_logic::{self, LinkState},
+    Instant, LinkConditionerSchedule, TimeQueue,
+};
 
-use super::{PacketReceiver, RecvError};
+use super::{PacketReceiver, PacketSender, RecvError, SendError};
 
 /// Used to receive packets from the Client Socket
 #[derive(Clone)]
 pub struct ConditionedPacketReceiver {
     inner_receiver: Box<dyn PacketReceiver>,
-    link_conditioner_config: LinkConditionerConfig,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_states: HashMap<SocketAddr, LinkState>,
     time_queue: TimeQueue<(SocketAddr, Box<[u8]>)>,
     last_payload: Option<Box<[u8]>>,
+    outgoing: OutgoingConditioner,
 }
 
 impl ConditionedPacketReceiver {
-    /// Creates a new ConditionedPacketReceiver
+    /// Creates a new ConditionedPacketReceiver, which also sends the packets
+    /// held by the given OutgoingConditioner as they become due
     pub fn new(
         inner_receiver: Box<dyn PacketReceiver>,
-        link_conditioner_config: &LinkConditionerConfig,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+        outgoing: OutgoingConditioner,
     ) -> Self {
         ConditionedPacketReceiver {
             inner_receiver,
-            link_conditioner_config: link_conditioner_config.clone(),
+            link_conditioner_schedule: link_conditioner_schedule.clone(),
+            started: Instant::now(),
+            link_states: HashMap::new(),
             time_queue: TimeQueue::new(),
             last_payload: None,
+            outgoing,
         }
     }
 }
 
 impl PacketReceiver for ConditionedPacketReceiver {
     fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+        self.outgoing.flush();
+
         loop {
             match self.inner_receiver.receive() {
                 Ok(option) => match option {
@@ -37,10 +54,15 @@ impl PacketReceiver for ConditionedPacketReceiver {
                         break;
                     }
                     Some((addr, buffer)) => {
-                        link_condition_logic::process_packet(
-                            &self.link_conditioner_config,
+                        let link_state = self.link_states.entry(addr).or_default();
+                        link_condition_logic::process_incoming_packet(
+                            &self
+                                .link_conditioner_schedule
+                                .config_at(self.started.elapsed()),
+                            link_state,
                             &mut self.time_queue,
                             (addr, buffer.into()),
+                            buffer.len(),
                         );
                     }
                 },
@@ -59,3 +81,85 @@ impl PacketReceiver for ConditionedPacketReceiver {
         }
     }
 }
+
+/// Holds outgoing packets until the time the link conditioner sends them
+#[derive(Clone)]
+pub struct OutgoingConditioner {
+    inner: Arc<Mutex<OutgoingConditionerInner>>,
+}
+
+struct OutgoingConditionerInner {
+    inner_sender: Box<dyn PacketSender>,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_states: HashMap<SocketAddr, LinkState>,
+    time_queue: TimeQueue<(SocketAddr, Box<[u8]>)>,
+}
+
+impl OutgoingConditioner {
+    pub fn new(
+        inner_sender: Box<dyn PacketSender>,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(OutgoingConditionerInner {
+                inner_sender,
+                link_conditioner_schedule: link_conditioner_schedule.clone(),
+                started: Instant::now(),
+                link_states: HashMap::new(),
+                time_queue: TimeQueue::new(),
+            })),
+        }
+    }
+
+    /// Sends every held packet which has become due. Packets which fail to
+    /// send are lost, as they would be on a real link
+    pub fn flush(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        while let Some((address, payload)) = inner.time_queue.pop_item() {
+            let _ = inner.inner_sender.send(&address, &payload);
+        }
+        inner.inner_sender.flush();
+    }
+}
+
+/// Used to send packets through the link conditioner
+pub struct ConditionedPacketSender {
+    outgoing: OutgoingConditioner,
+}
+
+impl ConditionedPacketSender {
+    pub fn new(outgoing: OutgoingConditioner) -> Self {
+        Self { outgoing }
+    }
+}
+
+impl PacketSender for ConditionedPacketSender {
+    fn send(&mut self, address: &SocketAddr, payload: &[u8]) -> Result<(), SendError> {
+        {
+            let mut guard = self.outgoing.inner.lock().unwrap();
+            let inner = &mut *guard;
+            let link_state = inner.link_states.entry(*address).or_default();
+            link_condition_logic::process_outgoing_packet(
+                &inner
+                    .link_conditioner_schedule
+                    .config_at(inner.started.elapsed()),
+                link_state,
+                &mut inner.time_queue,
+                (*address, payload.into()),
+                payload.len(),
+            );
+        }
+        self.outgoing.flush();
+        Ok(())
+    }
+
+    fn local_addrs(&self) -> Vec<SocketAddr> {
+        self.outgoing
+            .inner
+            .lock()
+            .unwrap()
+            .inner_sender
+            .local_addrs()
+    }
+}
@@ -9,6 +9,16 @@ cfg_if! {
         mod conditioner;
     } else {}
 }
+cfg_if! {
+    if #[cfg(feature = "transport_websocket")] {
+        pub mod websocket;
+    } else {}
+}
+cfg_if! {
+    if #[cfg(feature = "transport_replay")] {
+        pub mod replay;
+    } else {}
+}
 
 pub use inner::{PacketReceiver, PacketSender, RecvError, SendError, Socket};
 
@@ -26,7 +36,17 @@ mod inner {
 
     pub trait PacketSender: Send + Sync {
         /// Sends a packet to the Server Socket
-        fn send(&self, address: &SocketAddr, payload: &[u8]) -> Result<(), SendError>;
+        fn send(&mut self, address: &SocketAddr, payload: &[u8]) -> Result<(), SendError>;
+        /// Sends any packets the transport has queued. Called once the Server
+        /// finishes sending or receiving, transports which send each packet
+        /// right away needn't implement it
+        fn flush(&mut self) {}
+        /// The local addresses the transport receives on, for transports
+        /// bound to addresses. Useful for finding the port chosen when
+        /// binding port 0
+        fn local_addrs(&self) -> Vec<SocketAddr> {
+            Vec::new()
+        }
     }
 
     pub trait PacketReceiver: PacketReceiverClone + Send + Sync {
@@ -0,0 +1,125 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use naia_shared::{CaptureReplay, PacketCapture};
+
+use super::{
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    Socket as TransportSocket,
+};
+
+// Socket
+/// Replays the packets a Server received, from a capture recorded with
+/// `Server::start_capture`, at the points of the capture they were received
+/// at. Packets the Server sends are discarded, capture the replay to compare
+/// them. Use `Server::replay` to also sign handshakes as the captured Server
+/// did, which replaying a Client's handshake requires
+pub struct Socket {
+    capture: PacketCapture,
+}
+
+impl Socket {
+    pub fn new(capture: PacketCapture) -> Self {
+        return Self { capture };
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let sender = Box::new(PacketSender);
+        let receiver = Box::new(PacketReceiver::new(CaptureReplay::new(&self.capture)));
+
+        return (sender, receiver);
+    }
+}
+
+// Packet Sender
+struct PacketSender;
+
+impl TransportSender for PacketSender {
+    /// Discards a packet sent from the Server
+    fn send(&mut self, _socket_addr: &SocketAddr, _payload: &[u8]) -> Result<(), SendError> {
+        return Ok(());
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    replay: Arc<Mutex<CaptureReplay>>,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl PacketReceiver {
+    pub fn new(replay: CaptureReplay) -> Self {
+        return Self {
+            replay: Arc::new(Mutex::new(replay)),
+            last_payload: None,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives the captured packets due at this point of the replay
+    fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+        let Some(packet) = self.replay.lock().unwrap().receive() else {
+            return Ok(None);
+        };
+
+        self.last_payload = Some(packet.payload);
+        return Ok(Some((packet.address, self.last_payload.as_ref().unwrap())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, time::Duration};
+
+    use naia_shared::{CapturedPacket, Instant, PacketCapture, PacketDirection};
+
+    use super::{Socket, TransportSocket};
+
+    #[test]
+    fn replays_incoming_packets_in_order() {
+        let address: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let packet = |direction, millis, payload: &[u8]| CapturedPacket {
+            direction,
+            elapsed: Duration::from_millis(millis),
+            address,
+            payload: payload.into(),
+        };
+        let capture = PacketCapture::new(
+            Vec::new(),
+            vec![
+                packet(PacketDirection::Incoming, 500, &[1]),
+                packet(PacketDirection::Outgoing, 510, &[2]),
+                packet(PacketDirection::Incoming, 520, &[3]),
+                packet(PacketDirection::Incoming, 60_000, &[4]),
+            ],
+        );
+
+        let socket: Box<dyn TransportSocket> = Box::new(Socket::new(capture));
+        let (_, mut receiver) = socket.listen();
+        let start = Instant::now();
+
+        // each pass of receiving moves the replay on to the next packet
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            while let Ok(Some((packet_address, payload))) = receiver.receive() {
+                assert_eq!(packet_address, address);
+                received.push(payload[0]);
+            }
+        }
+        assert_eq!(received, [1, 3]);
+        // the last packet is a minute further into the capture
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,436 @@
+use std::{
+    io::{Error as IoError, ErrorKind},
+    mem,
+    net::{SocketAddr, UdpSocket},
+    os::fd::AsRawFd,
+    ptr,
+    sync::Arc,
+};
+
+use socket2::SockAddr;
+
+use super::MAX_PACKET_SIZE;
+
+/// The most packets read or written by one system call
+const BATCH_SIZE: usize = 32;
+/// Queued packets are sent once there are this many, even before a flush
+const MAX_QUEUED_PACKETS: usize = 256;
+/// The kernel refuses to split one send into more segments than this
+const MAX_GSO_SEGMENTS: usize = 64;
+/// The largest UDP payload, which bounds the size of a segmented send
+const MAX_GSO_SIZE: usize = 65507;
+
+// Batch Reader
+/// Reads packets from a socket with `recvmmsg`, a batch at a time
+#[derive(Clone)]
+pub struct BatchReader {
+    socket: Arc<UdpSocket>,
+    buffers: Vec<[u8; MAX_PACKET_SIZE]>,
+    headers: ReadHeaders,
+    /// The address, buffer index & length of each packet of the last batch
+    received: Vec<(SocketAddr, usize, usize)>,
+    next: usize,
+}
+
+/// The headers `recvmmsg` fills in, kept between batches so that reading
+/// allocates nothing. Their pointers are set again before every call, as the
+/// reader may have been moved or cloned since the last
+#[derive(Clone)]
+struct ReadHeaders {
+    addresses: Vec<libc::sockaddr_storage>,
+    iovecs: Vec<libc::iovec>,
+    messages: Vec<libc::mmsghdr>,
+}
+
+// SAFETY: the pointers within the headers are only dereferenced by
+// `recvmmsg`, after being pointed at buffers of the reader which owns them
+unsafe impl Send for ReadHeaders {}
+unsafe impl Sync for ReadHeaders {}
+
+impl ReadHeaders {
+    fn new() -> Self {
+        // SAFETY: all of these are plain C structs, for which zeroes are valid
+        return Self {
+            addresses: vec![unsafe { mem::zeroed() }; BATCH_SIZE],
+            iovecs: vec![unsafe { mem::zeroed() }; BATCH_SIZE],
+            messages: vec![unsafe { mem::zeroed() }; BATCH_SIZE],
+        };
+    }
+}
+
+impl BatchReader {
+    pub fn new(socket: Arc<UdpSocket>) -> Self {
+        return Self {
+            socket,
+            buffers: vec![[0; MAX_PACKET_SIZE]; BATCH_SIZE],
+            headers: ReadHeaders::new(),
+            received: Vec::with_capacity(BATCH_SIZE),
+            next: 0,
+        };
+    }
+
+    /// Returns whether a packet is ready to take, reading the next batch once
+    /// the last has been taken
+    pub fn poll(&mut self) -> Result<bool, IoError> {
+        if self.next < self.received.len() {
+            return Ok(true);
+        }
+        self.received.clear();
+        self.next = 0;
+        self.read_batch()?;
+        return Ok(!self.received.is_empty());
+    }
+
+    /// Takes the next packet, after `poll` has returned true
+    pub fn take(&mut self) -> (SocketAddr, &[u8]) {
+        let (address, index, length) = self.received[self.next];
+        self.next += 1;
+        return (address, &self.buffers[index][..length]);
+    }
+
+    // the types of some fields & arguments differ between libcs
+    #[allow(trivial_numeric_casts)]
+    fn read_batch(&mut self) -> Result<(), IoError> {
+        let headers = &mut self.headers;
+        for (index, buffer) in self.buffers.iter_mut().enumerate() {
+            headers.iovecs[index] = libc::iovec {
+                iov_base: buffer.as_mut_ptr().cast(),
+                iov_len: buffer.len(),
+            };
+            let header = &mut headers.messages[index].msg_hdr;
+            header.msg_name = ptr::addr_of_mut!(headers.addresses[index]).cast();
+            header.msg_namelen = mem::size_of::<libc::sockaddr_storage>() as _;
+            header.msg_iov = ptr::addr_of_mut!(headers.iovecs[index]);
+            header.msg_iovlen = 1;
+            header.msg_flags = 0;
+        }
+
+        // SAFETY: every message points at an address & buffer which outlive
+        // the call, and at most BATCH_SIZE messages are written
+        let count = unsafe {
+            libc::recvmmsg(
+                self.socket.as_raw_fd(),
+                headers.messages.as_mut_ptr(),
+                BATCH_SIZE as _,
+                libc::MSG_DONTWAIT as _,
+                ptr::null_mut(),
+            )
+        };
+        if count < 0 {
+            let error = IoError::last_os_error();
+            if error.kind() == ErrorKind::WouldBlock {
+                return Ok(());
+            }
+            return Err(error);
+        }
+
+        for (index, message) in headers.messages.iter().take(count as usize).enumerate() {
+            // packets larger than the buffer are larger than any naia sends
+            if message.msg_hdr.msg_flags & libc::MSG_TRUNC != 0 {
+                continue;
+            }
+            // SAFETY: the kernel filled in the address & its length
+            let address =
+                unsafe { SockAddr::new(headers.addresses[index], message.msg_hdr.msg_namelen) };
+            if let Some(address) = address.as_socket() {
+                self.received
+                    .push((address, index, message.msg_len as usize));
+            }
+        }
+        return Ok(());
+    }
+}
+
+// Batch Writer
+/// Queues packets for a socket, and writes them with `sendmmsg` when flushed
+pub struct BatchWriter {
+    socket: Arc<UdpSocket>,
+    queue: Queue,
+    /// Whether equally sized packets to one address are coalesced, for the
+    /// kernel to segment. Turned off if the kernel refuses
+    gso: bool,
+}
+
+#[derive(Default)]
+struct Queue {
+    data: Vec<u8>,
+    /// The address, start & length in `data` of each queued packet
+    packets: Vec<(SocketAddr, usize, usize)>,
+}
+
+impl Queue {
+    /// Removes the first `count` packets, which have been sent, keeping the
+    /// rest queued
+    fn remove_sent(&mut self, count: usize) {
+        let Some((_, sent_length, _)) = self.packets.get(count).copied() else {
+            self.data.clear();
+            self.packets.clear();
+            return;
+        };
+        self.data.drain(..sent_length);
+        self.packets.drain(..count);
+        for (_, start, _) in &mut self.packets {
+            *start -= sent_length;
+        }
+    }
+}
+
+/// One send, which may carry several segments of equal size (except for the
+/// last, which may be smaller)
+#[derive(Clone, Copy)]
+struct Datagram {
+    address: SocketAddr,
+    start: usize,
+    length: usize,
+    segment_size: usize,
+    segments: usize,
+}
+
+impl BatchWriter {
+    pub fn new(socket: Arc<UdpSocket>, gso: bool) -> Self {
+        return Self {
+            socket,
+            queue: Queue::default(),
+            gso,
+        };
+    }
+
+    /// Queues a packet. A packet which finds the queue full, because the
+    /// socket's buffer is, is lost, as it would be on a congested link
+    pub fn send(&mut self, address: SocketAddr, payload: &[u8]) {
+        if self.queue.packets.len() >= MAX_QUEUED_PACKETS {
+            self.send_queue();
+            if self.queue.packets.len() >= MAX_QUEUED_PACKETS {
+                return;
+            }
+        }
+
+        let start = self.queue.data.len();
+        self.queue.packets.push((address, start, payload.len()));
+        self.queue.data.extend_from_slice(payload);
+    }
+
+    pub fn flush(&mut self) {
+        self.send_queue();
+    }
+
+    /// Sends as many queued packets as the socket takes. Those left once its
+    /// buffer is full stay queued for the next flush
+    fn send_queue(&mut self) {
+        if self.queue.packets.is_empty() {
+            return;
+        }
+
+        let mut datagrams = group_packets(&self.queue.packets, self.gso);
+        let mut sent = 0;
+        // the packets within the datagrams sent, or skipped, so far
+        let mut sent_packets = 0;
+        while sent < datagrams.len() {
+            let end = datagrams.len().min(sent + BATCH_SIZE);
+            match self.send_batch(&datagrams[sent..end]) {
+                Ok(count) => {
+                    sent_packets += datagrams[sent..sent + count]
+                        .iter()
+                        .map(|datagram| datagram.segments)
+                        .sum::<usize>();
+                    sent += count;
+                }
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(_) if datagrams[sent].segments > 1 => {
+                    // the kernel or device can't segment, send each packet
+                    // on its own from now on
+                    self.gso = false;
+                    datagrams = split_datagrams(&datagrams[sent..]);
+                    sent = 0;
+                }
+                // skip a packet which can't be sent, to an unreachable
+                // address for example
+                Err(_) => {
+                    sent_packets += datagrams[sent].segments;
+                    sent += 1;
+                }
+            }
+        }
+
+        self.queue.remove_sent(sent_packets);
+    }
+
+    // the types of some fields & arguments differ between libcs
+    #[allow(trivial_numeric_casts)]
+    fn send_batch(&self, datagrams: &[Datagram]) -> Result<usize, IoError> {
+        let data = &self.queue.data;
+        let addresses: Vec<SockAddr> = datagrams
+            .iter()
+            .map(|datagram| SockAddr::from(datagram.address))
+            .collect();
+        let mut iovecs: Vec<libc::iovec> = datagrams
+            .iter()
+            .map(|datagram| libc::iovec {
+                iov_base: data[datagram.start..].as_ptr() as *mut _,
+                iov_len: datagram.length,
+            })
+            .collect();
+        // cmsghdrs give the control buffers the alignment they need
+        // SAFETY: all of these are plain C structs, for which zeroes are valid
+        let mut controls: Vec<[libc::cmsghdr; 2]> = vec![unsafe { mem::zeroed() }; datagrams.len()];
+        let mut messages: Vec<libc::mmsghdr> = vec![unsafe { mem::zeroed() }; datagrams.len()];
+
+        for (index, message) in messages.iter_mut().enumerate() {
+            let header = &mut message.msg_hdr;
+            header.msg_name = addresses[index].as_ptr() as *mut _;
+            header.msg_namelen = addresses[index].len();
+            header.msg_iov = ptr::addr_of_mut!(iovecs[index]);
+            header.msg_iovlen = 1;
+
+            let datagram = &datagrams[index];
+            if datagram.segments > 1 {
+                // SAFETY: the control buffer has room for one u16 message,
+                // and outlives the call
+                unsafe {
+                    header.msg_control = controls[index].as_mut_ptr().cast();
+                    header.msg_controllen = libc::CMSG_SPACE(mem::size_of::<u16>() as _) as _;
+                    let control = libc::CMSG_FIRSTHDR(header);
+                    (*control).cmsg_level = libc::SOL_UDP;
+                    (*control).cmsg_type = libc::UDP_SEGMENT;
+                    (*control).cmsg_len = libc::CMSG_LEN(mem::size_of::<u16>() as _) as _;
+                    ptr::write_unaligned(
+                        libc::CMSG_DATA(control).cast::<u16>(),
+                        datagram.segment_size as u16,
+                    );
+                }
+            }
+        }
+
+        // SAFETY: every message points at an address, buffer & control
+        // buffer which outlive the call
+        let count = unsafe {
+            libc::sendmmsg(
+                self.socket.as_raw_fd(),
+                messages.as_mut_ptr(),
+                messages.len() as _,
+                0,
+            )
+        };
+        if count < 0 {
+            return Err(IoError::last_os_error());
+        }
+        return Ok(count as usize);
+    }
+}
+
+/// Coalesces consecutive packets to the same address into segmented
+/// datagrams, when GSO is enabled
+fn group_packets(packets: &[(SocketAddr, usize, usize)], gso: bool) -> Vec<Datagram> {
+    let mut datagrams: Vec<Datagram> = Vec::with_capacity(packets.len());
+    for (address, start, length) in packets.iter().copied() {
+        if gso {
+            if let Some(last) = datagrams.last_mut() {
+                if last.can_append(&address, length) {
+                    last.length += length;
+                    last.segments += 1;
+                    continue;
+                }
+            }
+        }
+        datagrams.push(Datagram {
+            address,
+            start,
+            length,
+            segment_size: length,
+            segments: 1,
+        });
+    }
+    return datagrams;
+}
+
+/// Splits segmented datagrams back into their packets
+fn split_datagrams(datagrams: &[Datagram]) -> Vec<Datagram> {
+    let mut split = Vec::new();
+    for datagram in datagrams {
+        let mut offset = 0;
+        while offset < datagram.length {
+            let length = datagram.segment_size.min(datagram.length - offset);
+            split.push(Datagram {
+                address: datagram.address,
+                start: datagram.start + offset,
+                length,
+                segment_size: length,
+                segments: 1,
+            });
+            offset += length;
+        }
+    }
+    return split;
+}
+
+impl Datagram {
+    /// Whether the next queued packet can join this datagram. Only the last
+    /// segment may be shorter than the rest
+    fn can_append(&self, address: &SocketAddr, length: usize) -> bool {
+        return self.address == *address
+            && length > 0
+            && length <= self.segment_size
+            && self.length == self.segment_size * self.segments
+            && self.segments < MAX_GSO_SEGMENTS
+            && self.length + length <= MAX_GSO_SIZE;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use super::{group_packets, split_datagrams, Queue};
+
+    #[test]
+    fn groups_equal_packets_to_one_address() {
+        let first: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let second: SocketAddr = "127.0.0.1:2000".parse().unwrap();
+        let packets = [
+            (first, 0, 100),
+            (first, 100, 100),
+            (first, 200, 40),
+            (first, 240, 100),
+            (second, 340, 100),
+        ];
+
+        let datagrams = group_packets(&packets, true);
+        let shapes: Vec<(usize, usize, usize)> = datagrams
+            .iter()
+            .map(|datagram| (datagram.start, datagram.length, datagram.segments))
+            .collect();
+        // a short segment ends a datagram
+        assert_eq!(shapes, [(0, 240, 3), (240, 100, 1), (340, 100, 1)]);
+
+        assert_eq!(group_packets(&packets, false).len(), packets.len());
+
+        let split: Vec<(usize, usize)> = split_datagrams(&datagrams)
+            .iter()
+            .map(|datagram| (datagram.start, datagram.length))
+            .collect();
+        let expected: Vec<(usize, usize)> = packets
+            .iter()
+            .map(|(_, start, length)| (*start, *length))
+            .collect();
+        assert_eq!(split, expected);
+    }
+
+    #[test]
+    fn keeps_unsent_packets_queued() {
+        let address: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let mut queue = Queue::default();
+        for (index, length) in [3, 2, 4].into_iter().enumerate() {
+            let start = queue.data.len();
+            queue.packets.push((address, start, length));
+            queue.data.extend(vec![index as u8; length]);
+        }
+
+        queue.remove_sent(1);
+        assert_eq!(queue.packets, [(address, 0, 2), (address, 2, 4)]);
+        assert_eq!(queue.data, [1, 1, 2, 2, 2, 2]);
+
+        queue.remove_sent(2);
+        assert!(queue.packets.is_empty());
+        assert!(queue.data.is_empty());
+    }
+}
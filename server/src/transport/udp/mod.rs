@@ -0,0 +1,612 @@
+use std::{
+    io::{Error as IoError, ErrorKind},
+    net::{SocketAddr, SocketAddrV6, UdpSocket},
+    sync::Arc,
+};
+
+use socket2::{Domain, Protocol, Socket as RawSocket, Type};
+
+use naia_shared::{LinkConditionerConfig, LinkConditionerSchedule};
+
+use super::{
+    conditioner::{ConditionedPacketReceiver, ConditionedPacketSender, OutgoingConditioner},
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    Socket as TransportSocket,
+};
+
+cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        mod batch;
+        use batch::{BatchReader, BatchWriter};
+    } else {}
+}
+
+/// The largest packet received, the most which fits in an Ethernet frame
+const MAX_PACKET_SIZE: usize = 1472;
+
+/// Options applied to every UDP socket the Server binds
+#[derive(Clone, Default)]
+pub struct SocketOptions {
+    /// Lets several processes bind the same address (SO_REUSEPORT), so that
+    /// the kernel balances Clients between them. Unix only
+    pub reuse_port: bool,
+    /// The size of the kernel's send buffer in bytes (SO_SNDBUF)
+    pub send_buffer_size: Option<usize>,
+    /// The size of the kernel's receive buffer in bytes (SO_RCVBUF)
+    pub recv_buffer_size: Option<usize>,
+    /// The Differentiated Services code point (0-63) to mark outgoing
+    /// packets with, for networks which prioritize traffic
+    pub dscp: Option<u8>,
+    /// Reads & writes packets many at a time, with `recvmmsg` & `sendmmsg`,
+    /// which saves system calls when serving many Clients. Sent packets are
+    /// queued until the Server finishes sending or receiving. Linux only
+    pub batch_syscalls: bool,
+    /// Coalesces consecutive, equally sized packets to one Client into a
+    /// single send, for the kernel to split (UDP GSO). Requires
+    /// `batch_syscalls`, Linux only
+    pub gso: bool,
+}
+
+// Socket
+pub struct Socket {
+    sockets: Vec<Arc<UdpSocket>>,
+    options: SocketOptions,
+    config: Option<LinkConditionerSchedule>,
+}
+
+impl Socket {
+    /// Binds a single address.
+    ///
+    /// # Panics
+    /// If the address can't be bound, use `Socket::bind` to handle that
+    /// error instead
+    pub fn new(server_addr: &SocketAddr, config: Option<LinkConditionerConfig>) -> Self {
+        return Self::bind(
+            &[*server_addr],
+            &SocketOptions::default(),
+            config.map(LinkConditionerSchedule::from),
+        )
+        .expect("can't bind UDP socket!");
+    }
+
+    /// Creates a Socket which simulates network conditions that change over
+    /// time, as described by the given schedule
+    pub fn with_schedule(server_addr: &SocketAddr, schedule: LinkConditionerSchedule) -> Self {
+        let mut socket = Self::new(server_addr, None);
+        socket.config = Some(schedule);
+        return socket;
+    }
+
+    /// Binds every given address, with the given options. Binding both
+    /// `0.0.0.0:port` & `[::]:port` serves IPv4 & IPv6 Clients together, and
+    /// a lone IPv6 address also accepts IPv4 Clients where the OS allows
+    /// dual-stack sockets. Packets to a Client leave through the first socket
+    /// bound for its address family.
+    pub fn bind(
+        server_addrs: &[SocketAddr],
+        options: &SocketOptions,
+        config: Option<LinkConditionerSchedule>,
+    ) -> Result<Self, IoError> {
+        if server_addrs.is_empty() {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "no address to bind the UDP socket to",
+            ));
+        }
+        if options.gso && !options.batch_syscalls {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "GSO requires batch_syscalls",
+            ));
+        }
+        if options.batch_syscalls && !cfg!(target_os = "linux") {
+            return Err(IoError::new(
+                ErrorKind::Unsupported,
+                "batch_syscalls is only supported on Linux",
+            ));
+        }
+        let dual_stack = !server_addrs.iter().any(SocketAddr::is_ipv4);
+
+        let mut sockets = Vec::new();
+        for server_addr in server_addrs {
+            let socket = bind_socket(server_addr, options, dual_stack)?;
+            sockets.push(Arc::new(socket));
+        }
+
+        return Ok(Self {
+            sockets,
+            options: options.clone(),
+            config,
+        });
+    }
+
+    /// The local addresses of the bound sockets, in the order they were given
+    /// to `bind`. Useful for finding the port chosen when binding port 0
+    pub fn local_addrs(&self) -> Result<Vec<SocketAddr>, IoError> {
+        self.sockets
+            .iter()
+            .map(|socket| socket.local_addr())
+            .collect()
+    }
+}
+
+fn bind_socket(
+    server_addr: &SocketAddr,
+    options: &SocketOptions,
+    dual_stack: bool,
+) -> Result<UdpSocket, IoError> {
+    let socket = RawSocket::new(
+        Domain::for_address(*server_addr),
+        Type::DGRAM,
+        Some(Protocol::UDP),
+    )?;
+    if server_addr.is_ipv6() {
+        socket.set_only_v6(!dual_stack)?;
+    }
+    if options.reuse_port {
+        set_reuse_port(&socket)?;
+    }
+    if let Some(size) = options.send_buffer_size {
+        socket.set_send_buffer_size(size)?;
+    }
+    if let Some(size) = options.recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(dscp) = options.dscp {
+        set_dscp(&socket, server_addr, dscp)?;
+    }
+    socket.set_nonblocking(true)?;
+    socket.bind(&(*server_addr).into())?;
+
+    return Ok(socket.into());
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+fn set_reuse_port(socket: &RawSocket) -> Result<(), IoError> {
+    socket.set_reuse_port(true)
+}
+
+#[cfg(not(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+)))]
+fn set_reuse_port(_socket: &RawSocket) -> Result<(), IoError> {
+    Err(IoError::new(
+        ErrorKind::Unsupported,
+        "SO_REUSEPORT is not supported on this platform",
+    ))
+}
+
+fn set_dscp(socket: &RawSocket, server_addr: &SocketAddr, dscp: u8) -> Result<(), IoError> {
+    if dscp > 63 {
+        return Err(IoError::new(
+            ErrorKind::InvalidInput,
+            "DSCP must be between 0 and 63",
+        ));
+    }
+    // the code point fills the upper six bits of the traffic class
+    let traffic_class = (dscp as u32) << 2;
+    if server_addr.is_ipv6() {
+        return set_traffic_class_v6(socket, traffic_class);
+    }
+    return set_tos_v4(socket, traffic_class);
+}
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn set_traffic_class_v6(socket: &RawSocket, traffic_class: u32) -> Result<(), IoError> {
+    socket.set_tclass_v6(traffic_class)
+}
+
+#[cfg(not(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+fn set_traffic_class_v6(_socket: &RawSocket, _traffic_class: u32) -> Result<(), IoError> {
+    Err(IoError::new(
+        ErrorKind::Unsupported,
+        "DSCP on IPv6 is not supported on this platform",
+    ))
+}
+
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku"
+)))]
+fn set_tos_v4(socket: &RawSocket, traffic_class: u32) -> Result<(), IoError> {
+    socket.set_tos(traffic_class)
+}
+
+#[cfg(any(
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku"
+))]
+fn set_tos_v4(_socket: &RawSocket, _traffic_class: u32) -> Result<(), IoError> {
+    Err(IoError::new(
+        ErrorKind::Unsupported,
+        "DSCP is not supported on this platform",
+    ))
+}
+
+/// Addresses from a dual-stack socket arrive IPv4-mapped, report them as the
+/// IPv4 addresses they are
+fn canonical_addr(address: SocketAddr) -> SocketAddr {
+    if let SocketAddr::V6(v6_address) = address {
+        if let Some(ipv4) = v6_address.ip().to_ipv4_mapped() {
+            return SocketAddr::new(ipv4.into(), v6_address.port());
+        }
+    }
+    return address;
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let inner_sender = Box::new(PacketSender::new(&self.sockets, &self.options));
+        let inner_receiver = Box::new(PacketReceiver::new(&self.sockets, &self.options));
+
+        if let Some(config) = &self.config {
+            let outgoing = OutgoingConditioner::new(inner_sender, config);
+            let sender = Box::new(ConditionedPacketSender::new(outgoing.clone()));
+            let receiver = Box::new(ConditionedPacketReceiver::new(
+                inner_receiver,
+                config,
+                outgoing,
+            ));
+            return (sender, receiver);
+        }
+
+        return (inner_sender, inner_receiver);
+    }
+}
+
+// Socket Writer
+/// Writes to one socket, each packet as it is sent, or in batches
+enum SocketWriter {
+    Single(Arc<UdpSocket>),
+    #[cfg(target_os = "linux")]
+    Batch(BatchWriter),
+}
+
+impl SocketWriter {
+    #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+    fn new(socket: &Arc<UdpSocket>, options: &SocketOptions) -> Self {
+        #[cfg(target_os = "linux")]
+        if options.batch_syscalls {
+            return Self::Batch(BatchWriter::new(socket.clone(), options.gso));
+        }
+        return Self::Single(socket.clone());
+    }
+
+    fn send(&mut self, address: SocketAddr, payload: &[u8]) -> Result<(), SendError> {
+        match self {
+            Self::Single(socket) => {
+                if socket.send_to(payload, address).is_err() {
+                    return Err(SendError);
+                }
+            }
+            #[cfg(target_os = "linux")]
+            Self::Batch(writer) => writer.send(address, payload),
+        }
+        return Ok(());
+    }
+
+    fn flush(&mut self) {
+        match self {
+            Self::Single(_) => {}
+            #[cfg(target_os = "linux")]
+            Self::Batch(writer) => writer.flush(),
+        }
+    }
+}
+
+// Socket Reader
+/// Reads from one socket, a packet or a batch at a time
+#[derive(Clone)]
+enum SocketReader {
+    Single {
+        socket: Arc<UdpSocket>,
+        buffer: Box<[u8; MAX_PACKET_SIZE]>,
+        received: Option<(SocketAddr, usize)>,
+    },
+    #[cfg(target_os = "linux")]
+    Batch(BatchReader),
+}
+
+impl SocketReader {
+    #[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+    fn new(socket: &Arc<UdpSocket>, options: &SocketOptions) -> Self {
+        #[cfg(target_os = "linux")]
+        if options.batch_syscalls {
+            return Self::Batch(BatchReader::new(socket.clone()));
+        }
+        return Self::Single {
+            socket: socket.clone(),
+            buffer: Box::new([0; MAX_PACKET_SIZE]),
+            received: None,
+        };
+    }
+
+    /// Returns whether a packet is ready to take
+    fn poll(&mut self) -> Result<bool, IoError> {
+        match self {
+            Self::Single {
+                socket,
+                buffer,
+                received,
+            } => {
+                if received.is_some() {
+                    return Ok(true);
+                }
+                match socket.recv_from(&mut buffer[..]) {
+                    Ok((recv_len, address)) => {
+                        *received = Some((address, recv_len));
+                        return Ok(true);
+                    }
+                    Err(error) if error.kind() == ErrorKind::WouldBlock => return Ok(false),
+                    Err(error) => return Err(error),
+                }
+            }
+            #[cfg(target_os = "linux")]
+            Self::Batch(reader) => reader.poll(),
+        }
+    }
+
+    /// Takes the packet which `poll` found
+    fn take(&mut self) -> (SocketAddr, &[u8]) {
+        match self {
+            Self::Single {
+                buffer, received, ..
+            } => {
+                let (address, recv_len) = received.take().unwrap();
+                return (address, &buffer[..recv_len]);
+            }
+            #[cfg(target_os = "linux")]
+            Self::Batch(reader) => reader.take(),
+        }
+    }
+}
+
+// Packet Sender
+struct PacketSender {
+    local_addrs: Vec<SocketAddr>,
+    /// The writers of the first IPv4 socket & the first IPv6 socket
+    writers: Vec<SocketWriter>,
+    ipv4_writer: Option<usize>,
+    ipv6_writer: Option<usize>,
+    /// Whether IPv4 Clients are reached through the dual-stack IPv6 socket
+    ipv4_mapped: bool,
+}
+
+impl PacketSender {
+    pub fn new(sockets: &[Arc<UdpSocket>], options: &SocketOptions) -> Self {
+        let is_ipv4 =
+            |socket: &&Arc<UdpSocket>| socket.local_addr().is_ok_and(|address| address.is_ipv4());
+        let ipv4_socket = sockets.iter().find(is_ipv4);
+        let ipv6_socket = sockets.iter().find(|socket| !is_ipv4(socket));
+
+        let mut writers = Vec::new();
+        let mut writer = |socket: &Arc<UdpSocket>| {
+            writers.push(SocketWriter::new(socket, options));
+            writers.len() - 1
+        };
+        let ipv4_writer = ipv4_socket.map(&mut writer);
+        let ipv6_writer = ipv6_socket.map(&mut writer);
+        let ipv4_mapped = ipv4_writer.is_none();
+        let local_addrs = sockets
+            .iter()
+            .filter_map(|socket| socket.local_addr().ok())
+            .collect();
+
+        return Self {
+            local_addrs,
+            writers,
+            ipv4_writer: ipv4_writer.or(ipv6_writer),
+            ipv6_writer,
+            ipv4_mapped,
+        };
+    }
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Client Socket
+    fn send(&mut self, socket_addr: &SocketAddr, payload: &[u8]) -> Result<(), SendError> {
+        let (writer, socket_addr) = match socket_addr {
+            SocketAddr::V4(v4_address) if self.ipv4_mapped => (
+                self.ipv4_writer,
+                SocketAddr::V6(SocketAddrV6::new(
+                    v4_address.ip().to_ipv6_mapped(),
+                    v4_address.port(),
+                    0,
+                    0,
+                )),
+            ),
+            SocketAddr::V4(_) => (self.ipv4_writer, *socket_addr),
+            SocketAddr::V6(_) => (self.ipv6_writer, *socket_addr),
+        };
+        let Some(writer) = writer else {
+            return Err(SendError);
+        };
+        return self.writers[writer].send(socket_addr, payload);
+    }
+
+    fn flush(&mut self) {
+        for writer in &mut self.writers {
+            writer.flush();
+        }
+    }
+
+    fn local_addrs(&self) -> Vec<SocketAddr> {
+        return self.local_addrs.clone();
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    readers: Vec<SocketReader>,
+    /// The socket to read first, so that a busy socket can't starve the rest
+    next_socket: usize,
+}
+
+impl PacketReceiver {
+    pub fn new(sockets: &[Arc<UdpSocket>], options: &SocketOptions) -> Self {
+        return Self {
+            readers: sockets
+                .iter()
+                .map(|socket| SocketReader::new(socket, options))
+                .collect(),
+            next_socket: 0,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Client Socket
+    fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+        let socket_count = self.readers.len();
+        for offset in 0..socket_count {
+            let index = (self.next_socket + offset) % socket_count;
+            match self.readers[index].poll() {
+                Ok(true) => {
+                    self.next_socket = (index + 1) % socket_count;
+                    let (address, payload) = self.readers[index].take();
+                    return Ok(Some((canonical_addr(address), payload)));
+                }
+                Ok(false) => continue,
+                Err(_) => return Err(RecvError),
+            }
+        }
+        return Ok(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{SocketAddr, UdpSocket},
+        thread,
+        time::Duration,
+    };
+
+    use super::{Socket, SocketOptions, TransportSocket};
+
+    fn localhost(address: &str) -> SocketAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn serves_ipv4_and_ipv6_together() {
+        let socket = Socket::bind(
+            &[localhost("127.0.0.1:0"), localhost("[::1]:0")],
+            &SocketOptions::default(),
+            None,
+        )
+        .unwrap();
+        let local_addrs = socket.local_addrs().unwrap();
+        assert!(local_addrs.iter().all(|address| address.port() != 0));
+
+        let boxed_socket: Box<dyn TransportSocket> = Box::new(socket);
+        let (mut sender, mut receiver) = boxed_socket.listen();
+
+        for local_addr in local_addrs {
+            let client = UdpSocket::bind(SocketAddr::new(local_addr.ip(), 0)).unwrap();
+            client.send_to(b"ping", local_addr).unwrap();
+            thread::sleep(Duration::from_millis(20));
+
+            let Ok(Some((address, payload))) = receiver.receive() else {
+                panic!("no packet received on {}", local_addr);
+            };
+            assert_eq!(address, client.local_addr().unwrap());
+            assert_eq!(payload, b"ping");
+
+            assert!(sender.send(&address, b"pong").is_ok());
+            let mut buffer = [0; 16];
+            let (length, _) = client.recv_from(&mut buffer).unwrap();
+            assert_eq!(&buffer[..length], b"pong");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn batches_packets() {
+        let options = SocketOptions {
+            batch_syscalls: true,
+            gso: true,
+            ..Default::default()
+        };
+        let socket = Socket::bind(&[localhost("127.0.0.1:0")], &options, None).unwrap();
+        let server_addr = socket.local_addrs().unwrap()[0];
+        let boxed_socket: Box<dyn TransportSocket> = Box::new(socket);
+        let (mut sender, mut receiver) = boxed_socket.listen();
+
+        let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+        client
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        for index in 0..40u8 {
+            client.send_to(&[index; 10], server_addr).unwrap();
+        }
+        thread::sleep(Duration::from_millis(20));
+
+        let mut received = Vec::new();
+        while let Ok(Some((address, payload))) = receiver.receive() {
+            assert_eq!(address, client.local_addr().unwrap());
+            received.push(payload[0]);
+        }
+        assert_eq!(received, (0..40).collect::<Vec<u8>>());
+
+        // equal packets, then a shorter one, which may leave as one send
+        let payloads: [&[u8]; 4] = [&[1; 100], &[2; 100], &[3; 100], &[4; 30]];
+        let client_addr = client.local_addr().unwrap();
+        for payload in payloads {
+            assert!(sender.send(&client_addr, payload).is_ok());
+        }
+        sender.flush();
+
+        let mut buffer = [0; 2048];
+        for payload in payloads {
+            let (length, _) = client.recv_from(&mut buffer).unwrap();
+            assert_eq!(&buffer[..length], payload);
+        }
+    }
+
+    #[test]
+    fn returns_an_error_when_binding_fails() {
+        let taken = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let result = Socket::bind(
+            &[taken.local_addr().unwrap()],
+            &SocketOptions::default(),
+            None,
+        );
+        assert!(result.is_err());
+
+        assert!(Socket::bind(&[], &SocketOptions::default(), None).is_err());
+    }
+}
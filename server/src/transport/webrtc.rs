@@ -4,7 +4,10 @@ use naia_shared::SocketConfig;
 
 use naia_server_socket::{PacketReceiver, PacketSender, Socket as ServerSocket};
 
-pub use naia_server_socket::ServerAddrs;
+pub use naia_server_socket::{
+    AuthorizeFn, HttpSignaling, HttpSignalingConfig, RateLimit, ServerAddrs, SessionAnswerer,
+    Signaling,
+};
 
 use super::{
     PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
@@ -14,6 +17,7 @@ use super::{
 pub struct Socket {
     server_addrs: ServerAddrs,
     config: SocketConfig,
+    signaling: Option<Box<dyn Signaling>>,
 }
 
 impl Socket {
@@ -21,13 +25,29 @@ impl Socket {
         return Self {
             server_addrs: server_addrs.clone(),
             config: config.clone(),
+            signaling: None,
+        };
+    }
+
+    /// Serves session offers through the given `Signaling`, for example one
+    /// built on your own HTTP stack, or an `HttpSignaling` with custom
+    /// configuration. `server_addrs.session_listen_addr` is then unused.
+    pub fn with_signaling<S: Signaling + 'static>(
+        server_addrs: &ServerAddrs,
+        config: &SocketConfig,
+        signaling: S,
+    ) -> Self {
+        return Self {
+            server_addrs: server_addrs.clone(),
+            config: config.clone(),
+            signaling: Some(Box::new(signaling)),
         };
     }
 }
 
 impl TransportSender for Box<dyn PacketSender> {
     /// Sends a packet from the Server Socket
-    fn send(&self, address: &SocketAddr, payload: &[u8]) -> Result<(), SendError> {
+    fn send(&mut self, address: &SocketAddr, payload: &[u8]) -> Result<(), SendError> {
         self.as_ref().send(address, payload).map_err(|_| SendError)
     }
 }
@@ -47,7 +67,12 @@ impl Into<Box<dyn TransportSocket>> for Socket {
 
 impl TransportSocket for Socket {
     fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
-        let (inner_sender, inner_receiver) = ServerSocket::listen(&self.server_addrs, &self.config);
+        let (inner_sender, inner_receiver) = match self.signaling {
+            Some(signaling) => {
+                ServerSocket::listen_with_signaling(&self.server_addrs, &self.config, signaling)
+            }
+            None => ServerSocket::listen(&self.server_addrs, &self.config),
+        };
         return (Box::new(inner_sender), Box::new(inner_receiver));
     }
 }
@@ -0,0 +1,402 @@
+use std::{
+    collections::HashMap,
+    io::{BufReader, Error as IoError, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{sync_channel, Receiver, SyncSender, TryRecvError, TrySendError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use log::warn;
+
+use naia_shared::websocket::{
+    accept_key, encode_frame, header_value, read_http_head, Message, MessageReader, OPCODE_BINARY,
+    OPCODE_CLOSE, OPCODE_PONG,
+};
+
+use super::{
+    PacketReceiver as TransportReceiver, PacketSender as TransportSender, RecvError, SendError,
+    Socket as TransportSocket,
+};
+
+type Outgoing = Arc<Mutex<HashMap<SocketAddr, Connection>>>;
+type Incoming = (SocketAddr, Box<[u8]>);
+
+/// Limits on the connections the Server accepts
+#[derive(Clone)]
+pub struct SocketOptions {
+    /// The most connections open at once, more are closed as they arrive
+    pub max_connections: usize,
+    /// How long a connection may go without sending anything, or take to
+    /// accept a frame, before it is closed. Keep it longer than the Clients'
+    /// heartbeat interval
+    pub timeout: Duration,
+    /// The most packets queued to one Client. A Client which falls this far
+    /// behind is disconnected
+    pub send_queue_size: usize,
+    /// The most received packets queued for the Server, more are dropped as
+    /// they would be over UDP
+    pub recv_queue_size: usize,
+}
+
+impl Default for SocketOptions {
+    fn default() -> Self {
+        Self {
+            max_connections: 1024,
+            timeout: Duration::from_secs(30),
+            send_queue_size: 256,
+            recv_queue_size: 4096,
+        }
+    }
+}
+
+// Socket
+/// Serves Clients over plain WebSockets, for networks where WebRTC is
+/// blocked. Each packet travels as one binary message.
+pub struct Socket {
+    listener: TcpListener,
+    options: SocketOptions,
+}
+
+impl Socket {
+    /// Binds an address.
+    ///
+    /// # Panics
+    /// If the address can't be bound, use `Socket::bind` to handle that
+    /// error instead
+    pub fn new(server_addr: &SocketAddr) -> Self {
+        return Self::bind(server_addr, &SocketOptions::default())
+            .expect("can't bind WebSocket listener!");
+    }
+
+    /// Binds an address, with the given options
+    pub fn bind(server_addr: &SocketAddr, options: &SocketOptions) -> Result<Self, IoError> {
+        let listener = TcpListener::bind(server_addr)?;
+        return Ok(Self {
+            listener,
+            options: options.clone(),
+        });
+    }
+
+    /// The local address of the listener. Useful for finding the port chosen
+    /// when binding port 0
+    pub fn local_addr(&self) -> Result<SocketAddr, IoError> {
+        self.listener.local_addr()
+    }
+}
+
+impl Into<Box<dyn TransportSocket>> for Socket {
+    fn into(self) -> Box<dyn TransportSocket> {
+        Box::new(self)
+    }
+}
+
+impl TransportSocket for Socket {
+    fn listen(self: Box<Self>) -> (Box<dyn TransportSender>, Box<dyn TransportReceiver>) {
+        let Self { listener, options } = *self;
+        let local_addrs = listener.local_addr().into_iter().collect();
+        let outgoing: Outgoing = Arc::new(Mutex::new(HashMap::new()));
+        let (incoming_sender, incoming_receiver) = sync_channel(options.recv_queue_size);
+
+        let accept_outgoing = outgoing.clone();
+        thread::spawn(move || {
+            let open_connections = Arc::new(AtomicUsize::new(0));
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                if open_connections.load(Ordering::Relaxed) >= options.max_connections {
+                    let _ = stream.shutdown(Shutdown::Both);
+                    continue;
+                }
+                open_connections.fetch_add(1, Ordering::Relaxed);
+
+                let open_connections = open_connections.clone();
+                let outgoing = accept_outgoing.clone();
+                let incoming = incoming_sender.clone();
+                let options = options.clone();
+                thread::spawn(move || {
+                    serve_connection(stream, &options, outgoing, incoming);
+                    open_connections.fetch_sub(1, Ordering::Relaxed);
+                });
+            }
+        });
+
+        let sender = Box::new(PacketSender::new(outgoing, local_addrs));
+        let receiver = Box::new(PacketReceiver::new(incoming_receiver));
+
+        return (sender, receiver);
+    }
+}
+
+/// An upgraded connection, as the Server sends to it
+struct Connection {
+    frames: SyncSender<Vec<u8>>,
+    /// Shut down to close the connection, when the Client falls behind
+    stream: TcpStream,
+}
+
+/// Upgrades a connection, then forwards its messages until it closes
+fn serve_connection(
+    stream: TcpStream,
+    options: &SocketOptions,
+    outgoing: Outgoing,
+    incoming: SyncSender<Incoming>,
+) {
+    let Ok(address) = stream.peer_addr() else {
+        return;
+    };
+    let _ = stream.set_nodelay(true);
+    let _ = stream.set_read_timeout(Some(options.timeout));
+    let _ = stream.set_write_timeout(Some(options.timeout));
+    let (Ok(mut write_stream), Ok(shutdown_stream)) = (stream.try_clone(), stream.try_clone())
+    else {
+        return;
+    };
+    let mut read_stream = BufReader::new(stream);
+
+    let Some(accept_key) = read_http_head(&mut read_stream)
+        .ok()
+        .and_then(|head| upgrade_accept_key(&head))
+    else {
+        let _ = write_stream.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n");
+        return;
+    };
+    let response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key
+    );
+    if write_stream.write_all(response.as_bytes()).is_err() {
+        return;
+    }
+
+    // writes happen on their own thread, through a queue of bounded size, so
+    // that the Server never waits on a Client. A Client which lets the queue
+    // fill is disconnected, rather than let it grow
+    let (frame_sender, frame_receiver) = sync_channel::<Vec<u8>>(options.send_queue_size);
+    thread::spawn(move || {
+        for frame in frame_receiver {
+            if write_stream.write_all(&frame).is_err() {
+                break;
+            }
+        }
+        let _ = write_stream.shutdown(Shutdown::Both);
+    });
+    outgoing.lock().unwrap().insert(
+        address,
+        Connection {
+            frames: frame_sender.clone(),
+            stream: shutdown_stream,
+        },
+    );
+
+    let mut message_reader = MessageReader::from_client();
+    loop {
+        match message_reader.read(&mut read_stream) {
+            Ok(Message::Binary(payload)) => match incoming.try_send((address, payload.into())) {
+                // the Server is behind, the packet is lost as it would be
+                // over UDP
+                Ok(()) | Err(TrySendError::Full(_)) => {}
+                Err(TrySendError::Disconnected(_)) => break,
+            },
+            Ok(Message::Ping(payload)) => {
+                let _ = frame_sender.try_send(encode_frame(OPCODE_PONG, &payload, None));
+            }
+            Ok(Message::Pong) => {}
+            Ok(Message::Close) => {
+                let _ = frame_sender.try_send(encode_frame(OPCODE_CLOSE, &[], None));
+                break;
+            }
+            Err(error) => {
+                warn!("WebSocket connection with {} failed: {}", address, error);
+                break;
+            }
+        }
+    }
+
+    outgoing.lock().unwrap().remove(&address);
+}
+
+/// Validates a WebSocket upgrade request, returning the key to accept it with
+fn upgrade_accept_key(head: &str) -> Option<String> {
+    if !head.starts_with("GET ") {
+        return None;
+    }
+    let upgrade = header_value(head, "Upgrade")?;
+    if !upgrade.eq_ignore_ascii_case("websocket") {
+        return None;
+    }
+    if header_value(head, "Sec-WebSocket-Version")? != "13" {
+        return None;
+    }
+    let key = header_value(head, "Sec-WebSocket-Key")?;
+
+    Some(accept_key(key))
+}
+
+// Packet Sender
+struct PacketSender {
+    outgoing: Outgoing,
+    local_addrs: Vec<SocketAddr>,
+}
+
+impl PacketSender {
+    pub fn new(outgoing: Outgoing, local_addrs: Vec<SocketAddr>) -> Self {
+        return Self {
+            outgoing,
+            local_addrs,
+        };
+    }
+}
+
+impl TransportSender for PacketSender {
+    /// Sends a packet from the Server Socket
+    fn send(&mut self, socket_addr: &SocketAddr, payload: &[u8]) -> Result<(), SendError> {
+        let mut outgoing = self.outgoing.lock().unwrap();
+        let Some(connection) = outgoing.get(socket_addr) else {
+            return Err(SendError);
+        };
+        match connection
+            .frames
+            .try_send(encode_frame(OPCODE_BINARY, payload, None))
+        {
+            Ok(()) => return Ok(()),
+            Err(TrySendError::Full(_)) => {
+                warn!(
+                    "WebSocket connection with {} closed, it fell too far behind",
+                    socket_addr
+                );
+                let _ = connection.stream.shutdown(Shutdown::Both);
+                outgoing.remove(socket_addr);
+                return Err(SendError);
+            }
+            Err(TrySendError::Disconnected(_)) => return Err(SendError),
+        }
+    }
+
+    fn local_addrs(&self) -> Vec<SocketAddr> {
+        self.local_addrs.clone()
+    }
+}
+
+// Packet Receiver
+#[derive(Clone)]
+struct PacketReceiver {
+    incoming: Arc<Mutex<Receiver<Incoming>>>,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl PacketReceiver {
+    pub fn new(incoming: Receiver<Incoming>) -> Self {
+        return Self {
+            incoming: Arc::new(Mutex::new(incoming)),
+            last_payload: None,
+        };
+    }
+}
+
+impl TransportReceiver for PacketReceiver {
+    /// Receives a packet from the Server Socket
+    fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, RecvError> {
+        let received = self.incoming.lock().unwrap().try_recv();
+        match received {
+            Ok((address, payload)) => {
+                self.last_payload = Some(payload);
+                Ok(Some((address, self.last_payload.as_ref().unwrap())))
+            }
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(RecvError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufReader, Write},
+        net::{SocketAddr, TcpStream},
+        thread,
+        time::Duration,
+    };
+
+    use naia_shared::websocket::{
+        encode_frame, read_http_head, Message, MessageReader, OPCODE_BINARY,
+    };
+
+    use super::{upgrade_accept_key, Socket, SocketOptions, TransportSocket};
+
+    const UPGRADE_REQUEST: &[u8] = b"GET / HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n";
+
+    /// Connects & sends an upgrade request, returning the response's head
+    fn upgrade(address: SocketAddr) -> (TcpStream, std::io::Result<String>) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        stream.write_all(UPGRADE_REQUEST).unwrap();
+        let head = read_http_head(&mut stream);
+        (stream, head)
+    }
+
+    #[test]
+    fn accepts_upgrade_requests() {
+        // the example handshake from RFC 6455
+        let head = "GET /chat HTTP/1.1\r\nHost: server.example.com\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n";
+        assert_eq!(
+            upgrade_accept_key(head).as_deref(),
+            Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo=")
+        );
+
+        let not_upgrade = "GET / HTTP/1.1\r\nHost: server.example.com\r\n\r\n";
+        assert_eq!(upgrade_accept_key(not_upgrade), None);
+    }
+
+    #[test]
+    fn serves_clients_up_to_the_cap() {
+        let options = SocketOptions {
+            max_connections: 1,
+            ..Default::default()
+        };
+        let socket = Socket::bind(&"127.0.0.1:0".parse().unwrap(), &options).unwrap();
+        let address = socket.local_addr().unwrap();
+        let socket: Box<dyn TransportSocket> = Box::new(socket);
+        let (mut sender, mut receiver) = socket.listen();
+        assert_eq!(sender.local_addrs(), vec![address]);
+
+        let (mut stream, head) = upgrade(address);
+        assert!(head.unwrap().starts_with("HTTP/1.1 101"));
+        let client_address = stream.local_addr().unwrap();
+
+        // a masked packet arrives, and a reply goes back unmasked
+        stream
+            .write_all(&encode_frame(OPCODE_BINARY, b"hello", Some([1, 2, 3, 4])))
+            .unwrap();
+        let received = loop {
+            if let Ok(Some((from, payload))) = receiver.receive() {
+                break (from, payload.to_vec());
+            }
+            thread::sleep(Duration::from_millis(1));
+        };
+        assert_eq!(received, (client_address, b"hello".to_vec()));
+        assert!(sender.send(&client_address, b"welcome").is_ok());
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        assert_eq!(
+            MessageReader::from_server().read(&mut reader).unwrap(),
+            Message::Binary(b"welcome".to_vec())
+        );
+
+        // a second connection is over the cap
+        let (_, head) = upgrade(address);
+        assert!(head.is_err());
+
+        // an unmasked frame closes the connection
+        stream
+            .write_all(&encode_frame(OPCODE_BINARY, b"hello", None))
+            .unwrap();
+        assert!(MessageReader::from_server().read(&mut reader).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use std::hash::Hash;
 
+use naia_shared::{ComponentKind, Replicate};
+
 use super::{server::Server, user::UserKey};
 
 pub struct UserScopeMut<'s, E: Copy + Eq + Hash + Send + Sync> {
@@ -26,6 +28,23 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync> UserScopeMut<'s, E> {
         self
     }
 
+    /// Hides a Component of an Entity from the User, while the rest of the
+    /// Entity continues to replicate
+    pub fn exclude_component<R: Replicate>(&mut self, entity: &E) -> &mut Self {
+        self.server
+            .user_scope_set_component(&self.key, entity, &ComponentKind::of::<R>(), false);
+
+        self
+    }
+
+    /// Reveals a Component previously hidden with `exclude_component()`
+    pub fn include_component<R: Replicate>(&mut self, entity: &E) -> &mut Self {
+        self.server
+            .user_scope_set_component(&self.key, entity, &ComponentKind::of::<R>(), true);
+
+        self
+    }
+
     /// Removes all Entities from the User's scope
     pub fn clear(&mut self) -> &mut Self {
         self.server.user_scope_remove_user(&self.key);
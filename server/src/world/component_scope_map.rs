@@ -0,0 +1,70 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use naia_shared::ComponentKind;
+
+use crate::user::UserKey;
+
+/// Tracks the Components which are hidden from specific Users, even while
+/// their Entity is in scope
+pub struct ComponentScopeMap<E: Copy + Eq + Hash> {
+    excluded_components: HashMap<(UserKey, E), HashSet<ComponentKind>>,
+}
+
+impl<E: Copy + Eq + Hash> ComponentScopeMap<E> {
+    pub fn new() -> Self {
+        Self {
+            excluded_components: HashMap::new(),
+        }
+    }
+
+    pub fn is_excluded(
+        &self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) -> bool {
+        self.excluded_components
+            .get(&(*user_key, *entity))
+            .map(|component_kinds| component_kinds.contains(component_kind))
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the Component's visibility changed
+    pub fn set(
+        &mut self,
+        user_key: &UserKey,
+        entity: &E,
+        component_kind: &ComponentKind,
+        is_contained: bool,
+    ) -> bool {
+        let key = (*user_key, *entity);
+        if is_contained {
+            let Some(component_kinds) = self.excluded_components.get_mut(&key) else {
+                return false;
+            };
+            let changed = component_kinds.remove(component_kind);
+            if component_kinds.is_empty() {
+                self.excluded_components.remove(&key);
+            }
+            changed
+        } else {
+            self.excluded_components
+                .entry(key)
+                .or_default()
+                .insert(*component_kind)
+        }
+    }
+
+    pub fn remove_user(&mut self, user_key: &UserKey) {
+        self.excluded_components
+            .retain(|(excluded_user_key, _), _| excluded_user_key != user_key);
+    }
+
+    pub fn remove_entity(&mut self, entity: &E) {
+        self.excluded_components
+            .retain(|(_, excluded_entity), _| excluded_entity != entity);
+    }
+}
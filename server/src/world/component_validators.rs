@@ -0,0 +1,202 @@
+use std::{collections::HashMap, hash::Hash};
+
+use naia_shared::{ComponentKind, RemoteComponentValidator, Replicate, ValidationResult};
+
+use crate::user::UserKey;
+
+type ValidatorFn<E> = Box<
+    dyn Fn(&UserKey, &E, Option<&dyn Replicate>, &mut dyn Replicate) -> ValidationResult
+        + Send
+        + Sync,
+>;
+
+/// A change which must be reverted on the Client
+pub enum ComponentCorrection<E> {
+    /// Send the Server's current value of the Component
+    Update(E, ComponentKind),
+    /// The Component's insert was rejected, remove it
+    Remove(E, ComponentKind),
+}
+
+/// Validation functions for Components of Client-owned Entities, by kind
+pub struct ComponentValidators<E: Copy + Eq + Hash> {
+    validators: HashMap<ComponentKind, ValidatorFn<E>>,
+}
+
+impl<E: Copy + Eq + Hash> ComponentValidators<E> {
+    pub fn new() -> Self {
+        Self {
+            validators: HashMap::new(),
+        }
+    }
+
+    pub fn insert<R: Replicate>(
+        &mut self,
+        validator: impl Fn(&UserKey, &E, Option<&R>, &mut R) -> ValidationResult + Send + Sync + 'static,
+    ) {
+        let boxed_validator: ValidatorFn<E> = Box::new(
+            move |user_key: &UserKey,
+                  entity: &E,
+                  current: Option<&dyn Replicate>,
+                  proposed: &mut dyn Replicate| {
+                let current = current.map(|component| {
+                    component
+                        .to_any()
+                        .downcast_ref::<R>()
+                        .expect("Component should be of validated kind")
+                });
+                let proposed = proposed
+                    .to_any_mut()
+                    .downcast_mut::<R>()
+                    .expect("Component should be of validated kind");
+                validator(user_key, entity, current, proposed)
+            },
+        );
+        self.validators
+            .insert(ComponentKind::of::<R>(), boxed_validator);
+    }
+
+    /// Returns whether Components of the given kind are validated
+    pub fn validates(&self, component_kind: &ComponentKind) -> bool {
+        self.validators.contains_key(component_kind)
+    }
+
+    /// Returns a validator of changes made by the given User
+    pub fn for_user(&self, user_key: &UserKey) -> UserComponentValidator<'_, E> {
+        UserComponentValidator {
+            validators: &self.validators,
+            user_key: *user_key,
+            corrections: Vec::new(),
+        }
+    }
+}
+
+/// Validates the changes made by one User, and records which of them must be
+/// corrected on that User's Client
+pub struct UserComponentValidator<'a, E: Copy + Eq + Hash> {
+    validators: &'a HashMap<ComponentKind, ValidatorFn<E>>,
+    user_key: UserKey,
+    corrections: Vec<ComponentCorrection<E>>,
+}
+
+impl<'a, E: Copy + Eq + Hash> UserComponentValidator<'a, E> {
+    pub fn take_corrections(&mut self) -> Vec<ComponentCorrection<E>> {
+        std::mem::take(&mut self.corrections)
+    }
+}
+
+impl<'a, E: Copy + Eq + Hash> RemoteComponentValidator<E> for UserComponentValidator<'a, E> {
+    fn validates(&self, component_kind: &ComponentKind) -> bool {
+        self.validators.contains_key(component_kind)
+    }
+
+    fn validate(
+        &mut self,
+        entity: &E,
+        current: Option<&dyn Replicate>,
+        proposed: &mut dyn Replicate,
+    ) -> ValidationResult {
+        let component_kind = proposed.kind();
+        let Some(validator) = self.validators.get(&component_kind) else {
+            return ValidationResult::Accept;
+        };
+
+        let is_insert = current.is_none();
+        let result = validator(&self.user_key, entity, current, proposed);
+        match (result, is_insert) {
+            (ValidationResult::Accept, _) => {}
+            (ValidationResult::Reject, true) => {
+                self.corrections
+                    .push(ComponentCorrection::Remove(*entity, component_kind));
+            }
+            (ValidationResult::Clamp, _) | (ValidationResult::Reject, false) => {
+                self.corrections
+                    .push(ComponentCorrection::Update(*entity, component_kind));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_shared::{BigMapKey, Property, RemoteComponentValidator, Replicate, ValidationResult};
+
+    use super::{ComponentCorrection, ComponentValidators};
+    use crate::user::UserKey;
+
+    // with bevy support, Replicate requires the Component to be a bevy Component
+    #[derive(Replicate)]
+    #[cfg_attr(feature = "bevy_support", derive(bevy_ecs::component::Component))]
+    pub struct Position {
+        pub x: Property<i16>,
+    }
+
+    impl Position {
+        pub fn new(x: i16) -> Self {
+            Position::new_complete(x)
+        }
+    }
+
+    fn validators() -> ComponentValidators<u32> {
+        let mut validators = ComponentValidators::new();
+        validators.insert::<Position>(|_user_key, _entity, current, proposed| {
+            if *proposed.x < 0 {
+                return ValidationResult::Reject;
+            }
+            if let Some(current) = current {
+                if *proposed.x > *current.x + 10 {
+                    *proposed.x = *current.x + 10;
+                    return ValidationResult::Clamp;
+                }
+            }
+            ValidationResult::Accept
+        });
+        validators
+    }
+
+    #[test]
+    fn clamps_update() {
+        let validators = validators();
+        let mut validator = validators.for_user(&UserKey::from_u64(0));
+
+        let current = Position::new(5);
+        let mut proposed = Position::new(100);
+        let result = validator.validate(&1, Some(&current), &mut proposed);
+
+        assert_eq!(result, ValidationResult::Clamp);
+        assert_eq!(*proposed.x, 15);
+        assert!(matches!(
+            validator.take_corrections().as_slice(),
+            [ComponentCorrection::Update(1, _)]
+        ));
+    }
+
+    #[test]
+    fn rejects_insert() {
+        let validators = validators();
+        let mut validator = validators.for_user(&UserKey::from_u64(0));
+
+        let mut proposed = Position::new(-1);
+        let result = validator.validate(&1, None, &mut proposed);
+
+        assert_eq!(result, ValidationResult::Reject);
+        assert!(matches!(
+            validator.take_corrections().as_slice(),
+            [ComponentCorrection::Remove(1, _)]
+        ));
+    }
+
+    #[test]
+    fn accepts_without_correction() {
+        let validators = validators();
+        let mut validator = validators.for_user(&UserKey::from_u64(0));
+
+        let current = Position::new(5);
+        let mut proposed = Position::new(6);
+        let result = validator.validate(&1, Some(&current), &mut proposed);
+
+        assert_eq!(result, ValidationResult::Accept);
+        assert!(validator.take_corrections().is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Tracks parent/child relations between Entities. Children share the scope
+/// of their root ancestor, spawn after their parent & despawn along with it.
+pub struct EntityHierarchy<E: Copy + Eq + Hash> {
+    parents: HashMap<E, E>,
+    children: HashMap<E, HashSet<E>>,
+}
+
+impl<E: Copy + Eq + Hash> EntityHierarchy<E> {
+    pub fn new() -> Self {
+        Self {
+            parents: HashMap::new(),
+            children: HashMap::new(),
+        }
+    }
+
+    pub fn parent(&self, entity: &E) -> Option<E> {
+        self.parents.get(entity).copied()
+    }
+
+    pub fn has_parent(&self, entity: &E) -> bool {
+        self.parents.contains_key(entity)
+    }
+
+    pub fn children(&self, entity: &E) -> Vec<E> {
+        self.children
+            .get(entity)
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns all descendants of the Entity, each after its parent
+    pub fn descendants(&self, entity: &E) -> Vec<E> {
+        let mut output = Vec::new();
+        let mut queue = VecDeque::from([*entity]);
+        while let Some(next) = queue.pop_front() {
+            if let Some(children) = self.children.get(&next) {
+                for child in children {
+                    output.push(*child);
+                    queue.push_back(*child);
+                }
+            }
+        }
+        output
+    }
+
+    /// Returns the Entity's highest ancestor, or the Entity itself if it has
+    /// no parent
+    pub fn root(&self, entity: &E) -> E {
+        let mut root = *entity;
+        while let Some(parent) = self.parents.get(&root) {
+            root = *parent;
+        }
+        root
+    }
+
+    /// Returns the previous parent, if any. Panics if this would create a
+    /// cycle.
+    pub fn set_parent(&mut self, child: &E, parent: &E) -> Option<E> {
+        if self.root(parent) == *child {
+            panic!("Cannot make an Entity the child of itself or of its own descendant");
+        }
+        let previous = self.remove_parent(child);
+        self.parents.insert(*child, *parent);
+        self.children.entry(*parent).or_default().insert(*child);
+        previous
+    }
+
+    /// Returns the previous parent, if any
+    pub fn remove_parent(&mut self, child: &E) -> Option<E> {
+        let parent = self.parents.remove(child)?;
+        if let Some(siblings) = self.children.get_mut(&parent) {
+            siblings.remove(child);
+            if siblings.is_empty() {
+                self.children.remove(&parent);
+            }
+        }
+        Some(parent)
+    }
+
+    /// Detaches the Entity from its parent & children, returning the children
+    pub fn remove_entity(&mut self, entity: &E) -> Vec<E> {
+        self.remove_parent(entity);
+        let children = self.children.remove(entity).unwrap_or_default();
+        for child in &children {
+            self.parents.remove(child);
+        }
+        children.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityHierarchy;
+
+    #[test]
+    fn descendants_follow_parents() {
+        let mut hierarchy = EntityHierarchy::<u32>::new();
+        hierarchy.set_parent(&2, &1);
+        hierarchy.set_parent(&3, &2);
+
+        assert_eq!(hierarchy.root(&3), 1);
+        assert_eq!(hierarchy.descendants(&1), vec![2, 3]);
+
+        hierarchy.remove_entity(&2);
+
+        assert_eq!(hierarchy.parent(&3), None);
+        assert!(hierarchy.descendants(&1).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_cycles() {
+        let mut hierarchy = EntityHierarchy::<u32>::new();
+        hierarchy.set_parent(&2, &1);
+        hierarchy.set_parent(&1, &2);
+    }
+}
@@ -58,6 +58,36 @@ impl<'s, E: Copy + Eq + Hash + Send + Sync, W: WorldMutType<E>> EntityMut<'s, E,
             .remove_component::<R, W>(&mut self.world, &self.entity)
     }
 
+    // Publishing
+
+    /// Replicates this Client-owned Entity to the other Users it shares a
+    /// Room with. The owning Client keeps write authority.
+    pub fn publish(&mut self) -> &mut Self {
+        self.server.publish_entity(&mut self.world, &self.entity);
+
+        self
+    }
+
+    pub fn unpublish(&mut self) -> &mut Self {
+        self.server.unpublish_entity(&mut self.world, &self.entity);
+
+        self
+    }
+
+    // Hierarchy
+
+    pub fn set_parent(&mut self, parent: &E) -> &mut Self {
+        self.server.set_entity_parent(&self.entity, parent);
+
+        self
+    }
+
+    pub fn remove_parent(&mut self) -> &mut Self {
+        self.server.remove_entity_parent(&self.entity);
+
+        self
+    }
+
     // Rooms
 
     pub fn enter_room(&mut self, room_key: &RoomKey) -> &mut Self {
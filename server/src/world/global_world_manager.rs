@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     hash::Hash,
     sync::{Arc, RwLock},
 };
@@ -19,6 +19,8 @@ pub struct GlobalWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     entity_records: HashMap<E, GlobalEntityRecord>,
     /// Map from the internal [`GlobalEntity`] to the external (e.g. Bevy's) entity id
     global_entity_map: BigMap<GlobalEntity, E>,
+    /// Client-owned entities which are replicated to other Users
+    published_entities: HashSet<E>,
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
@@ -27,6 +29,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
             diff_handler: Arc::new(RwLock::new(GlobalDiffHandler::new())),
             entity_records: HashMap::default(),
             global_entity_map: BigMap::new(),
+            published_entities: HashSet::new(),
         }
     }
 
@@ -52,6 +55,39 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
         return None;
     }
 
+    // Publishing
+    pub fn publish_entity(&mut self, entity: &E) -> bool {
+        self.published_entities.insert(*entity)
+    }
+
+    pub fn unpublish_entity(&mut self, entity: &E) -> bool {
+        self.published_entities.remove(entity)
+    }
+
+    pub fn entity_is_published(&self, entity: &E) -> bool {
+        self.published_entities.contains(entity)
+    }
+
+    pub fn published_entities(&self) -> Vec<E> {
+        self.published_entities.iter().copied().collect()
+    }
+
+    /// Returns whether the Entity should be replicated to the given User, if
+    /// they share a Room. Client-owned Entities are never sent back to their
+    /// owner, and only reach other Users once published.
+    pub fn entity_can_enter_scope(&self, entity: &E, user_key: &UserKey) -> bool {
+        let Some(record) = self.entity_records.get(entity) else {
+            return false;
+        };
+        match record.owner {
+            EntityOwner::Server => true,
+            EntityOwner::Client(owning_user_key) => {
+                owning_user_key != *user_key && self.published_entities.contains(entity)
+            }
+            EntityOwner::ClientWaiting(_) | EntityOwner::Local => false,
+        }
+    }
+
     // Spawn
     pub fn host_spawn_entity(&mut self, entity: &E) {
         if self.entity_records.contains_key(entity) {
@@ -76,6 +112,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManager<E> {
             panic!("entity does not exist!");
         }
 
+        self.published_entities.remove(entity);
         self.entity_records.remove(entity)
     }
 
@@ -157,7 +194,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
                 EntityOwner::Server => true,
                 EntityOwner::Client(owning_user_key)
                 | EntityOwner::ClientWaiting(owning_user_key) => {
-                    return owning_user_key.to_u64() == *user_key;
+                    return owning_user_key.to_u64() == *user_key
+                        || self.published_entities.contains(entity);
                 }
                 EntityOwner::Local => false,
             };
@@ -193,6 +231,19 @@ impl<E: Copy + Eq + Hash + Send + Sync> GlobalWorldManagerType<E> for GlobalWorl
             .entity_records
             .remove(entity)
             .expect("Cannot despawn non-existant entity!");
+
+        // published Components were registered as if host-owned
+        if self.published_entities.contains(entity) {
+            let mut diff_handler = self
+                .diff_handler
+                .as_ref()
+                .write()
+                .expect("Haven't initialized DiffHandler");
+            for component_kind in &record.component_kinds {
+                diff_handler.deregister_component(entity, component_kind);
+            }
+        }
+
         let global_entity = record.global_entity;
         self.global_entity_map.remove(&global_entity);
     }
@@ -220,3 +271,35 @@ impl<E: Copy + Eq + Hash + Send + Sync> EntityAndGlobalEntityConverter<E>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use naia_shared::{BigMapKey, GlobalWorldManagerType};
+
+    use super::GlobalWorldManager;
+    use crate::UserKey;
+
+    #[test]
+    fn published_entity_scopes_to_others() {
+        let owner = UserKey::from_u64(0);
+        let other = UserKey::from_u64(1);
+
+        let mut manager = GlobalWorldManager::<u32>::new();
+        manager.remote_spawn_entity(&1, &owner.to_u64());
+        manager.remote_spawn_entity_record(&1, &owner);
+
+        assert!(!manager.entity_can_enter_scope(&1, &owner));
+        assert!(!manager.entity_can_enter_scope(&1, &other));
+        assert!(!manager.entity_can_relate_to_user(&1, &other.to_u64()));
+
+        manager.publish_entity(&1);
+
+        assert!(!manager.entity_can_enter_scope(&1, &owner));
+        assert!(manager.entity_can_enter_scope(&1, &other));
+        assert!(manager.entity_can_relate_to_user(&1, &other.to_u64()));
+
+        manager.unpublish_entity(&1);
+
+        assert!(!manager.entity_can_enter_scope(&1, &other));
+    }
+}
@@ -1,3 +1,6 @@
+pub mod component_scope_map;
+pub mod component_validators;
+pub mod entity_hierarchy;
 pub mod entity_mut;
 pub mod entity_owner;
 pub mod entity_scope_map;
@@ -7,36 +7,62 @@ use quote::quote;
 
 mod channel;
 mod message;
+mod nested_replicate;
 mod replicate;
 mod shared;
 
 use channel::channel_impl;
 use message::message_impl;
+use nested_replicate::nested_replicate_impl;
 use replicate::replicate_impl;
 
 // Replicate
 
 /// Derives the Replicate trait for a given struct
-#[proc_macro_derive(Replicate)]
+#[proc_macro_derive(Replicate, attributes(naia))]
 pub fn replicate_derive_shared(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_shared };
     replicate_impl(input, shared_crate_name)
 }
 
 /// Derives the Replicate trait for a given struct, for the Bevy adapter
-#[proc_macro_derive(ReplicateBevy)]
+#[proc_macro_derive(ReplicateBevy, attributes(naia))]
 pub fn replicate_derive_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_bevy_shared };
     replicate_impl(input, shared_crate_name)
 }
 
 /// Derives the Replicate trait for a given struct, for the Bevy adapter
-#[proc_macro_derive(ReplicateHecs)]
+#[proc_macro_derive(ReplicateHecs, attributes(naia))]
 pub fn replicate_derive_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_hecs_shared };
     replicate_impl(input, shared_crate_name)
 }
 
+// NestedReplicate
+
+/// Derives the NestedReplicate trait for a given struct, so that it can be used
+/// in a NestedProperty
+#[proc_macro_derive(NestedReplicate, attributes(naia))]
+pub fn nested_replicate_derive_shared(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let shared_crate_name = quote! { naia_shared };
+    nested_replicate_impl(input, shared_crate_name)
+}
+
+/// Derives the NestedReplicate trait for a given struct, for the Bevy adapter
+#[proc_macro_derive(NestedReplicateBevy, attributes(naia))]
+pub fn nested_replicate_derive_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let shared_crate_name = quote! { naia_bevy_shared };
+    nested_replicate_impl(input, shared_crate_name)
+}
+
+/// Derives the NestedReplicate trait for a given struct, for the Hecs adapter
+#[proc_macro_derive(NestedReplicateHecs, attributes(naia))]
+pub fn nested_replicate_derive_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let shared_crate_name = quote! { naia_hecs_shared };
+    nested_replicate_impl(input, shared_crate_name)
+}
+
 // Channel
 
 /// Derives the Channel trait for a given struct
@@ -48,35 +74,35 @@ pub fn channel_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 // Message
 
 /// Derives the Message trait for a given struct, for internal
-#[proc_macro_derive(MessageInternal)]
+#[proc_macro_derive(MessageInternal, attributes(naia))]
 pub fn message_derive_internal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { crate };
     message_impl(input, shared_crate_name, false)
 }
 
 /// Derives the Message trait for a given struct, for FragmentedMessage
-#[proc_macro_derive(MessageFragment)]
+#[proc_macro_derive(MessageFragment, attributes(naia))]
 pub fn message_derive_fragment(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { crate };
     message_impl(input, shared_crate_name, true)
 }
 
 /// Derives the Message trait for a given struct
-#[proc_macro_derive(Message)]
+#[proc_macro_derive(Message, attributes(naia))]
 pub fn message_derive_shared(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_shared };
     message_impl(input, shared_crate_name, false)
 }
 
 /// Derives the Message trait for a given struct, for the Bevy adapter
-#[proc_macro_derive(MessageBevy)]
+#[proc_macro_derive(MessageBevy, attributes(naia))]
 pub fn message_derive_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_bevy_shared };
     message_impl(input, shared_crate_name, false)
 }
 
 /// Derives the Message trait for a given struct, for the Hecs adapter
-#[proc_macro_derive(MessageHecs)]
+#[proc_macro_derive(MessageHecs, attributes(naia))]
 pub fn message_derive_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let shared_crate_name = quote! { naia_hecs_shared };
     message_impl(input, shared_crate_name, false)
@@ -1,6 +1,9 @@
+use naia_serde_attributes::FieldAttributes;
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Index, LitStr, Member, Type};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Index, LitStr, Member, Result, Type,
+};
 
 use super::shared::{get_struct_type, StructType};
 
@@ -13,7 +16,10 @@ pub fn message_impl(
 
     // Helper Properties
     let struct_type = get_struct_type(&input);
-    let fields = get_fields(&input);
+    let fields = match get_fields(&input) {
+        Ok(fields) => fields,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     // Names
     let struct_name = input.ident;
@@ -31,10 +37,10 @@ pub fn message_impl(
     // let entities_method = get_entities_method(&fields, &struct_type);
     let relations_waiting_method = get_relations_waiting_method(&fields, &struct_type);
     let relations_complete_method = get_relations_complete_method(&fields, &struct_type);
-    let bit_length_method = get_bit_length_method(&fields, &struct_type);
-    let write_method = get_write_method(&fields, &struct_type);
+    let bit_length_method = get_bit_length_method(&fields, &struct_type, &shared_crate_name);
+    let write_method = get_write_method(&fields, &struct_type, &shared_crate_name);
     let create_builder_method = get_create_builder_method(&builder_name);
-    let read_method = get_read_method(&struct_name, &fields, &struct_type);
+    let read_method = get_read_method(&struct_name, &fields, &struct_type, &shared_crate_name);
     let is_fragment_method = get_is_fragment_method(is_fragment);
 
     let gen = quote! {
@@ -230,6 +236,7 @@ pub fn get_read_method(
     struct_name: &Ident,
     fields: &[Field],
     struct_type: &StructType,
+    shared_crate_name: &TokenStream,
 ) -> TokenStream {
     let mut field_names = quote! {};
     for field in fields.iter() {
@@ -256,8 +263,9 @@ pub fn get_read_method(
             Field::Normal(normal_field) => {
                 let field_name = &normal_field.variable_name;
                 let field_type = &normal_field.field_type;
+                let field_de = normal_field.attributes.de(shared_crate_name, field_type);
                 quote! {
-                    let #field_name = <#field_type>::de(reader)?;
+                    let #field_name: #field_type = #field_de;
                 }
             }
         };
@@ -300,17 +308,21 @@ pub fn get_read_method(
     }
 }
 
-fn get_write_method(fields: &[Field], struct_type: &StructType) -> TokenStream {
+fn get_write_method(
+    fields: &[Field],
+    struct_type: &StructType,
+    shared_crate_name: &TokenStream,
+) -> TokenStream {
     let mut field_writes = quote! {};
 
     for (index, field) in fields.iter().enumerate() {
         let field_name = get_field_name(field, index, struct_type);
         let new_output_right = match field {
-            Field::Normal(_) => {
-                quote! {
-                    self.#field_name.ser(writer);
-                }
-            }
+            Field::Normal(normal_field) => normal_field.attributes.ser(
+                shared_crate_name,
+                &normal_field.field_type,
+                quote! { &self.#field_name },
+            ),
             Field::EntityProperty(_) => {
                 quote! {
                     EntityProperty::write(&self.#field_name, writer, converter);
@@ -333,15 +345,24 @@ fn get_write_method(fields: &[Field], struct_type: &StructType) -> TokenStream {
     }
 }
 
-fn get_bit_length_method(fields: &[Field], struct_type: &StructType) -> TokenStream {
+fn get_bit_length_method(
+    fields: &[Field],
+    struct_type: &StructType,
+    shared_crate_name: &TokenStream,
+) -> TokenStream {
     let mut field_bit_lengths = quote! {};
 
     for (index, field) in fields.iter().enumerate() {
         let field_name = get_field_name(field, index, struct_type);
         let new_output_right = match field {
-            Field::Normal(_) => {
+            Field::Normal(normal_field) => {
+                let field_bit_length = normal_field.attributes.bit_length(
+                    shared_crate_name,
+                    &normal_field.field_type,
+                    quote! { &self.#field_name },
+                );
                 quote! {
-                    output += self.#field_name.bit_length();
+                    output += #field_bit_length;
                 }
             }
             Field::EntityProperty(_) => {
@@ -376,7 +397,7 @@ pub fn get_create_builder_method(builder_name: &Ident) -> TokenStream {
     }
 }
 
-fn get_fields(input: &DeriveInput) -> Vec<Field> {
+fn get_fields(input: &DeriveInput) -> Result<Vec<Field>> {
     let mut fields = Vec::new();
 
     if let Data::Struct(data_struct) = &input.data {
@@ -384,12 +405,14 @@ fn get_fields(input: &DeriveInput) -> Vec<Field> {
             Fields::Named(fields_named) => {
                 for field in fields_named.named.iter() {
                     if let Some(variable_name) = &field.ident {
+                        let attributes = FieldAttributes::from_field(field)?;
                         match &field.ty {
                             Type::Path(type_path) => {
                                 if let Some(property_seg) = type_path.path.segments.first() {
                                     let property_type = property_seg.ident.clone();
                                     // EntityProperty
                                     if property_type == "EntityProperty" {
+                                        check_entity_property_attributes(field, &attributes)?;
                                         fields.push(Field::entity_property(variable_name.clone()));
                                         continue;
                                         // Property
@@ -397,12 +420,17 @@ fn get_fields(input: &DeriveInput) -> Vec<Field> {
                                         fields.push(Field::normal(
                                             variable_name.clone(),
                                             field.ty.clone(),
+                                            attributes,
                                         ));
                                     }
                                 }
                             }
                             _ => {
-                                fields.push(Field::normal(variable_name.clone(), field.ty.clone()));
+                                fields.push(Field::normal(
+                                    variable_name.clone(),
+                                    field.ty.clone(),
+                                    attributes,
+                                ));
                             }
                         }
                     }
@@ -410,16 +438,22 @@ fn get_fields(input: &DeriveInput) -> Vec<Field> {
             }
             Fields::Unnamed(fields_unnamed) => {
                 for (index, field) in fields_unnamed.unnamed.iter().enumerate() {
+                    let attributes = FieldAttributes::from_field(field)?;
                     if let Type::Path(type_path) = &field.ty {
                         if let Some(property_seg) = type_path.path.segments.first() {
                             let property_type = property_seg.ident.clone();
                             let variable_name =
                                 get_variable_name_for_unnamed_field(index, property_type.span());
                             if property_type == "EntityProperty" {
+                                check_entity_property_attributes(field, &attributes)?;
                                 fields.push(Field::entity_property(variable_name));
                                 continue;
                             } else {
-                                fields.push(Field::normal(variable_name, field.ty.clone()))
+                                fields.push(Field::normal(
+                                    variable_name,
+                                    field.ty.clone(),
+                                    attributes,
+                                ))
                             }
                         }
                     }
@@ -431,7 +465,20 @@ fn get_fields(input: &DeriveInput) -> Vec<Field> {
         panic!("Can only derive Replicate on a struct");
     }
 
-    fields
+    Ok(fields)
+}
+
+fn check_entity_property_attributes(
+    field: &syn::Field,
+    attributes: &FieldAttributes,
+) -> Result<()> {
+    if !attributes.is_default() {
+        return Err(Error::new_spanned(
+            field,
+            "naia attributes are not supported on EntityProperty fields",
+        ));
+    }
+    Ok(())
 }
 
 /// Get the field name as a TokenStream
@@ -464,6 +511,7 @@ pub struct EntityProperty {
 pub struct Normal {
     pub variable_name: Ident,
     pub field_type: Type,
+    pub attributes: FieldAttributes,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -483,10 +531,11 @@ impl Field {
         })
     }
 
-    pub fn normal(variable_name: Ident, field_type: Type) -> Self {
+    pub fn normal(variable_name: Ident, field_type: Type, attributes: FieldAttributes) -> Self {
         Self::Normal(Normal {
             variable_name: variable_name.clone(),
             field_type,
+            attributes,
         })
     }
 
@@ -0,0 +1,205 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, DeriveInput, Ident};
+
+use crate::{
+    replicate::{
+        get_clone_method, get_field_name, get_new_complete_method, get_properties,
+        get_property_count, get_property_enum_definition, get_read_apply_update_body,
+        get_read_body, get_read_write_update_body, get_write_body, get_write_update_body, Property,
+    },
+    shared::{get_struct_type, StructType},
+};
+
+pub fn nested_replicate_impl(
+    input: proc_macro::TokenStream,
+    shared_crate_name: TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // Helper Properties
+    let properties = match get_properties(&input, &shared_crate_name) {
+        Ok(properties) => properties,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let struct_type = get_struct_type(&input);
+
+    if properties
+        .iter()
+        .any(|property| matches!(property, Property::Entity(_)))
+    {
+        panic!("EntityProperty fields are not supported in a NestedReplicate struct");
+    }
+
+    // Names
+    let struct_name = input.ident.clone();
+    let lowercase_struct_name = Ident::new(
+        struct_name.to_string().to_lowercase().as_str(),
+        Span::call_site(),
+    );
+    let module_name = format_ident!("define_{}", lowercase_struct_name);
+    let enum_name = format_ident!("{}Property", struct_name);
+
+    // Definitions
+    let property_enum_definition = get_property_enum_definition(&enum_name, &properties);
+    let property_count = get_property_count(&properties);
+
+    // Methods
+    let new_complete_method =
+        get_new_complete_method(&struct_name, &enum_name, &properties, &struct_type);
+    let clone_method = get_clone_method(&struct_name, &properties, &struct_type);
+    let set_mutator_method = get_set_mutator_method(&properties, &struct_type);
+    let mirror_method = get_mirror_method(&properties, &struct_type);
+    let write_method = get_write_method(&properties, &struct_type);
+    let new_read_method = get_new_read_method(&struct_name, &properties, &struct_type);
+    let write_update_method = get_write_update_method(&enum_name, &properties, &struct_type);
+    let read_apply_update_method = get_read_apply_update_method(&properties, &struct_type);
+    let read_apply_authority_update_method =
+        get_read_apply_authority_update_method(&properties, &struct_type);
+    let read_write_update_method = get_read_write_update_method(&properties);
+
+    let gen = quote! {
+        mod #module_name {
+
+            use #shared_crate_name::{
+                DiffMask, PropertyMutator, BitReader, BitWrite, BitWriter, SerdeErr, Serde,
+                Property, NestedProperty, NestedReplicate,
+            };
+            use super::*;
+
+            #property_enum_definition
+
+            impl #struct_name {
+                #new_complete_method
+            }
+            impl NestedReplicate for #struct_name {
+                const PROPERTY_COUNT: u8 = #property_count;
+                #set_mutator_method
+                #mirror_method
+                #write_method
+                #new_read_method
+                #write_update_method
+                #read_apply_update_method
+                #read_apply_authority_update_method
+                #read_write_update_method
+            }
+            impl Clone for #struct_name {
+                #clone_method
+            }
+        }
+    };
+
+    proc_macro::TokenStream::from(gen)
+}
+
+fn get_set_mutator_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let mut output = quote! {};
+
+    for property in properties.iter().filter(|p| p.is_replicated()) {
+        let field_name = get_field_name(property, struct_type);
+        output = quote! {
+            #output
+            self.#field_name.set_mutator(mutator);
+        };
+    }
+
+    quote! {
+        fn set_mutator(&mut self, mutator: &PropertyMutator) {
+            #output
+        }
+    }
+}
+
+fn get_mirror_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let mut output = quote! {};
+
+    for property in properties.iter().filter(|p| p.is_replicated()) {
+        let field_name = get_field_name(property, struct_type);
+        output = quote! {
+            #output
+            self.#field_name.mirror(&other.#field_name);
+        };
+    }
+
+    quote! {
+        fn mirror(&mut self, other: &Self) {
+            #output
+        }
+    }
+}
+
+fn get_write_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let property_writes = get_write_body(properties, struct_type);
+
+    quote! {
+        fn write(&self, writer: &mut dyn BitWrite) {
+            #property_writes
+        }
+    }
+}
+
+fn get_new_read_method(
+    struct_name: &Ident,
+    properties: &[Property],
+    struct_type: &StructType,
+) -> TokenStream {
+    let (prop_reads, struct_build) = get_read_body(struct_name, properties, struct_type);
+
+    quote! {
+        fn new_read(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+            #prop_reads
+
+            return Ok(#struct_build);
+        }
+    }
+}
+
+fn get_write_update_method(
+    enum_name: &Ident,
+    properties: &[Property],
+    struct_type: &StructType,
+) -> TokenStream {
+    let output = get_write_update_body(enum_name, properties, struct_type);
+
+    quote! {
+        fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite) {
+            #output
+        }
+    }
+}
+
+fn get_read_apply_update_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let output = get_read_apply_update_body(properties, struct_type, false);
+
+    quote! {
+        fn read_apply_update(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+            #output
+            Ok(())
+        }
+    }
+}
+
+fn get_read_apply_authority_update_method(
+    properties: &[Property],
+    struct_type: &StructType,
+) -> TokenStream {
+    let output = get_read_apply_update_body(properties, struct_type, true);
+
+    quote! {
+        fn read_apply_authority_update(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+            #output
+            Ok(())
+        }
+    }
+}
+
+fn get_read_write_update_method(properties: &[Property]) -> TokenStream {
+    let prop_read_writes = get_read_write_update_body(properties, quote! { writer });
+
+    quote! {
+        fn read_write_update(reader: &mut BitReader, writer: &mut BitWriter) -> Result<(), SerdeErr> {
+            #prop_read_writes
+            Ok(())
+        }
+    }
+}
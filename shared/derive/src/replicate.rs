@@ -1,8 +1,9 @@
+use naia_serde_attributes::FieldAttributes;
 use proc_macro2::{Punct, Spacing, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{
-    parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Ident, Index, LitStr, Member,
-    PathArguments, Type,
+    parse_macro_input, Data, DeriveInput, Error, Field, Fields, GenericArgument, Ident, Index,
+    LitStr, Member, Path, PathArguments, PathSegment, Result, Type,
 };
 
 use crate::shared::{get_struct_type, StructType};
@@ -14,6 +15,14 @@ pub struct NormalProperty {
     pub inner_type: Type,
     pub uppercase_variable_name: Ident,
     pub index: usize,
+    pub encoding: Option<PropertyEncoding>,
+}
+
+/// Paths of the functions used to serialize a Property which doesn't use the
+/// default encoding of its inner type
+pub struct PropertyEncoding {
+    pub ser_fn: TokenStream,
+    pub de_fn: TokenStream,
 }
 
 pub struct EntityProperty {
@@ -22,15 +31,24 @@ pub struct EntityProperty {
     pub index: usize,
 }
 
+pub struct NestedProperty {
+    pub variable_name: Ident,
+    pub inner_type: Type,
+    pub uppercase_variable_name: Ident,
+    pub index: usize,
+}
+
 pub struct NonReplicatedProperty {
     pub variable_name: Ident,
     pub field_type: Type,
+    pub default_fn: Option<Path>,
 }
 
 #[allow(clippy::large_enum_variant)]
 pub enum Property {
     Normal(NormalProperty),
     Entity(EntityProperty),
+    Nested(NestedProperty),
     NonReplicated(NonReplicatedProperty),
 }
 
@@ -41,7 +59,10 @@ pub fn replicate_impl(
     let input = parse_macro_input!(input as DeriveInput);
 
     // Helper Properties
-    let properties = get_properties(&input);
+    let properties = match get_properties(&input, &shared_crate_name) {
+        Ok(properties) => properties,
+        Err(error) => return error.to_compile_error().into(),
+    };
     let struct_type = get_struct_type(&input);
 
     // Names
@@ -57,14 +78,7 @@ pub fn replicate_impl(
 
     // Definitions
     let property_enum_definition = get_property_enum_definition(&enum_name, &properties);
-    let diff_mask_size = {
-        let len = properties.len();
-        if len == 0 {
-            0
-        } else {
-            ((len - 1) / 8) + 1
-        }
-    } as u8;
+    let property_count = get_property_count(&properties);
 
     // Methods
     let new_complete_method =
@@ -72,6 +86,7 @@ pub fn replicate_impl(
     let create_builder_method = get_create_builder_method(&builder_name);
     let read_method = get_read_method(&replica_name, &properties, &struct_type);
     let read_create_update_method = get_read_create_update_method(&replica_name, &properties);
+    let update_field_names_method = get_update_field_names_method(&properties, &struct_type);
 
     let dyn_ref_method = get_dyn_ref_method();
     let dyn_mut_method = get_dyn_mut_method();
@@ -79,8 +94,12 @@ pub fn replicate_impl(
     let mirror_method = get_mirror_method(&replica_name, &properties, &struct_type);
     let set_mutator_method = get_set_mutator_method(&properties, &struct_type);
     let read_apply_update_method = get_read_apply_update_method(&properties, &struct_type);
+    let read_apply_authority_update_method =
+        get_read_apply_authority_update_method(&properties, &struct_type);
     let read_apply_field_update_method =
-        get_read_apply_field_update_method(&properties, &struct_type);
+        get_read_apply_field_update_method(&properties, &struct_type, false);
+    let read_apply_authority_field_update_method =
+        get_read_apply_field_update_method(&properties, &struct_type, true);
     let write_method = get_write_method(&properties, &struct_type);
     let write_update_method = get_write_update_method(&enum_name, &properties, &struct_type);
     // let has_entity_properties = get_has_entity_properties_method(&properties);
@@ -98,6 +117,7 @@ pub fn replicate_impl(
                 ReplicaDynRef, ReplicaDynMut, LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut, ComponentKind, Named,
                 BitReader, BitWrite, BitWriter, OwnedBitReader, SerdeErr, Serde, LocalEntity,
                 EntityProperty, GlobalEntity, Replicate, Property, ComponentKinds, ReplicateBuilder, ComponentFieldUpdate,
+                NestedProperty, NestedReplicate,
             };
             use super::*;
 
@@ -107,6 +127,7 @@ pub fn replicate_impl(
             impl ReplicateBuilder for #builder_name {
                 #read_method
                 #read_create_update_method
+                #update_field_names_method
                 #split_update_method
             }
             impl Named for #builder_name {
@@ -139,7 +160,14 @@ pub fn replicate_impl(
                 fn copy_to_box(&self) -> Box<dyn Replicate> {
                     Box::new(self.clone())
                 }
-                fn diff_mask_size(&self) -> u8 { #diff_mask_size }
+                fn diff_mask_size(&self) -> u8 {
+                    let property_count: u8 = #property_count;
+                    if property_count == 0 {
+                        0
+                    } else {
+                        ((property_count - 1) / 8) + 1
+                    }
+                }
                 #create_builder_method
                 #dyn_ref_method
                 #dyn_mut_method
@@ -148,7 +176,9 @@ pub fn replicate_impl(
                 #write_method
                 #write_update_method
                 #read_apply_update_method
+                #read_apply_authority_update_method
                 #read_apply_field_update_method
+                #read_apply_authority_field_update_method
                 #relations_waiting_method
                 #relations_complete_method
             }
@@ -167,7 +197,7 @@ fn get_variable_name_for_unnamed_field(index: usize, span: Span) -> Ident {
 }
 
 /// Get the field name as a TokenStream
-fn get_field_name(property: &Property, struct_type: &StructType) -> Member {
+pub fn get_field_name(property: &Property, struct_type: &StructType) -> Member {
     match *struct_type {
         StructType::Struct => Member::from(property.variable_name().clone()),
         StructType::TupleStruct => {
@@ -184,7 +214,12 @@ fn get_field_name(property: &Property, struct_type: &StructType) -> Member {
 }
 
 impl Property {
-    pub fn normal(index: usize, variable_name: Ident, inner_type: Type) -> Self {
+    pub fn normal(
+        index: usize,
+        variable_name: Ident,
+        inner_type: Type,
+        encoding: Option<PropertyEncoding>,
+    ) -> Self {
         Self::Normal(NormalProperty {
             index,
             variable_name: variable_name.clone(),
@@ -193,6 +228,7 @@ impl Property {
                 variable_name.to_string().to_uppercase().as_str(),
                 Span::call_site(),
             ),
+            encoding,
         })
     }
 
@@ -207,16 +243,29 @@ impl Property {
         })
     }
 
-    pub fn nonreplicated(variable_name: Ident, field_type: Type) -> Self {
+    pub fn nested(index: usize, variable_name: Ident, inner_type: Type) -> Self {
+        Self::Nested(NestedProperty {
+            index,
+            variable_name: variable_name.clone(),
+            inner_type,
+            uppercase_variable_name: Ident::new(
+                variable_name.to_string().to_uppercase().as_str(),
+                Span::call_site(),
+            ),
+        })
+    }
+
+    pub fn nonreplicated(variable_name: Ident, field_type: Type, default_fn: Option<Path>) -> Self {
         Self::NonReplicated(NonReplicatedProperty {
             variable_name: variable_name.clone(),
             field_type,
+            default_fn,
         })
     }
 
     pub fn is_replicated(&self) -> bool {
         match self {
-            Self::Normal(_) | Self::Entity(_) => true,
+            Self::Normal(_) | Self::Entity(_) | Self::Nested(_) => true,
             Self::NonReplicated(_) => false,
         }
     }
@@ -225,6 +274,7 @@ impl Property {
         match self {
             Self::Normal(property) => &property.variable_name,
             Self::Entity(property) => &property.variable_name,
+            Self::Nested(property) => &property.variable_name,
             Self::NonReplicated(property) => &property.variable_name,
         }
     }
@@ -233,6 +283,7 @@ impl Property {
         match self {
             Self::Normal(property) => &property.uppercase_variable_name,
             Self::Entity(property) => &property.uppercase_variable_name,
+            Self::Nested(property) => &property.uppercase_variable_name,
             Self::NonReplicated(_) => panic!("Unused for non-replicated properties"),
         }
     }
@@ -241,29 +292,181 @@ impl Property {
         match self {
             Self::Normal(property) => property.index,
             Self::Entity(property) => property.index,
+            Self::Nested(property) => property.index,
             Self::NonReplicated(_) => panic!("Unused for non-replicated properties"),
         }
     }
+
+    /// The number of DiffMask bits used by the Property, as a constant
+    /// expression
+    pub fn property_count(&self) -> TokenStream {
+        match self {
+            Self::Normal(_) | Self::Entity(_) => quote! { 1 },
+            Self::Nested(property) => {
+                let inner_type = &property.inner_type;
+                quote! { <#inner_type as NestedReplicate>::PROPERTY_COUNT }
+            }
+            Self::NonReplicated(_) => quote! { 0 },
+        }
+    }
 }
 
-fn get_properties(input: &DeriveInput) -> Vec<Property> {
+impl NormalProperty {
+    pub fn new_read(&self) -> TokenStream {
+        let field_type = &self.inner_type;
+        match &self.encoding {
+            Some(PropertyEncoding { de_fn, .. }) => quote! {
+                Property::<#field_type>::new_read_with(reader, #de_fn)?
+            },
+            None => quote! {
+                Property::<#field_type>::new_read(reader)?
+            },
+        }
+    }
+
+    pub fn read_write(&self, writer: TokenStream) -> TokenStream {
+        let field_type = &self.inner_type;
+        match &self.encoding {
+            Some(PropertyEncoding { ser_fn, de_fn }) => quote! {
+                Property::<#field_type>::read_write_with(reader, #writer, #de_fn, #ser_fn)?;
+            },
+            None => quote! {
+                Property::<#field_type>::read_write(reader, #writer)?;
+            },
+        }
+    }
+
+    pub fn read(&self, field_name: &Member) -> TokenStream {
+        match &self.encoding {
+            Some(PropertyEncoding { de_fn, .. }) => quote! {
+                Property::read_with(&mut self.#field_name, reader, #de_fn)?;
+            },
+            None => quote! {
+                Property::read(&mut self.#field_name, reader)?;
+            },
+        }
+    }
+
+    pub fn read_authority(&self, field_name: &Member) -> TokenStream {
+        match &self.encoding {
+            Some(PropertyEncoding { de_fn, .. }) => quote! {
+                Property::read_authority_with(&mut self.#field_name, reader, #de_fn)?;
+            },
+            None => quote! {
+                Property::read_authority(&mut self.#field_name, reader)?;
+            },
+        }
+    }
+
+    pub fn write(&self, field_name: &Member) -> TokenStream {
+        match &self.encoding {
+            Some(PropertyEncoding { ser_fn, .. }) => quote! {
+                Property::write_with(&self.#field_name, writer, #ser_fn);
+            },
+            None => quote! {
+                Property::write(&self.#field_name, writer);
+            },
+        }
+    }
+}
+
+fn get_property_encoding(
+    field: &Field,
+    attributes: &FieldAttributes,
+    inner_type: &Type,
+    shared_crate_name: &TokenStream,
+) -> Result<Option<PropertyEncoding>> {
+    match attributes {
+        FieldAttributes::Default => Ok(None),
+        FieldAttributes::Skip(_) => Err(Error::new_spanned(
+            field,
+            "`#[naia(skip)]` can't be used on a Property, use a plain field instead to keep it from being replicated",
+        )),
+        _ => Ok(Some(PropertyEncoding {
+            ser_fn: attributes.ser_fn(shared_crate_name, inner_type),
+            de_fn: attributes.de_fn(shared_crate_name, inner_type),
+        })),
+    }
+}
+
+fn get_nonreplicated_default_fn(
+    field: &Field,
+    attributes: FieldAttributes,
+) -> Result<Option<Path>> {
+    match attributes {
+        FieldAttributes::Default | FieldAttributes::Skip(None) => Ok(None),
+        FieldAttributes::Skip(Some(default_fn)) => Ok(Some(default_fn)),
+        _ => Err(Error::new_spanned(
+            field,
+            "naia attributes other than `skip` can only be used on Property fields",
+        )),
+    }
+}
+
+fn check_entity_property_attributes(field: &Field, attributes: &FieldAttributes) -> Result<()> {
+    if !attributes.is_default() {
+        return Err(Error::new_spanned(
+            field,
+            "naia attributes are not supported on EntityProperty fields",
+        ));
+    }
+    Ok(())
+}
+
+fn check_nested_property_attributes(field: &Field, attributes: &FieldAttributes) -> Result<()> {
+    if !attributes.is_default() {
+        return Err(Error::new_spanned(
+            field,
+            "naia attributes are not supported on NestedProperty fields, put them on the Properties of the nested struct instead",
+        ));
+    }
+    Ok(())
+}
+
+/// Get the inner type of a generic field type such as `Property<T>`
+fn get_inner_type(property_seg: &PathSegment) -> Option<&Type> {
+    if let PathArguments::AngleBracketed(angle_args) = &property_seg.arguments {
+        if let Some(GenericArgument::Type(inner_type)) = angle_args.args.first() {
+            return Some(inner_type);
+        }
+    }
+    None
+}
+
+pub fn get_properties(
+    input: &DeriveInput,
+    shared_crate_name: &TokenStream,
+) -> Result<Vec<Property>> {
     let mut fields = Vec::new();
 
     if let Data::Struct(data_struct) = &input.data {
         match &data_struct.fields {
             Fields::Named(fields_named) => {
                 for field in fields_named.named.iter() {
+                    let attributes = FieldAttributes::from_field(field)?;
                     if let Some(variable_name) = &field.ident {
                         if let Type::Path(type_path) = &field.ty {
                             if let Some(property_seg) = type_path.path.segments.first() {
                                 let property_type = property_seg.ident.clone();
                                 // EntityProperty
                                 if property_type == "EntityProperty" {
+                                    check_entity_property_attributes(field, &attributes)?;
                                     fields.push(Property::entity(
                                         fields.len(),
                                         variable_name.clone(),
                                     ));
                                     continue;
+                                // NestedProperty
+                                } else if property_type == "NestedProperty" {
+                                    if let Some(inner_type) = get_inner_type(property_seg) {
+                                        check_nested_property_attributes(field, &attributes)?;
+                                        fields.push(Property::nested(
+                                            fields.len(),
+                                            variable_name.clone(),
+                                            inner_type.clone(),
+                                        ));
+                                        continue;
+                                    }
                                 // Property
                                 } else if property_type == "Property" {
                                     if let PathArguments::AngleBracketed(angle_args) =
@@ -272,10 +475,17 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
                                         if let Some(GenericArgument::Type(inner_type)) =
                                             angle_args.args.first()
                                         {
+                                            let encoding = get_property_encoding(
+                                                field,
+                                                &attributes,
+                                                inner_type,
+                                                shared_crate_name,
+                                            )?;
                                             fields.push(Property::normal(
                                                 fields.len(),
                                                 variable_name.clone(),
                                                 inner_type.clone(),
+                                                encoding,
                                             ));
                                             continue;
                                         }
@@ -285,6 +495,7 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
                                     fields.push(Property::nonreplicated(
                                         variable_name.clone(),
                                         field.ty.clone(),
+                                        get_nonreplicated_default_fn(field, attributes)?,
                                     ));
                                 }
                             }
@@ -294,24 +505,43 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
             }
             Fields::Unnamed(fields_unnamed) => {
                 for (index, field) in fields_unnamed.unnamed.iter().enumerate() {
+                    let attributes = FieldAttributes::from_field(field)?;
                     if let Type::Path(type_path) = &field.ty {
                         if let Some(property_seg) = type_path.path.segments.first() {
                             let property_type = property_seg.ident.clone();
                             let variable_name =
                                 get_variable_name_for_unnamed_field(index, property_type.span());
                             if property_type == "EntityProperty" {
+                                check_entity_property_attributes(field, &attributes)?;
                                 fields.push(Property::entity(fields.len(), variable_name));
                                 continue;
+                            } else if property_type == "NestedProperty" {
+                                if let Some(inner_type) = get_inner_type(property_seg) {
+                                    check_nested_property_attributes(field, &attributes)?;
+                                    fields.push(Property::nested(
+                                        fields.len(),
+                                        variable_name,
+                                        inner_type.clone(),
+                                    ));
+                                    continue;
+                                }
                             } else if let PathArguments::AngleBracketed(angle_args) =
                                 &property_seg.arguments
                             {
                                 if let Some(GenericArgument::Type(inner_type)) =
                                     angle_args.args.first()
                                 {
+                                    let encoding = get_property_encoding(
+                                        field,
+                                        &attributes,
+                                        inner_type,
+                                        shared_crate_name,
+                                    )?;
                                     fields.push(Property::normal(
                                         fields.len(),
                                         variable_name,
                                         inner_type.clone(),
+                                        encoding,
                                     ));
                                     continue;
                                 }
@@ -326,10 +556,21 @@ fn get_properties(input: &DeriveInput) -> Vec<Property> {
         panic!("Can only derive Replicate on a struct");
     }
 
-    fields
+    Ok(fields)
+}
+
+/// Get the total number of DiffMask bits used by the Properties, as a constant
+/// expression
+pub fn get_property_count(properties: &[Property]) -> TokenStream {
+    let mut output = quote! { 0 };
+    for property in properties.iter().filter(|p| p.is_replicated()) {
+        let property_count = property.property_count();
+        output = quote! { #output + #property_count };
+    }
+    output
 }
 
-fn get_property_enum_definition(enum_name: &Ident, properties: &[Property]) -> TokenStream {
+pub fn get_property_enum_definition(enum_name: &Ident, properties: &[Property]) -> TokenStream {
     if properties.is_empty() {
         return quote! {
             enum #enum_name {}
@@ -340,13 +581,18 @@ fn get_property_enum_definition(enum_name: &Ident, properties: &[Property]) -> T
 
     let mut variant_list = quote! {};
 
-    for (_, property) in properties.iter().filter(|p| p.is_replicated()).enumerate() {
-        let index = syn::Index::from(property.index());
+    // Each Property's bit in the DiffMask comes after the bits of all the
+    // Properties before it, nested structs taking up one bit per Property
+    let mut offset = quote! { 0 };
+
+    for property in properties.iter().filter(|p| p.is_replicated()) {
         let uppercase_variant_name = property.uppercase_variable_name();
+        let property_count = property.property_count();
 
         let new_output_right = quote! {
-            #uppercase_variant_name = #index as u8,
+            #uppercase_variant_name = #offset,
         };
+        offset = quote! { #offset + #property_count };
         let new_output_result = quote! {
             #variant_list
             #new_output_right
@@ -378,7 +624,7 @@ pub fn get_dyn_mut_method() -> TokenStream {
     }
 }
 
-fn get_clone_method(
+pub fn get_clone_method(
     replica_name: &Ident,
     properties: &[Property],
     struct_type: &StructType,
@@ -389,7 +635,7 @@ fn get_clone_method(
     for property in properties.iter() {
         let field_name = get_field_name(property, struct_type);
         match property {
-            Property::Normal(_) => {
+            Property::Normal(_) | Property::Nested(_) => {
                 let new_output_right = quote! {
                     (*self.#field_name).clone(),
                 };
@@ -492,10 +738,16 @@ pub fn get_new_complete_method(
     let mut args = quote! {};
     for property in properties.iter() {
         match property {
-            Property::Normal(property) => {
-                let field_name = &property.variable_name;
-                let field_type = &property.inner_type;
-
+            Property::Normal(NormalProperty {
+                variable_name: field_name,
+                inner_type: field_type,
+                ..
+            })
+            | Property::Nested(NestedProperty {
+                variable_name: field_name,
+                inner_type: field_type,
+                ..
+            }) => {
                 let new_output_right = quote! {
                     #field_name: #field_type,
                 };
@@ -548,6 +800,27 @@ pub fn get_new_complete_method(
                     }
                 }
             }
+            Property::Nested(property) => {
+                let field_name = &property.variable_name;
+                let field_type = &property.inner_type;
+                let uppercase_variant_name = &property.uppercase_variable_name;
+
+                match *struct_type {
+                    StructType::Struct => {
+                        quote! {
+                            #field_name: NestedProperty::<#field_type>::host_owned(#field_name, #enum_name::#uppercase_variant_name as u8)
+                        }
+                    }
+                    StructType::TupleStruct => {
+                        quote! {
+                            NestedProperty::<#field_type>::host_owned(#field_name, #enum_name::#uppercase_variant_name as u8)
+                        }
+                    }
+                    _ => {
+                        quote! {}
+                    }
+                }
+            }
             Property::Entity(property) => {
                 let field_name = &property.variable_name;
                 let uppercase_variant_name = &property.uppercase_variable_name;
@@ -637,6 +910,24 @@ pub fn get_read_method(
     properties: &[Property],
     struct_type: &StructType,
 ) -> TokenStream {
+    let (prop_reads, replica_build) = get_read_body(replica_name, properties, struct_type);
+
+    quote! {
+        fn read(&self, reader: &mut BitReader, converter: &dyn LocalEntityAndGlobalEntityConverter) -> Result<Box<dyn Replicate>, SerdeErr> {
+            #prop_reads
+
+            return Ok(Box::new(#replica_build));
+        }
+    }
+}
+
+/// Get the statements reading each Property, and the expression building the
+/// struct from them
+pub fn get_read_body(
+    replica_name: &Ident,
+    properties: &[Property],
+    struct_type: &StructType,
+) -> (TokenStream, TokenStream) {
     let mut prop_names = quote! {};
     for property in properties.iter() {
         let field_name = property.variable_name();
@@ -655,9 +946,9 @@ pub fn get_read_method(
         let field_name = property.variable_name();
         let new_output_right = match property {
             Property::Normal(inner_property) => {
-                let field_type = &inner_property.inner_type;
+                let new_read = inner_property.new_read();
                 quote! {
-                    let #field_name = Property::<#field_type>::new_read(reader)?;
+                    let #field_name = #new_read;
                 }
             }
             Property::Entity(_) => {
@@ -665,11 +956,22 @@ pub fn get_read_method(
                     let #field_name = EntityProperty::new_read(reader, converter)?;
                 }
             }
+            Property::Nested(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    let #field_name = NestedProperty::<#field_type>::new_read(reader)?;
+                }
+            }
             Property::NonReplicated(inner_property) => {
                 let field_name = &inner_property.variable_name;
                 let field_type = &inner_property.field_type;
-                quote! {
-                    let #field_name = <#field_type>::default();
+                match &inner_property.default_fn {
+                    Some(default_fn) => quote! {
+                        let #field_name: #field_type = #default_fn();
+                    },
+                    None => quote! {
+                        let #field_name = <#field_type>::default();
+                    },
                 }
             }
         };
@@ -703,27 +1005,94 @@ pub fn get_read_method(
         }
     };
 
+    (prop_reads, replica_build)
+}
+
+pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Property]) -> TokenStream {
+    let prop_read_writes = get_read_write_update_body(properties, quote! { &mut update_writer });
+
     quote! {
-        fn read(&self, reader: &mut BitReader, converter: &dyn LocalEntityAndGlobalEntityConverter) -> Result<Box<dyn Replicate>, SerdeErr> {
-            #prop_reads
+        fn read_create_update(&self, reader: &mut BitReader) -> Result<ComponentUpdate, SerdeErr> {
 
-            return Ok(Box::new(#replica_build));
+            let mut update_writer = BitWriter::new();
+
+            #prop_read_writes
+
+            let owned_reader = update_writer.to_owned_reader();
+
+            return Ok(ComponentUpdate::new(ComponentKind::of::<#replica_name>(), owned_reader));
         }
     }
 }
 
-pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Property]) -> TokenStream {
+/// Get the method naming the Properties an update carries, skipping over the
+/// value of each
+fn get_update_field_names_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let mut field_reads = quote! {};
+    for property in properties.iter() {
+        let read_write = match property {
+            Property::Normal(inner_property) => {
+                inner_property.read_write(quote! { &mut skip_writer })
+            }
+            Property::Entity(_) => quote! {
+                EntityProperty::read_write(reader, &mut skip_writer)?;
+            },
+            Property::Nested(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    NestedProperty::<#field_type>::read_write(reader, &mut skip_writer)?;
+                }
+            }
+            Property::NonReplicated(_) => {
+                continue;
+            }
+        };
+        let field_name = match get_field_name(property, struct_type) {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let field_name_str = LitStr::new(&field_name, Span::call_site());
+
+        field_reads = quote! {
+            #field_reads
+            {
+                let should_read = bool::de(reader)?;
+                if should_read {
+                    field_names.push(#field_name_str.to_string());
+                    #read_write
+                }
+            }
+        };
+    }
+
+    quote! {
+        fn update_field_names(&self, update: &ComponentUpdate) -> Result<Vec<String>, SerdeErr> {
+            let mut update_reader = update.reader();
+            let reader = &mut update_reader;
+            let mut skip_writer = BitWriter::new();
+            let mut field_names = Vec::new();
+
+            #field_reads
+
+            return Ok(field_names);
+        }
+    }
+}
+
+/// Get the statements reading an update of each Property and writing it to
+/// the given writer
+pub fn get_read_write_update_body(properties: &[Property], writer: TokenStream) -> TokenStream {
     let mut prop_read_writes = quote! {};
     for property in properties.iter() {
         let new_output_right = match property {
             Property::Normal(inner_property) => {
-                let field_type = &inner_property.inner_type;
+                let read_write = inner_property.read_write(writer.clone());
                 quote! {
                     {
                         let should_read = bool::de(reader)?;
-                        should_read.ser(&mut update_writer);
+                        should_read.ser(#writer);
                         if should_read {
-                            Property::<#field_type>::read_write(reader, &mut update_writer)?;
+                            #read_write
                         }
                     }
                 }
@@ -732,9 +1101,21 @@ pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Propert
                 quote! {
                     {
                         let should_read = bool::de(reader)?;
-                        should_read.ser(&mut update_writer);
+                        should_read.ser(#writer);
                         if should_read {
-                            EntityProperty::read_write(reader, &mut update_writer)?;
+                            EntityProperty::read_write(reader, #writer)?;
+                        }
+                    }
+                }
+            }
+            Property::Nested(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    {
+                        let should_read = bool::de(reader)?;
+                        should_read.ser(#writer);
+                        if should_read {
+                            NestedProperty::<#field_type>::read_write(reader, #writer)?;
                         }
                     }
                 }
@@ -751,18 +1132,7 @@ pub fn get_read_create_update_method(replica_name: &Ident, properties: &[Propert
         prop_read_writes = new_output_result;
     }
 
-    quote! {
-        fn read_create_update(&self, reader: &mut BitReader) -> Result<ComponentUpdate, SerdeErr> {
-
-            let mut update_writer = BitWriter::new();
-
-            #prop_read_writes
-
-            let owned_reader = update_writer.to_owned_reader();
-
-            return Ok(ComponentUpdate::new(ComponentKind::of::<#replica_name>(), owned_reader));
-        }
-    }
+    prop_read_writes
 }
 
 fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> TokenStream {
@@ -771,12 +1141,12 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
     for property in properties.iter() {
         let new_output_right = match property {
             Property::Normal(inner_property) => {
-                let field_type = &inner_property.inner_type;
+                let read_write = inner_property.read_write(quote! { &mut ready_writer });
                 quote! {
                     let should_read = bool::de(reader)?;
                     should_read.ser(&mut ready_writer);
                     if should_read {
-                        Property::<#field_type>::read_write(reader, &mut ready_writer)?;
+                        #read_write
                         ready_did_write = true;
                     }
                 }
@@ -811,6 +1181,17 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
                     }
                 }
             }
+            Property::Nested(inner_property) => {
+                let field_type = &inner_property.inner_type;
+                quote! {
+                    let should_read = bool::de(reader)?;
+                    should_read.ser(&mut ready_writer);
+                    if should_read {
+                        NestedProperty::<#field_type>::read_write(reader, &mut ready_writer)?;
+                        ready_did_write = true;
+                    }
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -864,22 +1245,77 @@ fn get_split_update_method(replica_name: &Ident, properties: &[Property]) -> Tok
 }
 
 fn get_read_apply_update_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let output = get_read_apply_update_body(properties, struct_type, false);
+
+    quote! {
+        fn read_apply_update(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter, mut update: ComponentUpdate) -> Result<(), SerdeErr> {
+            let reader = &mut update.reader();
+            #output
+            Ok(())
+        }
+    }
+}
+
+fn get_read_apply_authority_update_method(
+    properties: &[Property],
+    struct_type: &StructType,
+) -> TokenStream {
+    let output = get_read_apply_update_body(properties, struct_type, true);
+
+    quote! {
+        fn read_apply_authority_update(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter, mut update: ComponentUpdate) -> Result<(), SerdeErr> {
+            let reader = &mut update.reader();
+            #output
+            Ok(())
+        }
+    }
+}
+
+/// Get the statements reading an update and applying it to each Property. An
+/// authority update is applied to host-owned Properties
+pub fn get_read_apply_update_body(
+    properties: &[Property],
+    struct_type: &StructType,
+    authority: bool,
+) -> TokenStream {
     let mut output = quote! {};
 
     for property in properties.iter() {
         let field_name = get_field_name(property, struct_type);
         let new_output_right = match property {
-            Property::Normal(_) => {
+            Property::Normal(inner_property) => {
+                let read = if authority {
+                    inner_property.read_authority(&field_name)
+                } else {
+                    inner_property.read(&field_name)
+                };
                 quote! {
                     if bool::de(reader)? {
-                        Property::read(&mut self.#field_name, reader)?;
+                        #read
                     }
                 }
             }
             Property::Entity(_) => {
+                let read = if authority {
+                    quote! { EntityProperty::read_authority }
+                } else {
+                    quote! { EntityProperty::read }
+                };
                 quote! {
                     if bool::de(reader)? {
-                        EntityProperty::read(&mut self.#field_name, reader, converter)?;
+                        #read(&mut self.#field_name, reader, converter)?;
+                    }
+                }
+            }
+            Property::Nested(_) => {
+                let read = if authority {
+                    quote! { NestedProperty::read_authority }
+                } else {
+                    quote! { NestedProperty::read }
+                };
+                quote! {
+                    if bool::de(reader)? {
+                        #read(&mut self.#field_name, reader)?;
                     }
                 }
             }
@@ -895,32 +1331,39 @@ fn get_read_apply_update_method(properties: &[Property], struct_type: &StructTyp
         output = new_output_result;
     }
 
-    quote! {
-        fn read_apply_update(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter, mut update: ComponentUpdate) -> Result<(), SerdeErr> {
-            let reader = &mut update.reader();
-            #output
-            Ok(())
-        }
-    }
+    output
 }
 
+/// Get the method applying a field update, or when `authority` is set, the
+/// method applying a field update to host-owned Properties
 fn get_read_apply_field_update_method(
     properties: &[Property],
     struct_type: &StructType,
+    authority: bool,
 ) -> TokenStream {
+    let read = if authority {
+        quote! { EntityProperty::read_authority }
+    } else {
+        quote! { EntityProperty::read }
+    };
+    let method_name = if authority {
+        quote! { read_apply_authority_field_update }
+    } else {
+        quote! { read_apply_field_update }
+    };
     let mut output = quote! {};
 
     for property in properties.iter() {
         let field_name = get_field_name(property, struct_type);
         let new_output_right = match property {
-            Property::Normal(_) | Property::NonReplicated(_) => {
+            Property::Normal(_) | Property::Nested(_) | Property::NonReplicated(_) => {
                 continue;
             }
             Property::Entity(inner_property) => {
                 let index = inner_property.index as u8;
                 quote! {
                     #index => {
-                        EntityProperty::read(&mut self.#field_name, reader, converter)?;
+                        #read(&mut self.#field_name, reader, converter)?;
                     }
                 }
             }
@@ -934,7 +1377,7 @@ fn get_read_apply_field_update_method(
     }
 
     quote! {
-        fn read_apply_field_update(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter, mut update: ComponentFieldUpdate) -> Result<(), SerdeErr> {
+        fn #method_name(&mut self, converter: &dyn LocalEntityAndGlobalEntityConverter, mut update: ComponentFieldUpdate) -> Result<(), SerdeErr> {
             let reader = &mut update.reader();
             match update.field_id() {
                 #output
@@ -946,19 +1389,32 @@ fn get_read_apply_field_update_method(
 }
 
 fn get_write_method(properties: &[Property], struct_type: &StructType) -> TokenStream {
+    let property_writes = get_write_body(properties, struct_type);
+
+    quote! {
+        fn write(&self, component_kinds: &ComponentKinds, writer: &mut dyn BitWrite, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) {
+            self.kind().ser(component_kinds, writer);
+            #property_writes
+        }
+    }
+}
+
+/// Get the statements writing each Property
+pub fn get_write_body(properties: &[Property], struct_type: &StructType) -> TokenStream {
     let mut property_writes = quote! {};
 
     for property in properties.iter() {
         let field_name = get_field_name(property, struct_type);
         let new_output_right = match property {
-            Property::Normal(_) => {
+            Property::Normal(inner_property) => inner_property.write(&field_name),
+            Property::Entity(_) => {
                 quote! {
-                    Property::write(&self.#field_name, writer);
+                    EntityProperty::write(&self.#field_name, writer, converter);
                 }
             }
-            Property::Entity(_) => {
+            Property::Nested(_) => {
                 quote! {
-                    EntityProperty::write(&self.#field_name, writer, converter);
+                    NestedProperty::write(&self.#field_name, writer);
                 }
             }
             Property::NonReplicated(_) => {
@@ -973,15 +1429,26 @@ fn get_write_method(properties: &[Property], struct_type: &StructType) -> TokenS
         property_writes = new_output_result;
     }
 
+    property_writes
+}
+
+fn get_write_update_method(
+    enum_name: &Ident,
+    properties: &[Property],
+    struct_type: &StructType,
+) -> TokenStream {
+    let output = get_write_update_body(enum_name, properties, struct_type);
+
     quote! {
-        fn write(&self, component_kinds: &ComponentKinds, writer: &mut dyn BitWrite, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) {
-            self.kind().ser(component_kinds, writer);
-            #property_writes
+        fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) {
+            #output
         }
     }
 }
 
-fn get_write_update_method(
+/// Get the statements writing each Property which has changed according to
+/// the DiffMask
+pub fn get_write_update_body(
     enum_name: &Ident,
     properties: &[Property],
     struct_type: &StructType,
@@ -993,10 +1460,11 @@ fn get_write_update_method(
         let new_output_right = match property {
             Property::Normal(property) => {
                 let uppercase_variant_name = &property.uppercase_variable_name;
+                let write = property.write(&field_name);
                 quote! {
                     if let Some(true) = diff_mask.bit(#enum_name::#uppercase_variant_name as u8) {
                         true.ser(writer);
-                        Property::write(&self.#field_name, writer);
+                        #write
                     } else {
                         false.ser(writer);
                     }
@@ -1013,6 +1481,24 @@ fn get_write_update_method(
                     }
                 }
             }
+            Property::Nested(property) => {
+                let uppercase_variant_name = &property.uppercase_variable_name;
+                let field_type = &property.inner_type;
+                quote! {
+                    {
+                        let sub_mask = diff_mask.sub_mask(
+                            #enum_name::#uppercase_variant_name as u8,
+                            <#field_type as NestedReplicate>::PROPERTY_COUNT,
+                        );
+                        if !sub_mask.is_clear() {
+                            true.ser(writer);
+                            NestedProperty::write_update(&self.#field_name, &sub_mask, writer);
+                        } else {
+                            false.ser(writer);
+                        }
+                    }
+                }
+            }
             Property::NonReplicated(_) => {
                 continue;
             }
@@ -1025,11 +1511,7 @@ fn get_write_update_method(
         output = new_output_result;
     }
 
-    quote! {
-        fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite, converter: &mut dyn LocalEntityAndGlobalEntityConverterMut) {
-            #output
-        }
-    }
+    output
 }
 
 // fn get_has_entity_properties_method(properties: &[Property]) -> TokenStream {
@@ -0,0 +1,279 @@
+//! # Naia Serde Attributes
+//! Parses the `#[naia(..)]` field attributes shared by naia's derive macros
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Field, Lit, LitInt, Meta, NestedMeta, Path, Result, Type};
+
+/// How a single field is serialized, as configured by `#[naia(..)]` attributes
+pub enum FieldAttributes {
+    /// Use the field type's own Serde implementation
+    Default,
+    /// `#[naia(bits = N)]`, write an integer with N bits
+    Bits(LitInt),
+    /// `#[naia(varint)]`, write an integer in variable-length chunks
+    VarInt,
+    /// `#[naia(with = "module")]`, use `module::ser`, `module::de` and
+    /// `module::bit_length`
+    With(Path),
+    /// `#[naia(skip)]`, don't write the field, and read it back as
+    /// `Default::default()`, or by calling the function given with
+    /// `#[naia(skip, default = "function")]`
+    Skip(Option<Path>),
+}
+
+impl FieldAttributes {
+    pub fn from_field(field: &Field) -> Result<Self> {
+        let mut bits = None;
+        let mut varint = false;
+        let mut with = None;
+        let mut skip = false;
+        let mut default = None;
+
+        for attr in &field.attrs {
+            if !attr.path.is_ident("naia") {
+                continue;
+            }
+            let Meta::List(list) = attr.parse_meta()? else {
+                return Err(Error::new_spanned(
+                    attr,
+                    "expected attribute of the form `#[naia(..)]`",
+                ));
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                        skip = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("varint") => {
+                        varint = true;
+                    }
+                    NestedMeta::Meta(Meta::NameValue(name_value))
+                        if name_value.path.is_ident("bits") =>
+                    {
+                        let Lit::Int(lit_int) = name_value.lit else {
+                            return Err(Error::new_spanned(
+                                name_value.lit,
+                                "expected `#[naia(bits = ..)]` to be given an integer",
+                            ));
+                        };
+                        if !matches!(lit_int.base10_parse::<u8>(), Ok(1..=127)) {
+                            return Err(Error::new_spanned(
+                                lit_int,
+                                "`#[naia(bits = ..)]` must be between 1 and 127",
+                            ));
+                        }
+                        bits = Some(lit_int);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(name_value))
+                        if name_value.path.is_ident("with") =>
+                    {
+                        with = Some(parse_path(&name_value.lit, "with")?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(name_value))
+                        if name_value.path.is_ident("default") =>
+                    {
+                        default = Some(parse_path(&name_value.lit, "default")?);
+                    }
+                    nested => {
+                        return Err(Error::new_spanned(
+                            nested,
+                            "unknown naia attribute, expected one of `bits = N`, `varint`, `with = \"module\"`, `skip` or `default = \"function\"`",
+                        ));
+                    }
+                }
+            }
+        }
+
+        if default.is_some() && !skip {
+            return Err(Error::new_spanned(
+                field,
+                "`#[naia(default = ..)]` can only be used together with `skip`",
+            ));
+        }
+        let encodings = [bits.is_some(), varint, with.is_some(), skip];
+        if encodings.iter().filter(|set| **set).count() > 1 {
+            return Err(Error::new_spanned(
+                field,
+                "only one of `bits`, `varint`, `with` or `skip` may be used on a field",
+            ));
+        }
+
+        let attributes = if let Some(bits) = bits {
+            Self::Bits(bits)
+        } else if varint {
+            Self::VarInt
+        } else if let Some(with) = with {
+            Self::With(with)
+        } else if skip {
+            Self::Skip(default)
+        } else {
+            Self::Default
+        };
+        Ok(attributes)
+    }
+
+    /// Whether the field is written using the field type's own Serde
+    /// implementation
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Default)
+    }
+
+    /// Statement writing the field, given a reference to its value
+    pub fn ser(
+        &self,
+        crate_name: &TokenStream,
+        field_type: &Type,
+        value_ref: TokenStream,
+    ) -> TokenStream {
+        match self {
+            Self::Default => quote! { Serde::ser(#value_ref, writer); },
+            Self::Bits(_) | Self::VarInt | Self::With(_) => {
+                let ser_fn = self.ser_fn(crate_name, field_type);
+                quote! { #ser_fn(#value_ref, writer); }
+            }
+            Self::Skip(_) => quote! {},
+        }
+    }
+
+    /// Expression reading the field
+    pub fn de(&self, crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        match self {
+            Self::Default => quote! { Serde::de(reader)? },
+            Self::Bits(_) | Self::VarInt | Self::With(_) => {
+                let de_fn = self.de_fn(crate_name, field_type);
+                quote! { #de_fn(reader)? }
+            }
+            Self::Skip(Some(default)) => quote! { #default() },
+            Self::Skip(None) => quote! { Default::default() },
+        }
+    }
+
+    /// Expression returning the bit length of the field, given a reference to
+    /// its value
+    pub fn bit_length(
+        &self,
+        crate_name: &TokenStream,
+        field_type: &Type,
+        value_ref: TokenStream,
+    ) -> TokenStream {
+        match self {
+            Self::Default => quote! { Serde::bit_length(#value_ref) },
+            Self::Bits(_) | Self::VarInt => {
+                let encoding = self.encoding(crate_name, field_type);
+                quote! { #encoding::bit_length(#value_ref) }
+            }
+            Self::With(module) => quote! { #module::bit_length(#value_ref) },
+            Self::Skip(_) => quote! { 0 },
+        }
+    }
+
+    /// Path of the function used to write the field, for fields which don't
+    /// use the default encoding
+    pub fn ser_fn(&self, crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        match self {
+            Self::Bits(_) | Self::VarInt => {
+                let encoding = self.encoding(crate_name, field_type);
+                quote! { #encoding::ser }
+            }
+            Self::With(module) => quote! { #module::ser },
+            Self::Default | Self::Skip(_) => unreachable!(),
+        }
+    }
+
+    /// Path of the function used to read the field, for fields which don't
+    /// use the default encoding
+    pub fn de_fn(&self, crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        match self {
+            Self::Bits(_) | Self::VarInt => {
+                let encoding = self.encoding(crate_name, field_type);
+                quote! { #encoding::de }
+            }
+            Self::With(module) => quote! { #module::de },
+            Self::Default | Self::Skip(_) => unreachable!(),
+        }
+    }
+
+    fn encoding(&self, crate_name: &TokenStream, field_type: &Type) -> TokenStream {
+        match self {
+            Self::Bits(bits) => quote! {
+                <#crate_name::BitsEncoding<#bits> as #crate_name::FieldEncoding<#field_type>>
+            },
+            Self::VarInt => quote! {
+                <#crate_name::VarIntEncoding as #crate_name::FieldEncoding<#field_type>>
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn parse_path(lit: &Lit, attribute: &str) -> Result<Path> {
+    let Lit::Str(lit_str) = lit else {
+        return Err(Error::new_spanned(
+            lit,
+            format!(
+                "expected `#[naia({} = \"..\")]` to be given a string",
+                attribute
+            ),
+        ));
+    };
+    lit_str.parse::<Path>().map_err(|_| {
+        Error::new_spanned(
+            lit_str,
+            format!("`#[naia({} = ..)]` must be given a path", attribute),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{Data, DeriveInput};
+
+    use super::FieldAttributes;
+
+    fn parse_field(field: &str) -> syn::Result<FieldAttributes> {
+        let input: DeriveInput = syn::parse_str(&format!("struct Test {{ {} }}", field)).unwrap();
+        let Data::Struct(data_struct) = input.data else {
+            unreachable!();
+        };
+        let field = data_struct.fields.iter().next().unwrap();
+        FieldAttributes::from_field(field)
+    }
+
+    #[test]
+    fn parses_attributes() {
+        assert!(parse_field("a: u8").unwrap().is_default());
+        assert!(matches!(
+            parse_field("#[naia(bits = 5)] a: u8").unwrap(),
+            FieldAttributes::Bits(_)
+        ));
+        assert!(matches!(
+            parse_field("#[naia(varint)] a: u64").unwrap(),
+            FieldAttributes::VarInt
+        ));
+        assert!(matches!(
+            parse_field("#[naia(skip, default = \"seven\")] a: u8").unwrap(),
+            FieldAttributes::Skip(Some(_))
+        ));
+    }
+
+    #[test]
+    fn ignores_other_attributes() {
+        assert!(parse_field("#[serde(rename = \"b\")] a: u8")
+            .unwrap()
+            .is_default());
+    }
+
+    #[test]
+    fn rejects_invalid_attributes() {
+        let error = parse_field("#[naia(bist = 5)] a: u8").err().unwrap();
+        assert!(error.to_string().starts_with("unknown naia attribute"));
+
+        assert!(parse_field("#[naia(bits = 0)] a: u8").is_err());
+        assert!(parse_field("#[naia(bits = 200)] a: u8").is_err());
+        assert!(parse_field("#[naia(bits = \"5\")] a: u8").is_err());
+        assert!(parse_field("#[naia(default = \"seven\")] a: u8").is_err());
+        assert!(parse_field("#[naia(bits = 5, varint)] a: u8").is_err());
+        assert!(parse_field("#[naia = \"skip\"] a: u8").is_err());
+    }
+}
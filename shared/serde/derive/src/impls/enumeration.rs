@@ -1,6 +1,7 @@
+use naia_serde_attributes::FieldAttributes;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{DataEnum, Fields};
+use syn::{DataEnum, Fields, Result};
 
 fn bits_needed_for(max_value: usize) -> u8 {
     let mut bits = 1;
@@ -18,13 +19,13 @@ pub fn derive_serde_enum(
     enum_: &DataEnum,
     enum_name: &Ident,
     serde_crate_name: TokenStream,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let variant_number = enum_.variants.len();
     let bits_needed = bits_needed_for(variant_number);
 
-    let ser_method = get_ser_method(enum_, bits_needed);
-    let de_method = get_de_method(enum_, bits_needed);
-    let bit_length_method = get_bit_length_method(enum_, bits_needed);
+    let ser_method = get_ser_method(enum_, bits_needed, &serde_crate_name)?;
+    let de_method = get_de_method(enum_, bits_needed, &serde_crate_name)?;
+    let bit_length_method = get_bit_length_method(enum_, bits_needed, &serde_crate_name)?;
 
     let lowercase_enum_name = Ident::new(
         enum_name.to_string().to_lowercase().as_str(),
@@ -36,7 +37,7 @@ pub fn derive_serde_enum(
         quote! { Serde, BitWrite, UnsignedInteger, BitReader, SerdeErr, ConstBitLength, };
     let imports = quote! { use #serde_crate_name::{#import_types}; };
 
-    quote! {
+    Ok(quote! {
         mod #module_name {
             #imports
             use super::#enum_name;
@@ -47,10 +48,14 @@ pub fn derive_serde_enum(
                 #bit_length_method
             }
         }
-    }
+    })
 }
 
-fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
+fn get_ser_method(
+    enum_: &DataEnum,
+    bits_needed: u8,
+    serde_crate_name: &TokenStream,
+) -> Result<TokenStream> {
     let mut ser = quote! {};
     for (index, variant) in enum_.variants.iter().enumerate() {
         let variant_index = index as u16;
@@ -85,9 +90,14 @@ fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                         .ident
                         .as_ref()
                         .expect("expected field to have a name.");
+                    let field_ser = FieldAttributes::from_field(field)?.ser(
+                        serde_crate_name,
+                        &field.ty,
+                        quote! { #field_name },
+                    );
                     right = quote! {
                         #right
-                        #field_name.ser(writer);
+                        #field_ser
                     }
                 }
                 quote! {
@@ -107,10 +117,15 @@ fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                     let index = UnsignedInteger::<#bits_needed>::new(#variant_index);
                     index.ser(writer);
                 };
-                for field_name in names {
+                for (field_name, field) in names.iter().zip(fields.unnamed.iter()) {
+                    let field_ser = FieldAttributes::from_field(field)?.ser(
+                        serde_crate_name,
+                        &field.ty,
+                        quote! { #field_name },
+                    );
                     right = quote! {
                         #right
-                        #field_name.ser(writer);
+                        #field_ser
                     }
                 }
                 quote! {
@@ -123,16 +138,20 @@ fn get_ser_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
             #base
         }
     }
-    quote! {
+    Ok(quote! {
          fn ser(&self, writer: &mut dyn BitWrite) {
             match self {
                 #ser
             }
          }
-    }
+    })
 }
 
-fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
+fn get_de_method(
+    enum_: &DataEnum,
+    bits_needed: u8,
+    serde_crate_name: &TokenStream,
+) -> Result<TokenStream> {
     let mut de = quote! {};
 
     for (index, variant) in enum_.variants.iter().enumerate() {
@@ -152,9 +171,11 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                         .ident
                         .as_ref()
                         .expect("expected field to have a name.");
+                    let field_de =
+                        FieldAttributes::from_field(field)?.de(serde_crate_name, &field.ty);
                     base = quote! {
                         #base
-                        #field_name: Serde::de(reader)?,
+                        #field_name: #field_de,
                     }
                 }
                 de = quote! {
@@ -166,10 +187,12 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
             }
             Fields::Unnamed(fields) => {
                 let mut base = quote! {};
-                for _ in fields.unnamed.iter() {
+                for field in fields.unnamed.iter() {
+                    let field_de =
+                        FieldAttributes::from_field(field)?.de(serde_crate_name, &field.ty);
                     base = quote! {
                         #base
-                        Serde::de(reader)?,
+                        #field_de,
                     }
                 }
                 de = quote! {
@@ -181,7 +204,7 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
             }
         }
     }
-    quote! {
+    Ok(quote! {
         fn de(reader: &mut BitReader) -> std::result::Result<Self, SerdeErr> {
             let index: UnsignedInteger<#bits_needed> = Serde::de(reader)?;
             let index_u16: u16 = index.get() as u16;
@@ -190,12 +213,16 @@ fn get_de_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                 _ => return Err(SerdeErr)
             })
         }
-    }
+    })
 }
 
-fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
+fn get_bit_length_method(
+    enum_: &DataEnum,
+    bits_needed: u8,
+    serde_crate_name: &TokenStream,
+) -> Result<TokenStream> {
     let mut bit_length = quote! {};
-    for (_, variant) in enum_.variants.iter().enumerate() {
+    for variant in enum_.variants.iter() {
         let variant_name = &variant.ident;
         let base = match &variant.fields {
             Fields::Unit => {
@@ -225,9 +252,14 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                         .ident
                         .as_ref()
                         .expect("expected field to have a name.");
+                    let field_bit_length = FieldAttributes::from_field(field)?.bit_length(
+                        serde_crate_name,
+                        &field.ty,
+                        quote! { #field_name },
+                    );
                     right = quote! {
                         #right
-                        output += #field_name.bit_length();
+                        output += #field_bit_length;
                     }
                 }
                 quote! {
@@ -246,10 +278,15 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
                 let mut right = quote! {
                     output += <UnsignedInteger::<#bits_needed> as ConstBitLength>::const_bit_length();
                 };
-                for field_name in names {
+                for (field_name, field) in names.iter().zip(fields.unnamed.iter()) {
+                    let field_bit_length = FieldAttributes::from_field(field)?.bit_length(
+                        serde_crate_name,
+                        &field.ty,
+                        quote! { #field_name },
+                    );
                     right = quote! {
                         #right
-                        output += #field_name.bit_length();
+                        output += #field_bit_length;
                     }
                 }
                 quote! {
@@ -262,7 +299,7 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
             #base
         }
     }
-    quote! {
+    Ok(quote! {
          fn bit_length(&self) -> u32 {
             let mut output = 0;
             match self {
@@ -270,5 +307,5 @@ fn get_bit_length_method(enum_: &DataEnum, bits_needed: u8) -> TokenStream {
             }
             output
          }
-    }
+    })
 }
@@ -1,30 +1,37 @@
+use naia_serde_attributes::FieldAttributes;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::DataStruct;
+use syn::{DataStruct, Result};
 
 #[allow(clippy::format_push_string)]
 pub fn derive_serde_struct(
     struct_: &DataStruct,
     struct_name: &Ident,
     serde_crate_name: TokenStream,
-) -> TokenStream {
+) -> Result<TokenStream> {
     let mut ser_body = quote! {};
     let mut de_body = quote! {};
     let mut bit_length_body = quote! {};
 
     for field in &struct_.fields {
         let field_name = field.ident.as_ref().expect("expected field to have a name");
+        let field_type = &field.ty;
+        let attributes = FieldAttributes::from_field(field)?;
+        let field_ser = attributes.ser(&serde_crate_name, field_type, quote! { &self.#field_name });
+        let field_de = attributes.de(&serde_crate_name, field_type);
+        let field_bit_length =
+            attributes.bit_length(&serde_crate_name, field_type, quote! { &self.#field_name });
         ser_body = quote! {
             #ser_body
-            self.#field_name.ser(writer);
+            #field_ser
         };
         de_body = quote! {
             #de_body
-            #field_name: Serde::de(reader)?,
+            #field_name: #field_de,
         };
         bit_length_body = quote! {
             #bit_length_body
-            output += self.#field_name.bit_length();
+            output += #field_bit_length;
         };
     }
 
@@ -37,7 +44,7 @@ pub fn derive_serde_struct(
     let import_types = quote! { Serde, BitWrite, ConstBitLength, BitReader, SerdeErr };
     let imports = quote! { use #serde_crate_name::{#import_types}; };
 
-    quote! {
+    Ok(quote! {
         mod #module_name {
             #imports
             use super::#struct_name;
@@ -57,5 +64,5 @@ pub fn derive_serde_struct(
                 }
             }
         }
-    }
+    })
 }
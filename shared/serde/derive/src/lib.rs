@@ -4,25 +4,25 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields};
 mod impls;
 use impls::*;
 
-#[proc_macro_derive(Serde)]
+#[proc_macro_derive(Serde, attributes(naia))]
 pub fn derive_serde(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_shared };
     derive_serde_common(input, serde_crate_name)
 }
 
-#[proc_macro_derive(SerdeInternal)]
+#[proc_macro_derive(SerdeInternal, attributes(naia))]
 pub fn derive_serde_internal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_serde };
     derive_serde_common(input, serde_crate_name)
 }
 
-#[proc_macro_derive(SerdeBevy)]
+#[proc_macro_derive(SerdeBevy, attributes(naia))]
 pub fn derive_serde_bevy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_bevy_shared };
     derive_serde_common(input, serde_crate_name)
 }
 
-#[proc_macro_derive(SerdeHecs)]
+#[proc_macro_derive(SerdeHecs, attributes(naia))]
 pub fn derive_serde_hecs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let serde_crate_name = quote! { naia_hecs_shared };
     derive_serde_common(input, serde_crate_name)
@@ -35,7 +35,7 @@ fn derive_serde_common(
     let input = parse_macro_input!(input as DeriveInput);
     let input_name = input.ident;
 
-    let gen = match &input.data {
+    let result = match &input.data {
         Data::Enum(enum_) => derive_serde_enum(enum_, &input_name, serde_crate_name),
         Data::Struct(struct_) => match struct_.fields {
             Fields::Unit | Fields::Unnamed(_) => {
@@ -46,5 +46,7 @@ fn derive_serde_common(
         _ => unimplemented!("Only structs and enums are supported"),
     };
 
+    let gen = result.unwrap_or_else(|error| error.to_compile_error());
+
     proc_macro::TokenStream::from(gen)
 }
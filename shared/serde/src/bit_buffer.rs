@@ -0,0 +1,105 @@
+use crate::BitWrite;
+
+// BitBuffer
+/// Records written bits, so that they can be copied into any number of other
+/// writers without serializing again
+#[derive(Clone, Default)]
+pub struct BitBuffer {
+    bytes: Vec<u8>,
+    bit_length: u32,
+}
+
+impl BitBuffer {
+    pub fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            bit_length: 0,
+        }
+    }
+
+    pub fn bit_length(&self) -> u32 {
+        self.bit_length
+    }
+
+    /// Writes the recorded bits into another writer
+    pub fn write_to(&self, writer: &mut dyn BitWrite) {
+        if writer.is_counter() {
+            writer.write_bits(self.bit_length);
+            return;
+        }
+        for index in 0..self.bit_length as usize {
+            let bit = (self.bytes[index / 8] >> (index % 8)) & 1 != 0;
+            writer.write_bit(bit);
+        }
+    }
+}
+
+impl BitWrite for BitBuffer {
+    fn write_bit(&mut self, bit: bool) {
+        let index = self.bit_length as usize;
+        let bit_index = index % 8;
+        if bit_index == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            self.bytes[index / 8] |= 1 << bit_index;
+        }
+        self.bit_length += 1;
+    }
+
+    fn write_byte(&mut self, byte: u8) {
+        let mut temp = byte;
+        for _ in 0..8 {
+            self.write_bit(temp & 1 != 0);
+            temp >>= 1;
+        }
+    }
+
+    fn write_bits(&mut self, _: u32) {
+        panic!("This method should not be called for BitBuffer!");
+    }
+
+    fn is_counter(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BitBuffer, BitReader, BitWrite, BitWriter, Serde, UnsignedVariableInteger};
+
+    #[test]
+    fn copies_recorded_bits() {
+        let mut buffer = BitBuffer::new();
+        true.ser(&mut buffer);
+        UnsignedVariableInteger::<5>::new(1234u64).ser(&mut buffer);
+        "recorded".to_string().ser(&mut buffer);
+
+        // write into an unaligned position
+        let mut writer = BitWriter::new();
+        false.ser(&mut writer);
+        buffer.write_to(&mut writer);
+
+        let bytes = writer.to_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert!(!bool::de(&mut reader).unwrap());
+        assert!(bool::de(&mut reader).unwrap());
+        assert_eq!(
+            UnsignedVariableInteger::<5>::de(&mut reader).unwrap().get(),
+            1234
+        );
+        assert_eq!(String::de(&mut reader).unwrap(), "recorded");
+    }
+
+    #[test]
+    fn counts_recorded_bits() {
+        let mut buffer = BitBuffer::new();
+        7u16.ser(&mut buffer);
+
+        let writer = BitWriter::new();
+        let mut counter = writer.counter();
+        buffer.write_to(&mut counter);
+        assert_eq!(counter.bits_needed(), buffer.bit_length());
+        assert_eq!(buffer.bit_length(), 16);
+    }
+}
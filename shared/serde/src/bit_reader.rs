@@ -26,6 +26,11 @@ impl<'b> BitReader<'b> {
         }
     }
 
+    /// The number of bits read so far
+    pub fn bits_read(&self) -> u32 {
+        (self.state.buffer_index as u32 * 8) - self.state.scratch_index as u32
+    }
+
     pub(crate) fn read_bit(&mut self) -> Result<bool, SerdeErr> {
         if self.state.scratch_index == 0 {
             if self.state.buffer_index == self.buffer.len() {
@@ -0,0 +1,167 @@
+use crate::{
+    bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, integer::SerdeInteger,
+    serde::Serde,
+};
+
+/// An alternative way to serialize a field of type T, used in place of T's own
+/// Serde implementation when a field is annotated with a derive attribute such
+/// as `#[naia(bits = 10)]` or `#[naia(varint)]`.
+pub trait FieldEncoding<T> {
+    /// Serialize the value to a BitWriter
+    fn ser(value: &T, writer: &mut dyn BitWrite);
+
+    /// Parse the value from a BitReader
+    fn de(reader: &mut BitReader) -> Result<T, SerdeErr>;
+
+    /// Return length of the serialized value in bits
+    fn bit_length(value: &T) -> u32;
+}
+
+/// Writes an integer with exactly BITS bits, plus a sign bit for signed
+/// integers. Used by the `#[naia(bits = BITS)]` attribute.
+pub struct BitsEncoding<const BITS: u8>;
+
+/// Writes an integer in chunks of 7 bits, so that small values take up less
+/// space. Used by the `#[naia(varint)]` attribute.
+pub struct VarIntEncoding;
+
+const VARINT_CHUNK_BITS: u8 = 7;
+
+fn ser_integer<const SIGNED: bool, const VARIABLE: bool, const BITS: u8>(
+    value: i128,
+    writer: &mut dyn BitWrite,
+) {
+    SerdeInteger::<SIGNED, VARIABLE, BITS>::new(value).ser(writer);
+}
+
+fn de_integer<T: TryFrom<i128>, const SIGNED: bool, const VARIABLE: bool, const BITS: u8>(
+    reader: &mut BitReader,
+) -> Result<T, SerdeErr> {
+    let value = SerdeInteger::<SIGNED, VARIABLE, BITS>::de(reader)?.get();
+    T::try_from(value).map_err(|_| SerdeErr)
+}
+
+fn integer_bit_length<const SIGNED: bool, const VARIABLE: bool, const BITS: u8>(
+    value: i128,
+) -> u32 {
+    SerdeInteger::<SIGNED, VARIABLE, BITS>::new(value).bit_length()
+}
+
+macro_rules! impl_field_encoding_for {
+    ($signed:expr, $($type:ty),*) => {
+        $(
+            impl<const BITS: u8> FieldEncoding<$type> for BitsEncoding<BITS> {
+                fn ser(value: &$type, writer: &mut dyn BitWrite) {
+                    ser_integer::<$signed, false, BITS>(*value as i128, writer);
+                }
+
+                fn de(reader: &mut BitReader) -> Result<$type, SerdeErr> {
+                    de_integer::<$type, $signed, false, BITS>(reader)
+                }
+
+                fn bit_length(value: &$type) -> u32 {
+                    integer_bit_length::<$signed, false, BITS>(*value as i128)
+                }
+            }
+
+            impl FieldEncoding<$type> for VarIntEncoding {
+                fn ser(value: &$type, writer: &mut dyn BitWrite) {
+                    ser_integer::<$signed, true, VARINT_CHUNK_BITS>(*value as i128, writer);
+                }
+
+                fn de(reader: &mut BitReader) -> Result<$type, SerdeErr> {
+                    de_integer::<$type, $signed, true, VARINT_CHUNK_BITS>(reader)
+                }
+
+                fn bit_length(value: &$type) -> u32 {
+                    integer_bit_length::<$signed, true, VARINT_CHUNK_BITS>(*value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_field_encoding_for!(false, u8, u16, u32, u64, usize);
+impl_field_encoding_for!(true, i8, i16, i32, i64, isize);
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bit_reader::BitReader,
+        bit_writer::BitWriter,
+        field_encoding::{BitsEncoding, FieldEncoding, VarIntEncoding},
+    };
+
+    #[test]
+    fn read_write_bits() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1: u16 = 1000;
+        let in_2: i32 = -300;
+
+        <BitsEncoding<10> as FieldEncoding<u16>>::ser(&in_1, &mut writer);
+        <BitsEncoding<9> as FieldEncoding<i32>>::ser(&in_2, &mut writer);
+
+        assert_eq!(
+            <BitsEncoding<10> as FieldEncoding<u16>>::bit_length(&in_1),
+            10
+        );
+        assert_eq!(
+            <BitsEncoding<9> as FieldEncoding<i32>>::bit_length(&in_2),
+            10
+        );
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = <BitsEncoding<10> as FieldEncoding<u16>>::de(&mut reader).unwrap();
+        let out_2 = <BitsEncoding<9> as FieldEncoding<i32>>::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
+
+    #[test]
+    fn read_write_varint() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1: u64 = 3;
+        let in_2: u64 = 123_456_789;
+        let in_3: i16 = -2;
+
+        <VarIntEncoding as FieldEncoding<u64>>::ser(&in_1, &mut writer);
+        <VarIntEncoding as FieldEncoding<u64>>::ser(&in_2, &mut writer);
+        <VarIntEncoding as FieldEncoding<i16>>::ser(&in_3, &mut writer);
+
+        assert_eq!(<VarIntEncoding as FieldEncoding<u64>>::bit_length(&in_1), 8);
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = <VarIntEncoding as FieldEncoding<u64>>::de(&mut reader).unwrap();
+        let out_2 = <VarIntEncoding as FieldEncoding<u64>>::de(&mut reader).unwrap();
+        let out_3 = <VarIntEncoding as FieldEncoding<i16>>::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+        assert_eq!(in_3, out_3);
+    }
+
+    #[test]
+    fn out_of_range_fails() {
+        let mut writer = BitWriter::new();
+        <VarIntEncoding as FieldEncoding<u32>>::ser(&300, &mut writer);
+        let buffer = writer.to_bytes();
+
+        let mut reader = BitReader::new(&buffer);
+        assert!(<VarIntEncoding as FieldEncoding<u8>>::de(&mut reader).is_err());
+    }
+}
@@ -1,8 +1,18 @@
 mod array;
 mod boxed;
+mod btree;
+mod duration;
 mod hash;
+mod non_zero;
 mod option;
+mod result;
 mod scalars;
+mod shared_pointer;
 mod string;
 mod tuple;
 mod vector;
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "uuid")]
+mod uuid;
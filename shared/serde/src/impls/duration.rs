@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use crate::{
+    bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, serde::Serde, UnsignedInteger,
+    UnsignedVariableInteger,
+};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+// 2^30 is the smallest power of two greater than NANOS_PER_SEC
+type SubsecNanos = UnsignedInteger<30>;
+type Secs = UnsignedVariableInteger<7>;
+
+impl Serde for Duration {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        Secs::new(self.as_secs()).ser(writer);
+        SubsecNanos::new(self.subsec_nanos()).ser(writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let secs = u64::try_from(Secs::de(reader)?.get()).map_err(|_| SerdeErr)?;
+        let nanos = SubsecNanos::de(reader)?.get() as u32;
+        if nanos >= NANOS_PER_SEC {
+            return Err(SerdeErr);
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+
+    fn bit_length(&self) -> u32 {
+        let mut output = 0;
+        output += Secs::new(self.as_secs()).bit_length();
+        output += SubsecNanos::new(self.subsec_nanos()).bit_length();
+        output
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use std::time::Duration;
+
+    #[test]
+    fn read_write() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = Duration::from_millis(1_500);
+        let in_2 = Duration::new(u64::MAX, 999_999_999);
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        //Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Duration::de(&mut reader).unwrap();
+        let out_2 = Duration::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
+}
@@ -0,0 +1,78 @@
+use ::glam::{IVec2, IVec3, IVec4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+
+use crate::{
+    bit_reader::BitReader,
+    bit_writer::BitWrite,
+    error::SerdeErr,
+    serde::{ConstBitLength, Serde},
+};
+
+macro_rules! impl_serde_for_glam {
+    ($impl_type:ident, $component_type:ident, $component_count:expr) => {
+        impl Serde for $impl_type {
+            fn ser(&self, writer: &mut dyn BitWrite) {
+                self.to_array().ser(writer);
+            }
+
+            fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+                let components = <[$component_type; $component_count]>::de(reader)?;
+                Ok($impl_type::from_array(components))
+            }
+
+            fn bit_length(&self) -> u32 {
+                <Self as ConstBitLength>::const_bit_length()
+            }
+        }
+        impl ConstBitLength for $impl_type {
+            fn const_bit_length() -> u32 {
+                <[$component_type; $component_count] as ConstBitLength>::const_bit_length()
+            }
+        }
+    };
+}
+
+impl_serde_for_glam!(Vec2, f32, 2);
+impl_serde_for_glam!(Vec3, f32, 3);
+impl_serde_for_glam!(Vec4, f32, 4);
+impl_serde_for_glam!(IVec2, i32, 2);
+impl_serde_for_glam!(IVec3, i32, 3);
+impl_serde_for_glam!(IVec4, i32, 4);
+impl_serde_for_glam!(UVec2, u32, 2);
+impl_serde_for_glam!(UVec3, u32, 3);
+impl_serde_for_glam!(UVec4, u32, 4);
+impl_serde_for_glam!(Quat, f32, 4);
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use ::glam::{IVec2, Quat, Vec3};
+
+    #[test]
+    fn read_write() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = Vec3::new(1.5, -2.0, 3.25);
+        let in_2 = IVec2::new(-7, 12);
+        let in_3 = Quat::from_rotation_y(0.5);
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+        in_3.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        //Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Vec3::de(&mut reader).unwrap();
+        let out_2 = IVec2::de(&mut reader).unwrap();
+        let out_3 = Quat::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+        assert_eq!(in_3, out_3);
+    }
+}
@@ -0,0 +1,86 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::{
+    bit_reader::BitReader,
+    bit_writer::BitWrite,
+    error::SerdeErr,
+    serde::{ConstBitLength, Serde},
+};
+
+macro_rules! impl_serde_for_non_zero {
+    ($impl_type:ident, $inner_type:ident) => {
+        impl Serde for $impl_type {
+            fn ser(&self, writer: &mut dyn BitWrite) {
+                self.get().ser(writer);
+            }
+
+            fn de(reader: &mut BitReader) -> Result<$impl_type, SerdeErr> {
+                $impl_type::new($inner_type::de(reader)?).ok_or(SerdeErr)
+            }
+
+            fn bit_length(&self) -> u32 {
+                self.get().bit_length()
+            }
+        }
+        impl ConstBitLength for $impl_type {
+            fn const_bit_length() -> u32 {
+                <$inner_type as ConstBitLength>::const_bit_length()
+            }
+        }
+    };
+}
+
+impl_serde_for_non_zero!(NonZeroU8, u8);
+impl_serde_for_non_zero!(NonZeroU16, u16);
+impl_serde_for_non_zero!(NonZeroU32, u32);
+impl_serde_for_non_zero!(NonZeroU64, u64);
+impl_serde_for_non_zero!(NonZeroUsize, usize);
+impl_serde_for_non_zero!(NonZeroI8, i8);
+impl_serde_for_non_zero!(NonZeroI16, i16);
+impl_serde_for_non_zero!(NonZeroI32, i32);
+impl_serde_for_non_zero!(NonZeroI64, i64);
+impl_serde_for_non_zero!(NonZeroIsize, isize);
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use std::num::{NonZeroI32, NonZeroU16, NonZeroU8};
+
+    #[test]
+    fn read_write() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = NonZeroU16::new(4321).unwrap();
+        let in_2 = NonZeroI32::new(-87).unwrap();
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        //Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = NonZeroU16::de(&mut reader).unwrap();
+        let out_2 = NonZeroI32::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
+
+    #[test]
+    fn zero_fails() {
+        let mut writer = BitWriter::new();
+        0_u8.ser(&mut writer);
+        let buffer = writer.to_bytes();
+
+        let mut reader = BitReader::new(&buffer);
+        assert!(NonZeroU8::de(&mut reader).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use std::{rc::Rc, sync::Arc};
+
+use crate::{
+    bit_reader::BitReader,
+    bit_writer::BitWrite,
+    error::SerdeErr,
+    serde::{ConstBitLength, Serde},
+};
+
+impl<T: Serde> Serde for Rc<T> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        (**self).ser(writer)
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Rc<T>, SerdeErr> {
+        Ok(Rc::new(Serde::de(reader)?))
+    }
+
+    fn bit_length(&self) -> u32 {
+        (**self).bit_length()
+    }
+}
+
+impl<T: ConstBitLength> ConstBitLength for Rc<T> {
+    fn const_bit_length() -> u32 {
+        T::const_bit_length()
+    }
+}
+
+impl<T: Serde> Serde for Arc<T> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        (**self).ser(writer)
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Arc<T>, SerdeErr> {
+        Ok(Arc::new(Serde::de(reader)?))
+    }
+
+    fn bit_length(&self) -> u32 {
+        (**self).bit_length()
+    }
+}
+
+impl<T: ConstBitLength> ConstBitLength for Arc<T> {
+    fn const_bit_length() -> u32 {
+        T::const_bit_length()
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use std::{rc::Rc, sync::Arc};
+
+    #[test]
+    fn read_write() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = Rc::new(123_u32);
+        let in_2 = Arc::new("shared".to_string());
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        //Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Rc::<u32>::de(&mut reader).unwrap();
+        let out_2 = Arc::<String>::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
+}
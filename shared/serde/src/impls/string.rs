@@ -1,16 +1,30 @@
+use std::borrow::Cow;
+
 use crate::{
     bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, serde::Serde,
     UnsignedVariableInteger,
 };
 
+fn ser_str(value: &str, writer: &mut dyn BitWrite) {
+    let length = UnsignedVariableInteger::<9>::new(value.len() as u64);
+    length.ser(writer);
+    let bytes = value.as_bytes();
+    for byte in bytes {
+        writer.write_byte(*byte);
+    }
+}
+
+fn str_bit_length(value: &str) -> u32 {
+    let mut output = 0;
+    let length = UnsignedVariableInteger::<9>::new(value.len() as u64);
+    output += length.bit_length();
+    output += (value.len() as u32) * 8;
+    output
+}
+
 impl Serde for String {
     fn ser(&self, writer: &mut dyn BitWrite) {
-        let length = UnsignedVariableInteger::<9>::new(self.len() as u64);
-        length.ser(writer);
-        let bytes = self.as_bytes();
-        for byte in bytes {
-            writer.write_byte(*byte);
-        }
+        ser_str(self, writer);
     }
 
     fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
@@ -26,11 +40,21 @@ impl Serde for String {
     }
 
     fn bit_length(&self) -> u32 {
-        let mut output = 0;
-        let length = UnsignedVariableInteger::<9>::new(self.len() as u64);
-        output += length.bit_length();
-        output += (self.len() as u32) * 8;
-        output
+        str_bit_length(self)
+    }
+}
+
+impl<'a> Serde for Cow<'a, str> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        ser_str(self, writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        Ok(Cow::Owned(String::de(reader)?))
+    }
+
+    fn bit_length(&self) -> u32 {
+        str_bit_length(self)
     }
 }
 
@@ -39,6 +63,7 @@ impl Serde for String {
 #[cfg(test)]
 mod tests {
     use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use std::borrow::Cow;
 
     #[test]
     fn read_write() {
@@ -62,4 +87,29 @@ mod tests {
         assert_eq!(in_1, out_1);
         assert_eq!(in_2, out_2);
     }
+
+    #[test]
+    fn read_write_cow() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1: Cow<str> = Cow::Borrowed("borrowed");
+        let in_2: Cow<str> = Cow::Owned("owned".to_string());
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        assert_eq!(in_1.bit_length(), "borrowed".to_string().bit_length());
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1: Cow<str> = Serde::de(&mut reader).unwrap();
+        let out_2: Cow<str> = Serde::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
 }
@@ -0,0 +1,60 @@
+use ::uuid::Uuid;
+
+use crate::{
+    bit_reader::BitReader,
+    bit_writer::BitWrite,
+    error::SerdeErr,
+    serde::{ConstBitLength, Serde},
+};
+
+impl Serde for Uuid {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        self.as_bytes().ser(writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let bytes = <[u8; 16]>::de(reader)?;
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl ConstBitLength for Uuid {
+    fn const_bit_length() -> u32 {
+        <[u8; 16] as ConstBitLength>::const_bit_length()
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_reader::BitReader, bit_writer::BitWriter, serde::Serde};
+    use ::uuid::Uuid;
+
+    #[test]
+    fn read_write() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let in_2 = Uuid::nil();
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        //Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Uuid::de(&mut reader).unwrap();
+        let out_2 = Uuid::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+    }
+}
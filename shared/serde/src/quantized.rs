@@ -0,0 +1,449 @@
+use crate::{
+    bit_reader::BitReader, bit_writer::BitWrite, error::SerdeErr, serde::Serde, ConstBitLength,
+    UnsignedInteger,
+};
+
+fn check_bits(bits: u8) {
+    if bits == 0 {
+        panic!("can't quantize a value with 0 bits...");
+    }
+    if bits > 32 {
+        panic!("can't quantize a value with more than 32 bits...");
+    }
+}
+
+fn max_step(bits: u8) -> u64 {
+    (1_u64 << bits) - 1
+}
+
+/// Maps a value in [min, max] onto an integer in [0, 2^bits - 1]
+fn quantize(value: f64, min: f64, max: f64, bits: u8) -> u64 {
+    let clamped = value.max(min).min(max);
+    let normalized = (clamped - min) / (max - min);
+    (normalized * max_step(bits) as f64).round() as u64
+}
+
+/// Maps an integer in [0, 2^bits - 1] back onto a value in [min, max]
+fn dequantize(step: u64, min: f64, max: f64, bits: u8) -> f64 {
+    min + (step as f64 / max_step(bits) as f64) * (max - min)
+}
+
+fn ser_step<const BITS: u8>(step: u64, writer: &mut dyn BitWrite) {
+    UnsignedInteger::<BITS>::new(step).ser(writer);
+}
+
+fn de_step<const BITS: u8>(reader: &mut BitReader) -> Result<u64, SerdeErr> {
+    Ok(UnsignedInteger::<BITS>::de(reader)?.get() as u64)
+}
+
+// QuantizedFloat
+
+/// A float within the range [MIN, MAX], written with BITS bits of precision.
+/// Values outside of the range are clamped. The resolution of the encoding is
+/// (MAX - MIN) / (2^BITS - 1).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QuantizedFloat<const MIN: i32, const MAX: i32, const BITS: u8> {
+    step: u64,
+}
+
+impl<const MIN: i32, const MAX: i32, const BITS: u8> QuantizedFloat<MIN, MAX, BITS> {
+    pub fn new(value: f32) -> Self {
+        check_bits(BITS);
+        if MIN >= MAX {
+            panic!("can't quantize a float with a range where MIN >= MAX");
+        }
+
+        Self {
+            step: quantize(value as f64, MIN as f64, MAX as f64, BITS),
+        }
+    }
+
+    pub fn get(&self) -> f32 {
+        dequantize(self.step, MIN as f64, MAX as f64, BITS) as f32
+    }
+}
+
+impl<const MIN: i32, const MAX: i32, const BITS: u8> Serde for QuantizedFloat<MIN, MAX, BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        ser_step::<BITS>(self.step, writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let step = de_step::<BITS>(reader)?;
+        Ok(Self { step })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const MIN: i32, const MAX: i32, const BITS: u8> ConstBitLength
+    for QuantizedFloat<MIN, MAX, BITS>
+{
+    fn const_bit_length() -> u32 {
+        BITS as u32
+    }
+}
+
+// UnitVector
+
+/// A normalized 3D vector, octahedrally encoded into two components of BITS
+/// bits each. A zero-length vector is encoded as pointing along +Z.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct UnitVector<const BITS: u8> {
+    u: u64,
+    v: u64,
+}
+
+impl<const BITS: u8> UnitVector<BITS> {
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        check_bits(BITS);
+
+        let (x, y, z) = (x as f64, y as f64, z as f64);
+        let l1_norm = x.abs() + y.abs() + z.abs();
+        let (mut u, mut v) = if l1_norm > 0.0 {
+            (x / l1_norm, y / l1_norm)
+        } else {
+            (0.0, 0.0)
+        };
+        if z < 0.0 {
+            (u, v) = octahedral_wrap(u, v);
+        }
+
+        Self {
+            u: quantize(u, -1.0, 1.0, BITS),
+            v: quantize(v, -1.0, 1.0, BITS),
+        }
+    }
+
+    pub fn get(&self) -> [f32; 3] {
+        let mut u = dequantize(self.u, -1.0, 1.0, BITS);
+        let mut v = dequantize(self.v, -1.0, 1.0, BITS);
+        let z = 1.0 - u.abs() - v.abs();
+        if z < 0.0 {
+            (u, v) = octahedral_wrap(u, v);
+        }
+
+        let length = (u * u + v * v + z * z).sqrt();
+        [
+            (u / length) as f32,
+            (v / length) as f32,
+            (z / length) as f32,
+        ]
+    }
+}
+
+fn octahedral_wrap(u: f64, v: f64) -> (f64, f64) {
+    let sign = |value: f64| if value >= 0.0 { 1.0 } else { -1.0 };
+    ((1.0 - v.abs()) * sign(u), (1.0 - u.abs()) * sign(v))
+}
+
+impl<const BITS: u8> Serde for UnitVector<BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        ser_step::<BITS>(self.u, writer);
+        ser_step::<BITS>(self.v, writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let u = de_step::<BITS>(reader)?;
+        let v = de_step::<BITS>(reader)?;
+        Ok(Self { u, v })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const BITS: u8> ConstBitLength for UnitVector<BITS> {
+    fn const_bit_length() -> u32 {
+        (BITS as u32) * 2
+    }
+}
+
+// QuantizedQuaternion
+
+const SMALLEST_THREE_INDEX_BITS: u8 = 2;
+const SMALLEST_THREE_LIMIT: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// A rotation quaternion, encoded with the "smallest three" method: the index
+/// of the largest component is written with 2 bits, and the remaining three
+/// components are written with BITS bits each.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct QuantizedQuaternion<const BITS: u8> {
+    largest_index: u8,
+    smallest_three: [u64; 3],
+}
+
+impl<const BITS: u8> QuantizedQuaternion<BITS> {
+    /// Creates a new QuantizedQuaternion from (x, y, z, w) components, which
+    /// will be normalized
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        check_bits(BITS);
+
+        let mut components = [x as f64, y as f64, z as f64, w as f64];
+        let length = components
+            .iter()
+            .map(|component| component * component)
+            .sum::<f64>()
+            .sqrt();
+        if length > 0.0 {
+            for component in components.iter_mut() {
+                *component /= length;
+            }
+        } else {
+            components = [0.0, 0.0, 0.0, 1.0];
+        }
+
+        let mut largest_index = 0;
+        for index in 1..4 {
+            if components[index].abs() > components[largest_index].abs() {
+                largest_index = index;
+            }
+        }
+
+        // q and -q represent the same rotation, so make the largest component positive
+        // in order to be able to reconstruct it from the others
+        if components[largest_index] < 0.0 {
+            for component in components.iter_mut() {
+                *component = -*component;
+            }
+        }
+
+        let mut smallest_three = [0; 3];
+        let mut smallest_index = 0;
+        for (index, component) in components.iter().enumerate() {
+            if index == largest_index {
+                continue;
+            }
+            smallest_three[smallest_index] = quantize(
+                *component,
+                -SMALLEST_THREE_LIMIT,
+                SMALLEST_THREE_LIMIT,
+                BITS,
+            );
+            smallest_index += 1;
+        }
+
+        Self {
+            largest_index: largest_index as u8,
+            smallest_three,
+        }
+    }
+
+    /// Returns the (x, y, z, w) components of the quaternion
+    pub fn get(&self) -> [f32; 4] {
+        let mut components = [0.0_f64; 4];
+        let mut smallest_index = 0;
+        let mut sum_of_squares = 0.0;
+        for (index, component) in components.iter_mut().enumerate() {
+            if index == self.largest_index as usize {
+                continue;
+            }
+            let value = dequantize(
+                self.smallest_three[smallest_index],
+                -SMALLEST_THREE_LIMIT,
+                SMALLEST_THREE_LIMIT,
+                BITS,
+            );
+            *component = value;
+            sum_of_squares += value * value;
+            smallest_index += 1;
+        }
+        components[self.largest_index as usize] = (1.0 - sum_of_squares).max(0.0).sqrt();
+
+        [
+            components[0] as f32,
+            components[1] as f32,
+            components[2] as f32,
+            components[3] as f32,
+        ]
+    }
+}
+
+impl<const BITS: u8> Serde for QuantizedQuaternion<BITS> {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        UnsignedInteger::<SMALLEST_THREE_INDEX_BITS>::new(self.largest_index).ser(writer);
+        for step in &self.smallest_three {
+            ser_step::<BITS>(*step, writer);
+        }
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let largest_index = UnsignedInteger::<SMALLEST_THREE_INDEX_BITS>::de(reader)?.get() as u8;
+        let mut smallest_three = [0; 3];
+        for step in smallest_three.iter_mut() {
+            *step = de_step::<BITS>(reader)?;
+        }
+        Ok(Self {
+            largest_index,
+            smallest_three,
+        })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl<const BITS: u8> ConstBitLength for QuantizedQuaternion<BITS> {
+    fn const_bit_length() -> u32 {
+        (SMALLEST_THREE_INDEX_BITS as u32) + (BITS as u32) * 3
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bit_reader::BitReader,
+        bit_writer::BitWriter,
+        quantized::{QuantizedFloat, QuantizedQuaternion, UnitVector},
+        serde::{ConstBitLength, Serde},
+    };
+
+    fn assert_close(a: f32, b: f32, tolerance: f32) {
+        assert!(
+            (a - b).abs() <= tolerance,
+            "{} is not within {} of {}",
+            a,
+            tolerance,
+            b
+        );
+    }
+
+    #[test]
+    fn float_in_and_out() {
+        let value = QuantizedFloat::<-100, 100, 16>::new(12.345);
+        assert_close(value.get(), 12.345, 200.0 / 65535.0);
+
+        assert_eq!(QuantizedFloat::<-100, 100, 16>::new(-100.0).get(), -100.0);
+        assert_eq!(QuantizedFloat::<-100, 100, 16>::new(100.0).get(), 100.0);
+    }
+
+    #[test]
+    fn float_clamps() {
+        assert_eq!(QuantizedFloat::<0, 10, 8>::new(-5.0).get(), 0.0);
+        assert_eq!(QuantizedFloat::<0, 10, 8>::new(15.0).get(), 10.0);
+    }
+
+    #[test]
+    fn read_write_float() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = QuantizedFloat::<-10, 10, 12>::new(3.3);
+        let in_2 = QuantizedFloat::<0, 1000, 20>::new(999.99);
+        let in_3 = QuantizedFloat::<0, 1, 1>::new(0.7);
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+        in_3.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Serde::de(&mut reader).unwrap();
+        let out_2 = Serde::de(&mut reader).unwrap();
+        let out_3 = Serde::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+        assert_eq!(in_3, out_3);
+        assert_eq!(QuantizedFloat::<-10, 10, 12>::const_bit_length(), 12);
+    }
+
+    #[test]
+    fn unit_vector_in_and_out() {
+        let inputs = [
+            [1.0, 0.0, 0.0],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, -1.0],
+            [0.577, -0.577, -0.577],
+            [-0.2, 0.4, -0.894],
+        ];
+        for input in inputs {
+            let output = UnitVector::<12>::new(input[0], input[1], input[2]).get();
+            let length = (input[0] * input[0] + input[1] * input[1] + input[2] * input[2]).sqrt();
+            for axis in 0..3 {
+                assert_close(output[axis], input[axis] / length, 0.002);
+            }
+        }
+    }
+
+    #[test]
+    fn read_write_unit_vector() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = UnitVector::<10>::new(0.3, -0.4, 0.866);
+        let in_2 = UnitVector::<16>::new(-0.5, 0.5, -0.707);
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Serde::de(&mut reader).unwrap();
+        let out_2 = Serde::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+        assert_eq!(UnitVector::<10>::const_bit_length(), 20);
+    }
+
+    #[test]
+    fn quaternion_in_and_out() {
+        let inputs = [
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, -1.0],
+            [0.5, 0.5, 0.5, 0.5],
+            [0.1, -0.7, 0.2, 0.677],
+            [-0.9, 0.1, 0.3, 0.3],
+        ];
+        for input in inputs {
+            let output =
+                QuantizedQuaternion::<12>::new(input[0], input[1], input[2], input[3]).get();
+            let length = input.iter().map(|c| c * c).sum::<f32>().sqrt();
+
+            // q and -q represent the same rotation
+            let dot: f32 = (0..4).map(|i| output[i] * input[i] / length).sum();
+            let sign = if dot < 0.0 { -1.0 } else { 1.0 };
+            for index in 0..4 {
+                assert_close(output[index] * sign, input[index] / length, 0.002);
+            }
+        }
+    }
+
+    #[test]
+    fn read_write_quaternion() {
+        // Write
+        let mut writer = BitWriter::new();
+
+        let in_1 = QuantizedQuaternion::<9>::new(0.1, -0.7, 0.2, 0.677);
+        let in_2 = QuantizedQuaternion::<15>::new(-0.9, 0.1, 0.3, 0.3);
+
+        in_1.ser(&mut writer);
+        in_2.ser(&mut writer);
+
+        let buffer = writer.to_bytes();
+
+        // Read
+        let mut reader = BitReader::new(&buffer);
+
+        let out_1 = Serde::de(&mut reader).unwrap();
+        let out_2 = Serde::de(&mut reader).unwrap();
+
+        assert_eq!(in_1, out_1);
+        assert_eq!(in_2, out_2);
+        assert_eq!(QuantizedQuaternion::<9>::const_bit_length(), 29);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    assets::asset_messages::{AssetData, AssetOffer, AssetRequest},
+    messages::channels::channel::{ChannelDirection, ChannelMode, ReliableSettings},
+    Channel, Protocol, ProtocolPlugin,
+};
+
+/// Internal Channel used to exchange Asset offers, requests & data
+#[derive(Channel)]
+pub struct AssetChannel;
+
+pub(crate) struct AssetCachePlugin;
+impl ProtocolPlugin for AssetCachePlugin {
+    fn build(&self, protocol: &mut Protocol) {
+        protocol
+            .add_channel::<AssetChannel>(
+                ChannelDirection::Bidirectional,
+                ChannelMode::UnorderedReliable(ReliableSettings::default()),
+            )
+            .add_message::<AssetOffer>()
+            .add_message::<AssetRequest>()
+            .add_message::<AssetData>();
+    }
+}
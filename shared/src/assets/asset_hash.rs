@@ -0,0 +1,130 @@
+use std::fmt;
+
+use ring::digest;
+
+use naia_serde::{BitReader, BitWrite, ConstBitLength, Serde, SerdeErr};
+
+const ASSET_HASH_BYTES: usize = 32;
+
+/// Content hash of an Asset's bytes (SHA-256), used as the Asset's identity
+/// so that a Client can tell whether it already has a given Asset cached
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AssetHash {
+    inner: [u8; ASSET_HASH_BYTES],
+}
+
+impl AssetHash {
+    pub fn new(inner: [u8; ASSET_HASH_BYTES]) -> Self {
+        Self { inner }
+    }
+
+    /// Hashes the given Asset bytes
+    pub fn of(bytes: &[u8]) -> Self {
+        let digest = digest::digest(&digest::SHA256, bytes);
+        let mut inner = [0; ASSET_HASH_BYTES];
+        inner.copy_from_slice(digest.as_ref());
+        Self { inner }
+    }
+
+    pub fn as_bytes(&self) -> &[u8; ASSET_HASH_BYTES] {
+        &self.inner
+    }
+
+    /// Lowercase hexadecimal representation, suitable for use as a file name
+    /// or storage key
+    pub fn to_hex(&self) -> String {
+        let mut output = String::with_capacity(ASSET_HASH_BYTES * 2);
+        for byte in &self.inner {
+            output.push_str(&format!("{:02x}", byte));
+        }
+        output
+    }
+
+    /// Parses a hash from its hexadecimal representation, returning None if
+    /// the input is malformed
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if hex.len() != ASSET_HASH_BYTES * 2 || !hex.is_ascii() {
+            return None;
+        }
+        let mut inner = [0; ASSET_HASH_BYTES];
+        for (index, byte) in inner.iter_mut().enumerate() {
+            let start = index * 2;
+            *byte = u8::from_str_radix(&hex[start..start + 2], 16).ok()?;
+        }
+        Some(Self { inner })
+    }
+}
+
+impl fmt::Display for AssetHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl Serde for AssetHash {
+    fn ser(&self, writer: &mut dyn BitWrite) {
+        self.inner.ser(writer);
+    }
+
+    fn de(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        let inner = <[u8; ASSET_HASH_BYTES]>::de(reader)?;
+        Ok(Self { inner })
+    }
+
+    fn bit_length(&self) -> u32 {
+        <Self as ConstBitLength>::const_bit_length()
+    }
+}
+
+impl ConstBitLength for AssetHash {
+    fn const_bit_length() -> u32 {
+        (ASSET_HASH_BYTES as u32) * 8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naia_serde::{BitReader, BitWriter, Serde};
+
+    use super::AssetHash;
+
+    #[test]
+    fn hex_round_trip() {
+        let mut bytes = [0; 32];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = (index * 7) as u8;
+        }
+        let hash = AssetHash::new(bytes);
+
+        let hex = hash.to_hex();
+        assert_eq!(hex.len(), 64);
+        assert_eq!(AssetHash::from_hex(&hex), Some(hash));
+    }
+
+    #[test]
+    fn of_hashes_with_sha256() {
+        let hash = AssetHash::of(b"abc");
+        assert_eq!(
+            hash.to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn from_hex_rejects_malformed() {
+        assert_eq!(AssetHash::from_hex("abc"), None);
+        assert_eq!(AssetHash::from_hex(&"zz".repeat(32)), None);
+    }
+
+    #[test]
+    fn read_write() {
+        let hash = AssetHash::new([42; 32]);
+
+        let mut writer = BitWriter::new();
+        hash.ser(&mut writer);
+        let buffer = writer.to_bytes();
+
+        let mut reader = BitReader::new(&buffer);
+        assert_eq!(AssetHash::de(&mut reader).unwrap(), hash);
+    }
+}
@@ -0,0 +1,43 @@
+use naia_derive::MessageInternal;
+
+use crate::assets::asset_hash::AssetHash;
+
+/// Sent from the Server to let a Client know that an Asset is available.
+/// The Client will only request the Asset's bytes if it has not cached them
+#[derive(MessageInternal)]
+pub struct AssetOffer {
+    pub hash: AssetHash,
+}
+
+impl AssetOffer {
+    pub fn new(hash: &AssetHash) -> Self {
+        Self { hash: *hash }
+    }
+}
+
+/// Sent from the Client to request the bytes of an offered Asset which are
+/// missing from its cache
+#[derive(MessageInternal)]
+pub struct AssetRequest {
+    pub hash: AssetHash,
+}
+
+impl AssetRequest {
+    pub fn new(hash: &AssetHash) -> Self {
+        Self { hash: *hash }
+    }
+}
+
+/// Sent from the Server in response to an AssetRequest, large payloads are
+/// split up by the Message fragmenter
+#[derive(MessageInternal)]
+pub struct AssetData {
+    pub hash: AssetHash,
+    pub bytes: Vec<u8>,
+}
+
+impl AssetData {
+    pub fn new(hash: &AssetHash, bytes: Vec<u8>) -> Self {
+        Self { hash: *hash, bytes }
+    }
+}
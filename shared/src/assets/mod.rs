@@ -0,0 +1,3 @@
+pub mod asset_channel;
+pub mod asset_hash;
+pub mod asset_messages;
@@ -9,7 +9,10 @@ use crate::{
     types::{HostType, PacketIndex},
     world::{
         entity::entity_converters::{EntityConverterMut, GlobalWorldManagerType},
-        host::{host_world_manager::HostWorldEvents, host_world_writer::HostWorldWriter},
+        host::{
+            host_world_manager::HostWorldEvents, host_world_writer::HostWorldWriter,
+            update_cache::UpdateCache,
+        },
         local_world_manager::LocalWorldManager,
         remote::remote_world_reader::RemoteWorldReader,
     },
@@ -44,12 +47,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         channel_kinds: &ChannelKinds,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
     ) -> Self {
+        // only the Server surfaces acknowledgements of its Entities as events
+        let records_acks = matches!(host_type, HostType::Server);
         BaseConnection {
             heartbeat_timer: Timer::new(connection_config.heartbeat_interval),
             timeout_timer: Timer::new(connection_config.disconnection_timeout_duration),
             ack_manager: AckManager::new(),
             message_manager: MessageManager::new(host_type, channel_kinds),
-            host_world_manager: HostWorldManager::new(address, global_world_manager),
+            host_world_manager: HostWorldManager::new(address, records_acks, global_world_manager),
             remote_world_manager: RemoteWorldManager::new(),
             remote_world_reader: RemoteWorldReader::new(),
             local_world_manager: LocalWorldManager::new(user_key),
@@ -154,6 +159,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
         has_written: &mut bool,
         write_world_events: bool,
         host_world_events: &mut HostWorldEvents<E>,
+        update_cache: &mut UpdateCache<E>,
     ) {
         // write messages
         {
@@ -182,6 +188,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> BaseConnection<E> {
                 has_written,
                 &mut self.host_world_manager,
                 host_world_events,
+                update_cache,
             );
         }
     }
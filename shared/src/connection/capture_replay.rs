@@ -0,0 +1,127 @@
+use std::{collections::VecDeque, time::Duration};
+
+use naia_socket_shared::Instant;
+
+use super::packet_capture::{CapturedPacket, PacketCapture, PacketDirection};
+
+/// Steps through a capture for the replay transports, on a simulated clock.
+/// Replaying takes over the clock of the thread it begins on, which moves on
+/// to the next recorded packet, sent or received, each time the host finishes
+/// a pass of receiving. The host's timers then ring when they rang while
+/// recording, and received packets arrive at the same points, however fast
+/// the host is driven. So drive a replaying host from a single thread.
+pub struct CaptureReplay {
+    packets: VecDeque<CapturedPacket>,
+    /// The time the replay began, which stands for the beginning of the
+    /// capture
+    start: std::time::Instant,
+    /// How far into the capture the replay is
+    position: Duration,
+}
+
+impl CaptureReplay {
+    /// Begins a replay, simulating the calling thread's clock until the replay
+    /// is dropped
+    pub fn new(capture: &PacketCapture) -> Self {
+        let start = Instant::now().inner();
+        Instant::simulate_now(Some(start));
+
+        Self {
+            packets: capture.packets().iter().cloned().collect(),
+            start,
+            position: Duration::ZERO,
+        }
+    }
+
+    /// Returns the next received packet which is due. Once none are, the pass
+    /// ends, and the clock moves on to the next recorded packet
+    pub fn receive(&mut self) -> Option<CapturedPacket> {
+        while let Some(packet) = self.packets.front() {
+            if packet.elapsed > self.position {
+                break;
+            }
+            let packet = self.packets.pop_front().unwrap();
+            // packets the host sent only mark the time
+            if packet.direction == PacketDirection::Incoming {
+                return Some(packet);
+            }
+        }
+
+        self.advance();
+        None
+    }
+
+    /// Whether every packet of the capture has been replayed
+    pub fn is_finished(&self) -> bool {
+        self.packets.is_empty()
+    }
+
+    fn advance(&mut self) {
+        let Some(next) = self.packets.front() else {
+            return;
+        };
+        self.position = self.position.max(next.elapsed);
+        Instant::simulate_now(Some(self.start + self.position));
+    }
+}
+
+impl Drop for CaptureReplay {
+    fn drop(&mut self) {
+        Instant::simulate_now(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, time::Duration};
+
+    use naia_socket_shared::Instant;
+
+    use super::CaptureReplay;
+    use crate::{CapturedPacket, PacketCapture, PacketDirection};
+
+    #[test]
+    fn steps_through_the_capture() {
+        let address: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let packet = |direction, millis, payload: u8| CapturedPacket {
+            direction,
+            elapsed: Duration::from_millis(millis),
+            address,
+            payload: Box::new([payload]),
+        };
+        let capture = PacketCapture::new(
+            Vec::new(),
+            vec![
+                packet(PacketDirection::Incoming, 500, 1),
+                packet(PacketDirection::Incoming, 500, 2),
+                packet(PacketDirection::Outgoing, 510, 3),
+                packet(PacketDirection::Incoming, 60_000, 4),
+            ],
+        );
+
+        let mut replay = CaptureReplay::new(&capture);
+        let start = Instant::now();
+        let mut pass = || {
+            let mut received = Vec::new();
+            while let Some(packet) = replay.receive() {
+                received.push(packet.payload[0]);
+            }
+            received
+        };
+
+        // each pass moves the clock to the next recorded packet, however
+        // little time really passes
+        assert_eq!(pass(), []);
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+        assert_eq!(pass(), [1, 2]);
+        assert_eq!(start.elapsed(), Duration::from_millis(510));
+        assert_eq!(pass(), []);
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+        assert_eq!(pass(), [4]);
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+        assert!(replay.is_finished());
+
+        drop(replay);
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+}
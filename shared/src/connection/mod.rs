@@ -1,10 +1,14 @@
 pub mod ack_manager;
 pub mod bandwidth_monitor;
 pub mod base_connection;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod capture_replay;
 pub mod compression_config;
 pub mod connection_config;
 pub mod decoder;
 pub mod encoder;
+pub mod packet_capture;
+pub mod packet_inspector;
 pub mod packet_notifiable;
 pub mod packet_type;
 pub mod ping_store;
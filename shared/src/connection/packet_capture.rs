@@ -0,0 +1,330 @@
+use std::{
+    io::{Error as IoError, ErrorKind, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+use naia_socket_shared::Instant;
+
+/// Begins every capture, and changes whenever the format does
+const CAPTURE_MAGIC: &[u8; 8] = b"NAIACAP1";
+
+/// The largest handshake secret a capture holds
+const MAX_HANDSHAKE_SIZE: usize = 256;
+
+/// The largest packet any transport carries, a UDP datagram or a WebSocket
+/// message, so a corrupt length can't make `read` allocate gigabytes
+const MAX_PAYLOAD_SIZE: usize = 64 * 1024;
+
+const ADDRESS_V4: u8 = 4;
+const ADDRESS_V6: u8 = 6;
+
+/// Whether a captured packet was received or sent
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PacketDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// A packet as it crossed the wire, compressed if compression was enabled
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapturedPacket {
+    pub direction: PacketDirection,
+    /// The time since the capture began
+    pub elapsed: Duration,
+    /// Who the packet came from, or was sent to
+    pub address: SocketAddr,
+    pub payload: Box<[u8]>,
+}
+
+// Capture Writer
+
+/// Records packets, with their timestamps & addresses, to a capture which
+/// `PacketCapture::read` can load again. The writer isn't flushed after
+/// each packet, so wrap files in a `BufWriter`.
+pub struct CaptureWriter {
+    writer: Box<dyn Write + Send + Sync>,
+    start: Instant,
+}
+
+impl CaptureWriter {
+    /// Begins a capture, writing its header. The handshake secret is what the
+    /// host signs or checks its handshakes with, which a replay restores so
+    /// that the captured handshakes still succeed
+    pub fn new<W: Write + Send + Sync + 'static>(
+        writer: W,
+        handshake_secret: &[u8],
+    ) -> Result<Self, IoError> {
+        if handshake_secret.len() > MAX_HANDSHAKE_SIZE {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "handshake secret too large for a naia capture",
+            ));
+        }
+        let mut writer: Box<dyn Write + Send + Sync> = Box::new(writer);
+        writer.write_all(CAPTURE_MAGIC)?;
+        writer.write_all(&(handshake_secret.len() as u16).to_le_bytes())?;
+        writer.write_all(handshake_secret)?;
+        Ok(Self {
+            writer,
+            start: Instant::now(),
+        })
+    }
+
+    pub fn record(
+        &mut self,
+        direction: PacketDirection,
+        address: &SocketAddr,
+        payload: &[u8],
+    ) -> Result<(), IoError> {
+        if payload.len() > MAX_PAYLOAD_SIZE {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "packet too large for a naia capture",
+            ));
+        }
+        let mut record = Vec::with_capacity(payload.len() + 32);
+        record.push(match direction {
+            PacketDirection::Incoming => 0,
+            PacketDirection::Outgoing => 1,
+        });
+        let elapsed_micros = self.start.elapsed().as_micros() as u64;
+        record.extend_from_slice(&elapsed_micros.to_le_bytes());
+        match address.ip() {
+            IpAddr::V4(ip) => {
+                record.push(ADDRESS_V4);
+                record.extend_from_slice(&ip.octets());
+            }
+            IpAddr::V6(ip) => {
+                record.push(ADDRESS_V6);
+                record.extend_from_slice(&ip.octets());
+            }
+        }
+        record.extend_from_slice(&address.port().to_le_bytes());
+        record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        record.extend_from_slice(payload);
+
+        self.writer.write_all(&record)
+    }
+
+    pub fn flush(&mut self) -> Result<(), IoError> {
+        self.writer.flush()
+    }
+}
+
+impl Drop for CaptureWriter {
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+// Packet Capture
+
+/// The packets of a capture, in the order they were recorded
+#[derive(Clone, Debug, Default)]
+pub struct PacketCapture {
+    handshake_secret: Vec<u8>,
+    packets: Vec<CapturedPacket>,
+}
+
+impl PacketCapture {
+    pub fn new(handshake_secret: Vec<u8>, packets: Vec<CapturedPacket>) -> Self {
+        Self {
+            handshake_secret,
+            packets,
+        }
+    }
+
+    /// Loads a capture recorded by a `CaptureWriter`. A capture which ends
+    /// part way through a packet, because its writer was never dropped,
+    /// loads every packet before that one
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, IoError> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != CAPTURE_MAGIC {
+            return Err(IoError::new(ErrorKind::InvalidData, "not a naia capture"));
+        }
+
+        let mut secret_length = [0; 2];
+        reader.read_exact(&mut secret_length)?;
+        let secret_length = u16::from_le_bytes(secret_length) as usize;
+        if secret_length > MAX_HANDSHAKE_SIZE {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "malformed handshake secret in naia capture",
+            ));
+        }
+        let mut handshake_secret = vec![0; secret_length];
+        reader.read_exact(&mut handshake_secret)?;
+
+        let mut packets = Vec::new();
+        loop {
+            match read_packet(&mut reader) {
+                Ok(Some(packet)) => packets.push(packet),
+                Ok(None) => break,
+                Err(error) if error.kind() == ErrorKind::UnexpectedEof => break,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(Self {
+            handshake_secret,
+            packets,
+        })
+    }
+
+    /// The secret the recording host's handshakes were signed or checked with
+    pub fn handshake_secret(&self) -> &[u8] {
+        &self.handshake_secret
+    }
+
+    pub fn packets(&self) -> &[CapturedPacket] {
+        &self.packets
+    }
+
+    /// The packets which were received, as a replay transport delivers them
+    pub fn incoming(&self) -> impl Iterator<Item = &CapturedPacket> {
+        self.packets
+            .iter()
+            .filter(|packet| packet.direction == PacketDirection::Incoming)
+    }
+
+    /// The packets which were sent
+    pub fn outgoing(&self) -> impl Iterator<Item = &CapturedPacket> {
+        self.packets
+            .iter()
+            .filter(|packet| packet.direction == PacketDirection::Outgoing)
+    }
+}
+
+/// Reads the next packet, or None at the end of the capture
+fn read_packet<R: Read>(reader: &mut R) -> Result<Option<CapturedPacket>, IoError> {
+    let mut direction = [0; 1];
+    if reader.read(&mut direction)? == 0 {
+        return Ok(None);
+    }
+    let direction = match direction[0] {
+        0 => PacketDirection::Incoming,
+        1 => PacketDirection::Outgoing,
+        _ => return Err(invalid_packet()),
+    };
+
+    let mut elapsed_micros = [0; 8];
+    reader.read_exact(&mut elapsed_micros)?;
+    let elapsed = Duration::from_micros(u64::from_le_bytes(elapsed_micros));
+
+    let mut family = [0; 1];
+    reader.read_exact(&mut family)?;
+    let ip: IpAddr = match family[0] {
+        ADDRESS_V4 => {
+            let mut octets = [0; 4];
+            reader.read_exact(&mut octets)?;
+            Ipv4Addr::from(octets).into()
+        }
+        ADDRESS_V6 => {
+            let mut octets = [0; 16];
+            reader.read_exact(&mut octets)?;
+            Ipv6Addr::from(octets).into()
+        }
+        _ => return Err(invalid_packet()),
+    };
+    let mut port = [0; 2];
+    reader.read_exact(&mut port)?;
+    let address = SocketAddr::new(ip, u16::from_le_bytes(port));
+
+    let mut length = [0; 4];
+    reader.read_exact(&mut length)?;
+    let length = u32::from_le_bytes(length) as usize;
+    if length > MAX_PAYLOAD_SIZE {
+        return Err(invalid_packet());
+    }
+    let mut payload = vec![0; length];
+    reader.read_exact(&mut payload)?;
+
+    Ok(Some(CapturedPacket {
+        direction,
+        elapsed,
+        address,
+        payload: payload.into_boxed_slice(),
+    }))
+}
+
+fn invalid_packet() -> IoError {
+    IoError::new(ErrorKind::InvalidData, "malformed packet in naia capture")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Error as IoError, ErrorKind, Write},
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    use super::{CaptureWriter, PacketCapture, PacketDirection};
+
+    /// Shares what is written, so the test can read it back
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, bytes: &[u8]) -> Result<usize, IoError> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> Result<(), IoError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn reads_what_was_written() {
+        let buffer = SharedBuffer::default();
+        let ipv4: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let ipv6: SocketAddr = "[::1]:2000".parse().unwrap();
+
+        let mut writer = CaptureWriter::new(buffer.clone(), &[7; 32]).unwrap();
+        writer
+            .record(PacketDirection::Incoming, &ipv4, &[1, 2, 3])
+            .unwrap();
+        writer
+            .record(PacketDirection::Outgoing, &ipv6, &[4, 5])
+            .unwrap();
+        drop(writer);
+
+        let mut bytes = buffer.0.lock().unwrap().clone();
+        let capture = PacketCapture::read(bytes.as_slice()).unwrap();
+        assert_eq!(capture.handshake_secret(), &[7; 32]);
+        let packets = capture.packets();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].direction, PacketDirection::Incoming);
+        assert_eq!(packets[0].address, ipv4);
+        assert_eq!(&*packets[0].payload, &[1, 2, 3]);
+        assert_eq!(packets[1].direction, PacketDirection::Outgoing);
+        assert_eq!(packets[1].address, ipv6);
+        assert_eq!(&*packets[1].payload, &[4, 5]);
+        assert!(packets[0].elapsed <= packets[1].elapsed);
+
+        // a capture cut off part way through a packet keeps the ones before
+        bytes.truncate(bytes.len() - 1);
+        let capture = PacketCapture::read(bytes.as_slice()).unwrap();
+        assert_eq!(capture.packets().len(), 1);
+
+        assert!(PacketCapture::read(&b"NOTACAPTURE"[..]).is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_packets() {
+        let mut bytes = b"NAIACAP1".to_vec();
+        bytes.extend_from_slice(&0_u16.to_le_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&0_u64.to_le_bytes());
+        bytes.push(4);
+        bytes.extend_from_slice(&[127, 0, 0, 1]);
+        bytes.extend_from_slice(&1000_u16.to_le_bytes());
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        let error = PacketCapture::read(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
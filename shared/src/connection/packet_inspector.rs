@@ -0,0 +1,458 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use naia_serde::{BitReader, Serde, SerdeErr, UnsignedVariableInteger};
+
+use crate::{
+    connection::{
+        ack_manager::REDUNDANT_PACKET_ACKS_SIZE, packet_type::PacketType,
+        standard_header::StandardHeader,
+    },
+    messages::channels::{
+        channel::ChannelMode, channel_kinds::ChannelKind,
+        receivers::indexed_message_reader::IndexedMessageReader,
+    },
+    types::{HostType, MessageIndex, PacketIndex, ShortMessageIndex, Tick},
+    world::{
+        component::component_kinds::ComponentKind,
+        entity::{
+            entity_action_type::EntityActionType, entity_converters::FakeEntityConverter,
+            local_entity::LocalEntity,
+        },
+    },
+    GameInstant, Protocol,
+};
+
+/// Decodes naia packets for debugging, naming the Channels, Messages &
+/// Components they carry. Packets must be decompressed first, if the
+/// Protocol compresses them
+pub struct PacketInspector<'p> {
+    protocol: &'p Protocol,
+}
+
+impl<'p> PacketInspector<'p> {
+    pub fn new(protocol: &'p Protocol) -> Self {
+        Self { protocol }
+    }
+
+    /// Decodes a packet sent by the given host. Only a packet too short to
+    /// hold a header is an error, packets which can't be read in full are
+    /// decoded up to the part which can't be read
+    pub fn inspect(&self, sender: HostType, payload: &[u8]) -> Result<InspectedPacket, SerdeErr> {
+        let mut reader = BitReader::new(payload);
+        let header = StandardHeader::de(&mut reader)?;
+
+        let mut packet = InspectedPacket {
+            header,
+            acks: Self::acks(&header),
+            tick: None,
+            tick_instant: None,
+            tick_buffered_messages: Vec::new(),
+            messages: Vec::new(),
+            updates: Vec::new(),
+            actions: Vec::new(),
+            bits_read: reader.bits_read(),
+            bit_length: payload.len() as u32 * 8,
+            malformed: false,
+        };
+        if self.read_body(sender, &mut reader, &mut packet).is_err() {
+            packet.malformed = true;
+        }
+
+        Ok(packet)
+    }
+
+    fn acks(header: &StandardHeader) -> Vec<PacketIndex> {
+        let mut acks = vec![header.sender_ack_index];
+        for i in 1..=REDUNDANT_PACKET_ACKS_SIZE {
+            if header.sender_ack_bitfield & (1 << (i - 1)) != 0 {
+                acks.push(header.sender_ack_index.wrapping_sub(i));
+            }
+        }
+        acks
+    }
+
+    fn read_body(
+        &self,
+        sender: HostType,
+        reader: &mut BitReader,
+        packet: &mut InspectedPacket,
+    ) -> Result<(), SerdeErr> {
+        let packet_type = packet.header.packet_type;
+        let reads_world = match sender {
+            HostType::Server => {
+                match packet_type {
+                    PacketType::Data
+                    | PacketType::Heartbeat
+                    | PacketType::Ping
+                    | PacketType::Pong => {
+                        packet.tick = Some(Tick::de(reader)?);
+                        packet.tick_instant = Some(GameInstant::de(reader)?);
+                        packet.bits_read = reader.bits_read();
+                    }
+                    _ => {}
+                }
+                true
+            }
+            HostType::Client => {
+                if packet_type == PacketType::Data {
+                    packet.tick = Some(Tick::de(reader)?);
+                    packet.bits_read = reader.bits_read();
+
+                    self.read_tick_buffered_messages(reader, packet)?;
+                }
+                self.protocol.client_authoritative_entities
+            }
+        };
+
+        // the bodies of other packets aren't decoded
+        if packet_type != PacketType::Data {
+            return Ok(());
+        }
+
+        self.read_messages(reader, packet)?;
+
+        if reads_world {
+            self.read_updates(reader, packet)?;
+            self.read_actions(reader, packet)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_tick_buffered_messages(
+        &self,
+        reader: &mut BitReader,
+        packet: &mut InspectedPacket,
+    ) -> Result<(), SerdeErr> {
+        let client_tick = packet.tick.unwrap_or_default();
+
+        loop {
+            let channel_continue = bool::de(reader)?;
+            if !channel_continue {
+                break;
+            }
+
+            let channel_kind = ChannelKind::de(&self.protocol.channel_kinds, reader)?;
+            let channel = self.protocol.channel_kinds.kind_to_name(&channel_kind);
+
+            let mut last_read_tick = client_tick;
+            loop {
+                let message_continue = bool::de(reader)?;
+                if !message_continue {
+                    break;
+                }
+
+                let tick_diff = UnsignedVariableInteger::<3>::de(reader)?.get() as Tick;
+                last_read_tick = last_read_tick.wrapping_sub(tick_diff);
+
+                let message_count = UnsignedVariableInteger::<3>::de(reader)?.get();
+                let mut last_read_index: ShortMessageIndex = 0;
+                for _ in 0..message_count {
+                    let id_diff =
+                        UnsignedVariableInteger::<2>::de(reader)?.get() as ShortMessageIndex;
+                    last_read_index = last_read_index.wrapping_add(id_diff);
+
+                    let message = self
+                        .protocol
+                        .message_kinds
+                        .read(reader, &FakeEntityConverter)?;
+                    packet.tick_buffered_messages.push(InspectedMessage {
+                        channel: channel.clone(),
+                        index: Some(MessageIndex::from(last_read_index)),
+                        tick: Some(last_read_tick),
+                        name: message.name(),
+                    });
+                    packet.bits_read = reader.bits_read();
+                }
+            }
+        }
+        packet.bits_read = reader.bits_read();
+
+        Ok(())
+    }
+
+    fn read_messages(
+        &self,
+        reader: &mut BitReader,
+        packet: &mut InspectedPacket,
+    ) -> Result<(), SerdeErr> {
+        loop {
+            let message_continue = bool::de(reader)?;
+            if !message_continue {
+                break;
+            }
+
+            let channel_kind = ChannelKind::de(&self.protocol.channel_kinds, reader)?;
+            let channel = self.protocol.channel_kinds.kind_to_name(&channel_kind);
+            let indexed = match self.protocol.channel_kinds.channel(&channel_kind).mode {
+                ChannelMode::UnorderedUnreliable => false,
+                ChannelMode::SequencedUnreliable
+                | ChannelMode::UnorderedReliable(_)
+                | ChannelMode::SequencedReliable(_)
+                | ChannelMode::OrderedReliable(_) => true,
+                // tick-buffered Messages are never written here
+                ChannelMode::TickBuffered(_) => return Err(SerdeErr),
+            };
+
+            let mut last_read_index: Option<MessageIndex> = None;
+            loop {
+                let channel_continue = bool::de(reader)?;
+                if !channel_continue {
+                    break;
+                }
+
+                let index = if indexed {
+                    let index = IndexedMessageReader::read_message_index(reader, &last_read_index)?;
+                    last_read_index = Some(index);
+                    Some(index)
+                } else {
+                    None
+                };
+
+                let message = self
+                    .protocol
+                    .message_kinds
+                    .read(reader, &FakeEntityConverter)?;
+                packet.messages.push(InspectedMessage {
+                    channel: channel.clone(),
+                    index,
+                    tick: None,
+                    name: message.name(),
+                });
+                packet.bits_read = reader.bits_read();
+            }
+        }
+        packet.bits_read = reader.bits_read();
+
+        Ok(())
+    }
+
+    fn read_updates(
+        &self,
+        reader: &mut BitReader,
+        packet: &mut InspectedPacket,
+    ) -> Result<(), SerdeErr> {
+        let component_kinds = &self.protocol.component_kinds;
+
+        loop {
+            let update_continue = bool::de(reader)?;
+            if !update_continue {
+                break;
+            }
+
+            let entity = LocalEntity::remote_de(reader)?;
+
+            loop {
+                let component_continue = bool::de(reader)?;
+                if !component_continue {
+                    break;
+                }
+
+                let update = component_kinds.read_create_update(reader)?;
+                packet.updates.push(InspectedUpdate {
+                    entity,
+                    component: component_kinds.kind_to_name(&update.kind),
+                    fields: component_kinds.update_field_names(&update)?,
+                });
+                packet.bits_read = reader.bits_read();
+            }
+        }
+        packet.bits_read = reader.bits_read();
+
+        Ok(())
+    }
+
+    fn read_actions(
+        &self,
+        reader: &mut BitReader,
+        packet: &mut InspectedPacket,
+    ) -> Result<(), SerdeErr> {
+        let component_kinds = &self.protocol.component_kinds;
+        let mut last_read_index: Option<MessageIndex> = None;
+
+        loop {
+            let action_continue = bool::de(reader)?;
+            if !action_continue {
+                break;
+            }
+
+            let index = IndexedMessageReader::read_message_index(reader, &last_read_index)?;
+            last_read_index = Some(index);
+
+            let action = match EntityActionType::de(reader)? {
+                EntityActionType::SpawnEntity => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    let components_num = UnsignedVariableInteger::<3>::de(reader)?.get();
+                    let mut components = Vec::new();
+                    for _ in 0..components_num {
+                        let component = component_kinds.read(reader, &FakeEntityConverter)?;
+                        components.push(component.name());
+                    }
+                    InspectedAction::SpawnEntity(index, entity, components)
+                }
+                EntityActionType::DespawnEntity => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    InspectedAction::DespawnEntity(index, entity)
+                }
+                EntityActionType::InsertComponent => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    let component = component_kinds.read(reader, &FakeEntityConverter)?;
+                    InspectedAction::InsertComponent(index, entity, component.name())
+                }
+                EntityActionType::RemoveComponent => {
+                    let entity = LocalEntity::remote_de(reader)?;
+                    let component_kind = ComponentKind::de(component_kinds, reader)?;
+                    InspectedAction::RemoveComponent(
+                        index,
+                        entity,
+                        component_kinds.kind_to_name(&component_kind),
+                    )
+                }
+                EntityActionType::Noop => InspectedAction::Noop(index),
+            };
+            packet.actions.push(action);
+            packet.bits_read = reader.bits_read();
+        }
+        packet.bits_read = reader.bits_read();
+
+        Ok(())
+    }
+}
+
+/// A decoded packet. Decoding stops at the first part of a packet which can't
+/// be read, keeping every part before it
+pub struct InspectedPacket {
+    pub header: StandardHeader,
+    /// The indices of the packets the header acknowledges
+    pub acks: Vec<PacketIndex>,
+    /// The sending host's tick, on Data packets & on every connected packet
+    /// sent by the Server
+    pub tick: Option<Tick>,
+    /// When the Server's tick began, on connected packets sent by the Server
+    pub tick_instant: Option<GameInstant>,
+    pub tick_buffered_messages: Vec<InspectedMessage>,
+    pub messages: Vec<InspectedMessage>,
+    pub updates: Vec<InspectedUpdate>,
+    pub actions: Vec<InspectedAction>,
+    /// The bits decoded, which is where the part that couldn't be read
+    /// begins if the packet is malformed
+    pub bits_read: u32,
+    pub bit_length: u32,
+    pub malformed: bool,
+}
+
+pub struct InspectedMessage {
+    pub channel: String,
+    /// The index of a Message on an ordered, sequenced or reliable Channel,
+    /// or within its tick on a tick-buffered Channel
+    pub index: Option<MessageIndex>,
+    /// The tick a tick-buffered Message is for
+    pub tick: Option<Tick>,
+    pub name: String,
+}
+
+pub struct InspectedUpdate {
+    pub entity: LocalEntity,
+    pub component: String,
+    /// The names of the updated fields
+    pub fields: Vec<String>,
+}
+
+/// An Entity action, with its index & the names of its Components
+pub enum InspectedAction {
+    SpawnEntity(MessageIndex, LocalEntity, Vec<String>),
+    DespawnEntity(MessageIndex, LocalEntity),
+    InsertComponent(MessageIndex, LocalEntity, String),
+    RemoveComponent(MessageIndex, LocalEntity, String),
+    Noop(MessageIndex),
+}
+
+impl Display for InspectedPacket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let header = &self.header;
+        writeln!(
+            f,
+            "{:?} #{}, acks {:?}",
+            header.packet_type, header.sender_packet_index, self.acks
+        )?;
+        if let Some(tick) = self.tick {
+            write!(f, "  tick {}", tick)?;
+            if let Some(tick_instant) = &self.tick_instant {
+                write!(f, " at {}ms", tick_instant.as_millis())?;
+            }
+            writeln!(f)?;
+        }
+        for message in &self.tick_buffered_messages {
+            writeln!(f, "  tick-buffered {}", message)?;
+        }
+        for message in &self.messages {
+            writeln!(f, "  message {}", message)?;
+        }
+        for update in &self.updates {
+            writeln!(
+                f,
+                "  update entity {}: {} {{ {} }}",
+                update.entity.value(),
+                update.component,
+                update.fields.join(", ")
+            )?;
+        }
+        for action in &self.actions {
+            writeln!(f, "  action {}", action)?;
+        }
+        if self.malformed {
+            write!(
+                f,
+                "  malformed at bit {} of {}",
+                self.bits_read, self.bit_length
+            )
+        } else {
+            write!(f, "  {} of {} bits read", self.bits_read, self.bit_length)
+        }
+    }
+}
+
+impl Display for InspectedMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.channel)?;
+        if let Some(tick) = self.tick {
+            write!(f, " tick {}", tick)?;
+        }
+        if let Some(index) = self.index {
+            write!(f, " #{}", index)?;
+        }
+        write!(f, ": {}", self.name)
+    }
+}
+
+impl Display for InspectedAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            InspectedAction::SpawnEntity(index, entity, components) => write!(
+                f,
+                "#{} spawn entity {} [{}]",
+                index,
+                entity.value(),
+                components.join(", ")
+            ),
+            InspectedAction::DespawnEntity(index, entity) => {
+                write!(f, "#{} despawn entity {}", index, entity.value())
+            }
+            InspectedAction::InsertComponent(index, entity, component) => write!(
+                f,
+                "#{} insert {} on entity {}",
+                index,
+                component,
+                entity.value()
+            ),
+            InspectedAction::RemoveComponent(index, entity, component) => write!(
+                f,
+                "#{} remove {} from entity {}",
+                index,
+                component,
+                entity.value()
+            ),
+            InspectedAction::Noop(index) => write!(f, "#{} noop", index),
+        }
+    }
+}
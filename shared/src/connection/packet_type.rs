@@ -81,7 +81,8 @@ impl Serde for PacketType {
             8 => Ok(PacketType::Ping),
             9 => Ok(PacketType::Pong),
             10 => Ok(PacketType::Disconnect),
-            _ => panic!("shouldn't happen, caught above"),
+            // a malformed packet
+            _ => Err(SerdeErr),
         }
     }
 
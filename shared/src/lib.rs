@@ -20,17 +20,23 @@ cfg_if! {
 }
 
 pub use naia_derive::{
-    Channel, Message, MessageBevy, MessageHecs, Replicate, ReplicateBevy, ReplicateHecs,
+    Channel, Message, MessageBevy, MessageHecs, NestedReplicate, NestedReplicateBevy,
+    NestedReplicateHecs, Replicate, ReplicateBevy, ReplicateHecs,
 };
 pub use naia_serde::{
-    BitReader, BitWrite, BitWriter, ConstBitLength, OutgoingPacket, OwnedBitReader, Serde,
-    SerdeBevy, SerdeErr, SerdeHecs, SerdeInternal, UnsignedInteger, UnsignedVariableInteger,
-    MTU_SIZE_BITS, MTU_SIZE_BYTES,
+    BitBuffer, BitReader, BitWrite, BitWriter, BitsEncoding, ConstBitLength, FieldEncoding,
+    OutgoingPacket, OwnedBitReader, QuantizedFloat, QuantizedQuaternion, Serde, SerdeBevy,
+    SerdeErr, SerdeHecs, SerdeInternal, UnitVector, UnsignedInteger, UnsignedVariableInteger,
+    VarIntEncoding, MTU_SIZE_BITS, MTU_SIZE_BYTES,
 };
 pub use naia_socket_shared::{
-    link_condition_logic, Instant, LinkConditionerConfig, Random, SocketConfig, TimeQueue,
+    link_condition_logic, BurstLoss, Instant, LinkConditionerConfig, LinkConditionerSchedule,
+    Random, SocketConfig, TimeQueue,
 };
+#[cfg(all(feature = "websocket", not(target_arch = "wasm32")))]
+pub use naia_socket_shared::websocket;
 
+mod assets;
 mod backends;
 mod bigmap;
 mod connection;
@@ -44,7 +50,14 @@ mod types;
 mod world;
 mod wrapping_number;
 
+pub use assets::{
+    asset_channel::AssetChannel,
+    asset_hash::AssetHash,
+    asset_messages::{AssetData, AssetOffer, AssetRequest},
+};
 pub use backends::{Timer, Timestamp};
+#[cfg(not(target_arch = "wasm32"))]
+pub use connection::capture_replay::CaptureReplay;
 pub use connection::{
     ack_manager::AckManager,
     bandwidth_monitor::BandwidthMonitor,
@@ -53,6 +66,10 @@ pub use connection::{
     connection_config::ConnectionConfig,
     decoder::Decoder,
     encoder::Encoder,
+    packet_capture::{CaptureWriter, CapturedPacket, PacketCapture, PacketDirection},
+    packet_inspector::{
+        InspectedAction, InspectedMessage, InspectedPacket, InspectedUpdate, PacketInspector,
+    },
     packet_notifiable::PacketNotifiable,
     packet_type::PacketType,
     ping_store::{PingIndex, PingStore},
@@ -76,11 +93,22 @@ pub use messages::{
     named::Named,
 };
 pub use world::{
+    authority::{
+        authority_channel::EntityAuthorityChannel,
+        authority_messages::{
+            EntityAuthorityAction, EntityAuthorityMessage, EntityAuthorityUpdate,
+        },
+    },
     component::{
         component_kinds::{ComponentKind, ComponentKinds},
         component_update::{ComponentFieldUpdate, ComponentUpdate},
         diff_mask::DiffMask,
         entity_property::EntityProperty,
+        nested_property::NestedProperty,
+        nested_replicate::{
+            NestedReplicate, NestedReplicate as NestedReplicateBevy,
+            NestedReplicate as NestedReplicateHecs,
+        },
         property::Property,
         property_mutate::{PropertyMutate, PropertyMutator},
         replica_ref::{
@@ -98,24 +126,35 @@ pub use world::{
         entity_action_type::EntityActionType,
         entity_converters::{
             EntityAndGlobalEntityConverter, EntityConverter, EntityConverterMut,
-            FakeEntityConverter, GlobalWorldManagerType, LocalEntityAndGlobalEntityConverter,
-            LocalEntityAndGlobalEntityConverterMut, LocalEntityConverter,
+            EntityReferenceDetector, FakeEntityConverter, GlobalWorldManagerType,
+            LocalEntityAndGlobalEntityConverter, LocalEntityAndGlobalEntityConverterMut,
+            LocalEntityConverter,
         },
         entity_ref::EntityRef,
         error::EntityDoesNotExistError,
         global_entity::GlobalEntity,
         local_entity::LocalEntity,
     },
+    hierarchy::{
+        hierarchy_channel::EntityHierarchyChannel,
+        hierarchy_messages::{EntityParentRemoved, EntityParentSet},
+    },
     host::{
         global_diff_handler::GlobalDiffHandler,
-        host_world_manager::{HostWorldEvents, HostWorldManager},
+        host_world_manager::{HostWorldEvents, HostWorldManager, PropertyOverride},
         mut_channel::{MutChannelType, MutReceiver},
+        update_cache::UpdateCache,
     },
     local_world_manager::LocalWorldManager,
     remote::{
         entity_action_event::EntityActionEvent, entity_event::EntityEvent,
         remote_world_manager::RemoteWorldManager,
     },
+    validation::{
+        component_correction::ComponentCorrection,
+        component_validator::{AcceptAllValidator, RemoteComponentValidator, ValidationResult},
+        validation_channel::ValidationChannel,
+    },
     world_type::{WorldMutType, WorldRefType},
 };
 
@@ -1,16 +1,21 @@
-use std::{any::Any, collections::HashSet};
+use std::{any::Any, collections::HashSet, sync::Arc};
 
-use naia_serde::BitWrite;
+use naia_serde::{BitBuffer, BitWrite};
 
 use crate::{
-    world::entity::entity_converters::LocalEntityAndGlobalEntityConverterMut, LocalEntity,
-    LocalEntityAndGlobalEntityConverter, Message, MessageKind, MessageKinds,
+    world::entity::entity_converters::{
+        EntityReferenceDetector, LocalEntityAndGlobalEntityConverterMut,
+    },
+    LocalEntity, LocalEntityAndGlobalEntityConverter, Message, MessageKind, MessageKinds,
 };
 
 #[derive(Clone)]
 pub struct MessageContainer {
     inner: Box<dyn Message>,
     bit_length: Option<u32>,
+    /// Bits serialized ahead of time, shared by every connection the Message
+    /// is sent to
+    shared_bits: Option<Arc<BitBuffer>>,
 }
 
 impl MessageContainer {
@@ -22,13 +27,36 @@ impl MessageContainer {
         Self {
             inner: message,
             bit_length: Some(bit_length),
+            shared_bits: None,
         }
     }
 
+    /// Serializes a Message once, so that clones of the returned container can
+    /// be written to any connection without serializing again. Messages which
+    /// refer to Entities are written differently for each connection, and are
+    /// handed back.
+    pub fn from_write_shared(
+        message: Box<dyn Message>,
+        message_kinds: &MessageKinds,
+    ) -> Result<Self, Box<dyn Message>> {
+        let mut detector = EntityReferenceDetector::new();
+        let mut bits = BitBuffer::new();
+        message.write(message_kinds, &mut bits, &mut detector);
+        if detector.found_reference() {
+            return Err(message);
+        }
+        Ok(Self {
+            inner: message,
+            bit_length: Some(bits.bit_length()),
+            shared_bits: Some(Arc::new(bits)),
+        })
+    }
+
     pub fn from_read(message: Box<dyn Message>) -> Self {
         Self {
             inner: message,
             bit_length: None,
+            shared_bits: None,
         }
     }
 
@@ -48,6 +76,8 @@ impl MessageContainer {
     ) {
         if writer.is_counter() {
             writer.write_bits(self.bit_length());
+        } else if let Some(shared_bits) = &self.shared_bits {
+            shared_bits.write_to(writer);
         } else {
             self.inner.write(message_kinds, writer, converter);
         }
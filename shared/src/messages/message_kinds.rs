@@ -33,7 +33,7 @@ impl MessageKind {
 
     pub fn de(message_kinds: &MessageKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(message_kinds.net_id_to_kind(&net_id))
+        message_kinds.net_id_to_kind(&net_id).ok_or(SerdeErr)
     }
 }
 
@@ -79,10 +79,10 @@ impl MessageKinds {
         return self.kind_to_builder(&message_kind).read(reader, converter);
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> MessageKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Message with Protocol via `add_message()` function!",
-        );
+    /// Returns None for net ids the Protocol doesn't know of, which a remote
+    /// host with a different Protocol, or a malformed packet, can send
+    fn net_id_to_kind(&self, net_id: &NetId) -> Option<MessageKind> {
+        return self.net_id_map.get(net_id).copied();
     }
 
     fn kind_to_net_id(&self, message_kind: &MessageKind) -> NetId {
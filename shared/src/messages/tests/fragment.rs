@@ -45,16 +45,16 @@ fn setup() -> (
 
 #[test]
 fn convert_single_fragment() {
-    let (message_kinds, converter, mut fragmenter, mut receiver) = setup();
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
 
     // Message
     let initial_message = StringMessage::new("hello");
     let outgoing_message = initial_message.clone();
 
-    let container = MessageContainer::from_write(Box::new(outgoing_message), &FakeEntityConverter);
+    let container = MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
 
     // Fragment Message
-    let fragments = fragmenter.fragment_message(&message_kinds, &converter, container);
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container);
     let fragment_count = fragments.len();
 
     // Receive Fragments
@@ -81,7 +81,7 @@ fn convert_single_fragment() {
 
 #[test]
 fn convert_multiple_fragments() {
-    let (message_kinds, converter, mut fragmenter, mut receiver) = setup();
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
 
     // Message
     let initial_message = StringMessage::new("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Donec sed justo a mi ultricies ultrices. \
@@ -95,10 +95,10 @@ fn convert_multiple_fragments() {
             Donec ut purus venenatis, mollis est ut, sollicitudin egestas.");
     let outgoing_message = initial_message.clone();
 
-    let container = MessageContainer::from_write(Box::new(outgoing_message), &FakeEntityConverter);
+    let container = MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
 
     // Fragment Message
-    let fragments = fragmenter.fragment_message(&message_kinds, &converter, container);
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container);
     let fragment_count = fragments.len();
 
     // Receive Fragments
@@ -130,3 +130,39 @@ fn convert_multiple_fragments() {
     assert_eq!(fragment_count, 3);
     assert_eq!(initial_message.inner, incoming_message.inner);
 }
+
+#[test]
+fn convert_many_fragments() {
+    let (message_kinds, mut converter, mut fragmenter, mut receiver) = setup();
+
+    // Message
+    let initial_message = StringMessage::new(&"naia".repeat(10_000));
+    let outgoing_message = initial_message.clone();
+
+    let container = MessageContainer::from_write(Box::new(outgoing_message), &mut FakeEntityConverter);
+
+    // Fragment Message
+    let fragments = fragmenter.fragment_message(&message_kinds, &mut converter, container);
+    let fragment_count = fragments.len();
+
+    // Receive Fragments
+    let mut incoming_message_container_opt = None;
+    for fragment in fragments {
+        if let Some((_, reassembled_message)) =
+            receiver.receive(&message_kinds, &converter, fragment)
+        {
+            incoming_message_container_opt = Some(reassembled_message);
+            break;
+        }
+    }
+    let Some(incoming_message_container) = incoming_message_container_opt else {
+        panic!("Did not receive reassembled message!");
+    };
+    let Ok(incoming_message) = incoming_message_container.to_boxed_any().downcast::<StringMessage>() else {
+        panic!("cannot cast message container into proper message!");
+    };
+
+    // Compare
+    assert!(fragment_count > 100);
+    assert_eq!(initial_message.inner, incoming_message.inner);
+}
@@ -1,8 +1,9 @@
 use std::time::Duration;
 
-use naia_socket_shared::{LinkConditionerConfig, SocketConfig};
+use naia_socket_shared::{LinkConditionerConfig, LinkConditionerSchedule, SocketConfig};
 
 use crate::{
+    assets::asset_channel::AssetCachePlugin,
     connection::compression_config::CompressionConfig,
     messages::{
         channels::{
@@ -14,7 +15,12 @@ use crate::{
         message::Message,
         message_kinds::MessageKinds,
     },
-    world::component::{component_kinds::ComponentKinds, replicate::Replicate},
+    world::{
+        authority::authority_channel::EntityAuthorityPlugin,
+        component::{component_kinds::ComponentKinds, replicate::Replicate},
+        hierarchy::hierarchy_channel::EntityHierarchyPlugin,
+        validation::validation_channel::ValidationPlugin,
+    },
 };
 
 // Protocol Plugin
@@ -35,6 +41,12 @@ pub struct Protocol {
     pub compression: Option<CompressionConfig>,
     /// Whether or not Client Authoritative Entities will be allowed
     pub client_authoritative_entities: bool,
+    /// Whether or not the Server may offer content-addressed Assets to Clients
+    pub asset_cache: bool,
+    /// Whether or not Clients may request authority over Server Entities
+    pub entity_authority: bool,
+    /// Whether or not parent/child relations between Entities are replicated
+    pub entity_hierarchy: bool,
     locked: bool,
 }
 
@@ -50,6 +62,9 @@ impl Default for Protocol {
             tick_interval: Duration::from_millis(50),
             compression: None,
             client_authoritative_entities: false,
+            asset_cache: false,
+            entity_authority: false,
+            entity_hierarchy: false,
             locked: false,
         }
     }
@@ -72,6 +87,12 @@ impl Protocol {
         self
     }
 
+    pub fn link_condition_schedule(&mut self, schedule: LinkConditionerSchedule) -> &mut Self {
+        self.check_lock();
+        self.socket.link_condition_schedule = Some(schedule);
+        self
+    }
+
     pub fn rtc_endpoint(&mut self, path: String) -> &mut Self {
         self.check_lock();
         self.socket.rtc_endpoint_path = path;
@@ -93,6 +114,32 @@ impl Protocol {
     pub fn enable_client_authoritative_entities(&mut self) -> &mut Self {
         self.check_lock();
         self.client_authoritative_entities = true;
+        let plugin = ValidationPlugin;
+        plugin.build(self);
+        self
+    }
+
+    pub fn enable_asset_cache(&mut self) -> &mut Self {
+        self.check_lock();
+        self.asset_cache = true;
+        let plugin = AssetCachePlugin;
+        plugin.build(self);
+        self
+    }
+
+    pub fn enable_entity_authority(&mut self) -> &mut Self {
+        self.check_lock();
+        self.entity_authority = true;
+        let plugin = EntityAuthorityPlugin;
+        plugin.build(self);
+        self
+    }
+
+    pub fn enable_entity_hierarchy(&mut self) -> &mut Self {
+        self.check_lock();
+        self.entity_hierarchy = true;
+        let plugin = EntityHierarchyPlugin;
+        plugin.build(self);
         self
     }
 
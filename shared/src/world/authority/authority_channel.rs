@@ -0,0 +1,23 @@
+use crate::{
+    messages::channels::channel::{ChannelDirection, ChannelMode, ReliableSettings},
+    world::authority::authority_messages::{EntityAuthorityMessage, EntityAuthorityUpdate},
+    Channel, Protocol, ProtocolPlugin,
+};
+
+/// Internal Channel used to request, grant, deny & revoke authority over
+/// Entities, and to carry updates from the authority holder
+#[derive(Channel)]
+pub struct EntityAuthorityChannel;
+
+pub(crate) struct EntityAuthorityPlugin;
+impl ProtocolPlugin for EntityAuthorityPlugin {
+    fn build(&self, protocol: &mut Protocol) {
+        protocol
+            .add_channel::<EntityAuthorityChannel>(
+                ChannelDirection::Bidirectional,
+                ChannelMode::OrderedReliable(ReliableSettings::default()),
+            )
+            .add_message::<EntityAuthorityMessage>()
+            .add_message::<EntityAuthorityUpdate>();
+    }
+}
@@ -0,0 +1,67 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+use naia_serde::SerdeInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+/// The kind of change to an Entity's authority carried by an
+/// EntityAuthorityMessage
+#[derive(Copy, PartialEq, Eq, Clone, Debug, SerdeInternal)]
+pub enum EntityAuthorityAction {
+    // Client asks the Server for authority over an Entity
+    Request,
+    // Client gives up authority it holds over an Entity
+    Release,
+    // Server gives a Client authority over an Entity
+    Grant,
+    // Server refuses a Client's request for authority
+    Deny,
+    // Server takes back authority from a Client
+    Revoke,
+}
+
+/// Sent between Client & Server to negotiate authority over an Entity
+#[derive(MessageInternal)]
+pub struct EntityAuthorityMessage {
+    pub entity: EntityProperty,
+    pub action: EntityAuthorityAction,
+}
+
+impl EntityAuthorityMessage {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        action: EntityAuthorityAction,
+    ) -> Self {
+        let mut message = Self {
+            entity: EntityProperty::new(),
+            action,
+        };
+        message.entity.set(converter, entity);
+        message
+    }
+}
+
+/// Sent from a Client holding authority over an Entity, carries a serialized
+/// Component update (the ComponentKind followed by the changed Properties)
+#[derive(MessageInternal)]
+pub struct EntityAuthorityUpdate {
+    pub entity: EntityProperty,
+    pub update: Vec<u8>,
+}
+
+impl EntityAuthorityUpdate {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        update: Vec<u8>,
+    ) -> Self {
+        let mut message = Self {
+            entity: EntityProperty::new(),
+            update,
+        };
+        message.entity.set(converter, entity);
+        message
+    }
+}
@@ -0,0 +1,2 @@
+pub mod authority_channel;
+pub mod authority_messages;
@@ -39,7 +39,7 @@ impl ComponentKind {
 
     pub fn de(component_kinds: &ComponentKinds, reader: &mut BitReader) -> Result<Self, SerdeErr> {
         let net_id: NetId = NetId::de(reader)?;
-        Ok(component_kinds.net_id_to_kind(&net_id))
+        component_kinds.net_id_to_kind(&net_id).ok_or(SerdeErr)
     }
 }
 
@@ -94,6 +94,12 @@ impl ComponentKinds {
             .read_create_update(reader);
     }
 
+    pub fn update_field_names(&self, update: &ComponentUpdate) -> Result<Vec<String>, SerdeErr> {
+        return self
+            .kind_to_builder(&update.kind)
+            .update_field_names(update);
+    }
+
     pub fn split_update(
         &self,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
@@ -115,10 +121,8 @@ impl ComponentKinds {
         return self.kind_to_builder(component_kind).name();
     }
 
-    fn net_id_to_kind(&self, net_id: &NetId) -> ComponentKind {
-        return *self.net_id_map.get(net_id).expect(
-            "Must properly initialize Component with Protocol via `add_component()` function!",
-        );
+    fn net_id_to_kind(&self, net_id: &NetId) -> Option<ComponentKind> {
+        return self.net_id_map.get(net_id).copied();
     }
 
     fn kind_to_net_id(&self, component_kind: &ComponentKind) -> NetId {
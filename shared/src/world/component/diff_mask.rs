@@ -96,6 +96,26 @@ impl DiffMask {
         }
     }
 
+    /// Creates a new DiffMask from a range of bits within the DiffMask, used
+    /// to track the Properties of a nested struct
+    pub fn sub_mask(&self, start: u8, length: u8) -> DiffMask {
+        let bytes = if length == 0 {
+            0
+        } else {
+            ((length - 1) / 8) + 1
+        };
+        let mut output = DiffMask::new(bytes);
+        for index in 0..length {
+            let Some(source_index) = start.checked_add(index) else {
+                break;
+            };
+            if let Some(true) = self.bit(source_index) {
+                output.set_bit(index, true);
+            }
+        }
+        output
+    }
+
     /// Copies the DiffMask into another DiffMask
     pub fn copy_contents(&mut self, other: &DiffMask) {
         //if other diff mask has different capacity, do nothing
@@ -365,4 +385,20 @@ mod double_byte_tests {
         assert!(!mask_b.bit(9).unwrap());
         assert!(mask_b.bit(10).unwrap());
     }
+
+    #[test]
+    fn sub_mask() {
+        let mut mask = DiffMask::new(2);
+        mask.set_bit(3, true);
+        mask.set_bit(6, true);
+        mask.set_bit(12, true);
+
+        let sub_mask = mask.sub_mask(5, 8);
+
+        assert_eq!(sub_mask.byte_number(), 1);
+        assert!(!sub_mask.bit(0).unwrap());
+        assert!(sub_mask.bit(1).unwrap());
+        assert!(sub_mask.bit(7).unwrap());
+        assert!(mask.sub_mask(7, 4).is_clear());
+    }
 }
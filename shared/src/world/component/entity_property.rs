@@ -166,6 +166,19 @@ impl EntityProperty {
         Ok(())
     }
 
+    /// Reads an update sent by the remote holder of this Entity's authority
+    /// into the host-owned relation, and queues it for update
+    pub fn read_authority(
+        &mut self,
+        reader: &mut BitReader,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Result<(), SerdeErr> {
+        let EntityRelation::HostOwned(inner) = &mut self.inner else {
+            panic!("Remote EntityProperty should never read an authority update.");
+        };
+        inner.read(reader, converter)
+    }
+
     // Internal
 
     pub fn get<E: Copy + Eq + Hash>(
@@ -347,6 +360,27 @@ impl HostOwnedRelation {
         }
     }
 
+    pub fn read(
+        &mut self,
+        reader: &mut BitReader,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+    ) -> Result<(), SerdeErr> {
+        let exists = bool::de(reader)?;
+        self.global_entity = if exists {
+            let local_entity = LocalEntity::owned_de(reader)?;
+            if let Ok(global_entity) = converter.local_entity_to_global_entity(&local_entity) {
+                Some(global_entity)
+            } else {
+                warn!("Could not find Global Entity from Local Entity, in order to read the EntityRelation value!");
+                None
+            }
+        } else {
+            None
+        };
+        self.mutate();
+        Ok(())
+    }
+
     pub fn mirror_host(&mut self, other: &HostOwnedRelation) {
         self.global_entity = other.global_entity;
         self.mutate();
@@ -2,6 +2,8 @@ pub mod component_kinds;
 pub mod component_update;
 pub mod diff_mask;
 pub mod entity_property;
+pub mod nested_property;
+pub mod nested_replicate;
 pub mod property;
 pub mod property_mutate;
 pub mod replica_ref;
@@ -0,0 +1,96 @@
+use std::ops::{Deref, DerefMut};
+
+use naia_serde::{BitReader, BitWrite, BitWriter, SerdeErr};
+
+use crate::world::component::{
+    diff_mask::DiffMask, nested_replicate::NestedReplicate, property_mutate::PropertyMutator,
+};
+
+/// A Property of a Component which holds a nested struct of Properties. Each
+/// Property of the nested struct is synced independently.
+///
+/// Properties of the nested struct can be modified through this as normal, but
+/// replacing the whole struct should be done with `mirror()`, in order to keep
+/// changes tracked.
+pub struct NestedProperty<T: NestedReplicate> {
+    inner: T,
+    mutator_index: u8,
+}
+
+impl<T: NestedReplicate> NestedProperty<T> {
+    /// Create a new host-owned NestedProperty
+    pub fn host_owned(value: T, mutator_index: u8) -> Self {
+        Self {
+            inner: value,
+            mutator_index,
+        }
+    }
+
+    /// Set an PropertyMutator to track changes to the nested Properties
+    pub fn set_mutator(&mut self, mutator: &PropertyMutator) {
+        self.inner
+            .set_mutator(&mutator.with_offset(self.mutator_index));
+    }
+
+    // Serialization / deserialization
+
+    /// Writes all nested Properties into outgoing byte stream
+    pub fn write(&self, writer: &mut dyn BitWrite) {
+        self.inner.write(writer);
+    }
+
+    /// Writes nested Properties which have changed according to the given
+    /// sub-DiffMask into outgoing byte stream
+    pub fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite) {
+        self.inner.write_update(diff_mask, writer);
+    }
+
+    /// Given a cursor into incoming packet data, initializes the
+    /// NestedProperty with the synced value
+    pub fn new_read(reader: &mut BitReader) -> Result<Self, SerdeErr> {
+        Ok(Self {
+            inner: T::new_read(reader)?,
+            mutator_index: 0,
+        })
+    }
+
+    /// Reads an update from a stream and immediately writes it to a stream
+    /// Used to buffer updates for later
+    pub fn read_write(reader: &mut BitReader, writer: &mut BitWriter) -> Result<(), SerdeErr> {
+        T::read_write_update(reader, writer)
+    }
+
+    /// Given a cursor into incoming packet data, updates the nested Properties
+    /// with the synced values
+    pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.inner.read_apply_update(reader)
+    }
+
+    /// Given a cursor into an update sent by the remote holder of this
+    /// Entity's authority, updates the nested host-owned Properties with its
+    /// values
+    pub fn read_authority(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.inner.read_apply_authority_update(reader)
+    }
+
+    /// Set value to the value of another NestedProperty, queues changed
+    /// Properties for update
+    pub fn mirror(&mut self, other: &Self) {
+        self.inner.mirror(&other.inner);
+    }
+}
+
+impl<T: NestedReplicate> Deref for NestedProperty<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: NestedReplicate> DerefMut for NestedProperty<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Nested Properties track their own changes
+        &mut self.inner
+    }
+}
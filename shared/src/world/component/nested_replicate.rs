@@ -0,0 +1,38 @@
+use naia_serde::{BitReader, BitWrite, BitWriter, SerdeErr};
+
+use crate::world::component::{diff_mask::DiffMask, property_mutate::PropertyMutator};
+
+/// A struct of Properties which is replicated as a part of a Component, through
+/// a NestedProperty. Each of its Properties has a bit within the Component's
+/// DiffMask, so that changing one of them only syncs that Property.
+pub trait NestedReplicate: Sized + Send + Sync + 'static {
+    /// The number of bits this struct occupies in its Component's DiffMask
+    const PROPERTY_COUNT: u8;
+
+    /// Set the PropertyMutator used to track changes to each Property
+    fn set_mutator(&mut self, mutator: &PropertyMutator);
+
+    /// Sets the current value of each Property to the value of another
+    fn mirror(&mut self, other: &Self);
+
+    /// Writes every Property into an outgoing byte stream
+    fn write(&self, writer: &mut dyn BitWrite);
+
+    /// Reads every Property from an incoming byte stream
+    fn new_read(reader: &mut BitReader) -> Result<Self, SerdeErr>;
+
+    /// Writes the Properties which have changed, according to the given
+    /// sub-DiffMask, into an outgoing byte stream
+    fn write_update(&self, diff_mask: &DiffMask, writer: &mut dyn BitWrite);
+
+    /// Reads an update written by `write_update` and applies it
+    fn read_apply_update(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr>;
+
+    /// Reads an update written by the remote holder of the Entity's authority
+    /// and applies it to host-owned Properties, queueing them for update
+    fn read_apply_authority_update(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr>;
+
+    /// Reads an update written by `write_update` and immediately writes it to
+    /// another stream. Used to buffer updates for later
+    fn read_write_update(reader: &mut BitReader, writer: &mut BitWriter) -> Result<(), SerdeErr>;
+}
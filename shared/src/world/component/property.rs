@@ -42,9 +42,15 @@ impl<T: Serde> Property<T> {
 
     /// Writes contained value into outgoing byte stream
     pub fn write(&self, writer: &mut dyn BitWrite) {
+        self.write_with(writer, T::ser);
+    }
+
+    /// Writes contained value into outgoing byte stream, using the given
+    /// serialization function
+    pub fn write_with(&self, writer: &mut dyn BitWrite, ser: fn(&T, &mut dyn BitWrite)) {
         match &self.inner {
             PropertyImpl::HostOwned(inner) => {
-                inner.write(writer);
+                ser(&inner.inner, writer);
             }
             PropertyImpl::RemoteOwned(_) => {
                 panic!("Remote Property should never be written.");
@@ -55,7 +61,16 @@ impl<T: Serde> Property<T> {
     /// Given a cursor into incoming packet data, initializes the Property with
     /// the synced value
     pub fn new_read(reader: &mut BitReader) -> Result<Self, SerdeErr> {
-        let inner_value = Self::read_inner(reader)?;
+        Self::new_read_with(reader, T::de)
+    }
+
+    /// Given a cursor into incoming packet data, initializes the Property with
+    /// the synced value, using the given deserialization function
+    pub fn new_read_with(
+        reader: &mut BitReader,
+        de: fn(&mut BitReader) -> Result<T, SerdeErr>,
+    ) -> Result<Self, SerdeErr> {
+        let inner_value = de(reader)?;
 
         Ok(Self {
             inner: PropertyImpl::RemoteOwned(RemoteOwnedProperty::new(inner_value)),
@@ -65,26 +80,68 @@ impl<T: Serde> Property<T> {
     /// Reads from a stream and immediately writes to a stream
     /// Used to buffer updates for later
     pub fn read_write(reader: &mut BitReader, writer: &mut BitWriter) -> Result<(), SerdeErr> {
-        T::de(reader)?.ser(writer);
+        Self::read_write_with(reader, writer, T::de, T::ser)
+    }
+
+    /// Reads from a stream and immediately writes to a stream, using the given
+    /// serialization functions
+    pub fn read_write_with(
+        reader: &mut BitReader,
+        writer: &mut BitWriter,
+        de: fn(&mut BitReader) -> Result<T, SerdeErr>,
+        ser: fn(&T, &mut dyn BitWrite),
+    ) -> Result<(), SerdeErr> {
+        ser(&de(reader)?, writer);
         Ok(())
     }
 
     /// Given a cursor into incoming packet data, updates the Property with the
     /// synced value
     pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.read_with(reader, T::de)
+    }
+
+    /// Given a cursor into incoming packet data, updates the Property with the
+    /// synced value, using the given deserialization function
+    pub fn read_with(
+        &mut self,
+        reader: &mut BitReader,
+        de: fn(&mut BitReader) -> Result<T, SerdeErr>,
+    ) -> Result<(), SerdeErr> {
         match &mut self.inner {
             PropertyImpl::HostOwned(_) => {
                 panic!("Host Property should never read.");
             }
             PropertyImpl::RemoteOwned(inner) => {
-                inner.read(reader)?;
+                inner.inner = de(reader)?;
             }
         }
         Ok(())
     }
 
-    fn read_inner(reader: &mut BitReader) -> Result<T, SerdeErr> {
-        T::de(reader)
+    /// Given a cursor into an update sent by the remote holder of this
+    /// Entity's authority, updates the host-owned Property with its value, and
+    /// queues it for update
+    pub fn read_authority(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
+        self.read_authority_with(reader, T::de)
+    }
+
+    /// Like `read_authority`, using the given deserialization function
+    pub fn read_authority_with(
+        &mut self,
+        reader: &mut BitReader,
+        de: fn(&mut BitReader) -> Result<T, SerdeErr>,
+    ) -> Result<(), SerdeErr> {
+        match &mut self.inner {
+            PropertyImpl::HostOwned(inner) => {
+                inner.inner = de(reader)?;
+                inner.mutate();
+            }
+            PropertyImpl::RemoteOwned(_) => {
+                panic!("Remote Property should never read an authority update.");
+            }
+        }
+        Ok(())
     }
 
     // Comparison
@@ -162,10 +219,6 @@ impl<T: Serde> HostOwnedProperty<T> {
         self.mutator = Some(mutator.clone_new());
     }
 
-    pub fn write(&self, writer: &mut dyn BitWrite) {
-        self.inner.ser(writer);
-    }
-
     pub fn mirror(&mut self, other: &T) {
         self.mutate();
         self.inner = other.clone();
@@ -188,9 +241,4 @@ impl<T: Serde> RemoteOwnedProperty<T> {
     pub fn new(value: T) -> Self {
         Self { inner: value }
     }
-
-    pub fn read(&mut self, reader: &mut BitReader) -> Result<(), SerdeErr> {
-        self.inner = Property::read_inner(reader)?;
-        Ok(())
-    }
 }
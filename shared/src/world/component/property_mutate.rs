@@ -36,6 +36,17 @@ impl PropertyMutator {
         Self { inner }
     }
 
+    /// Creates a PropertyMutator which offsets each Property index by the
+    /// given amount before passing it to this PropertyMutator. Used by the
+    /// Properties of a nested struct, which occupy a range of the Component's
+    /// DiffMask
+    pub fn with_offset(&self, offset: u8) -> Self {
+        Self::new(OffsetPropertyMutator {
+            inner: self.clone_new(),
+            offset,
+        })
+    }
+
     pub fn clone_new(&self) -> Self {
         //let current_inner: &dyn PropertyMutateClone = self.inner.as_ref() as &dyn
         // PropertyMutateClone;
@@ -58,3 +69,15 @@ impl DerefMut for PropertyMutator {
         self.inner.deref_mut()
     }
 }
+
+#[derive(Clone)]
+struct OffsetPropertyMutator {
+    inner: PropertyMutator,
+    offset: u8,
+}
+
+impl PropertyMutate for OffsetPropertyMutator {
+    fn mutate(&mut self, property_index: u8) {
+        self.inner.mutate(self.offset + property_index);
+    }
+}
@@ -26,6 +26,11 @@ pub trait ReplicateBuilder: Send + Sync + Named {
     ) -> Result<Box<dyn Replicate>, SerdeErr>;
     /// Create new Component Update from incoming bit stream
     fn read_create_update(&self, reader: &mut BitReader) -> Result<ComponentUpdate, SerdeErr>;
+    /// Get the names of the fields a Component Update carries, which are only
+    /// used for inspecting packets, so builders may leave them out
+    fn update_field_names(&self, _update: &ComponentUpdate) -> Result<Vec<String>, SerdeErr> {
+        Ok(Vec::new())
+    }
     /// Split a Component update into Waiting and Ready updates
     fn split_update(
         &self,
@@ -89,11 +94,26 @@ pub trait Replicate: ReplicateInner + Named + Any {
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         update: ComponentUpdate,
     ) -> Result<(), SerdeErr>;
+    /// Reads an update sent by the remote holder of the Entity's authority,
+    /// and applies it to the host-owned Component, queueing the changed
+    /// Properties to be replicated onwards
+    fn read_apply_authority_update(
+        &mut self,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        update: ComponentUpdate,
+    ) -> Result<(), SerdeErr>;
     fn read_apply_field_update(
         &mut self,
         converter: &dyn LocalEntityAndGlobalEntityConverter,
         update: ComponentFieldUpdate,
     ) -> Result<(), SerdeErr>;
+    /// Like `read_apply_authority_update`, for an update of a single
+    /// EntityProperty which was waiting on its Entity
+    fn read_apply_authority_field_update(
+        &mut self,
+        converter: &dyn LocalEntityAndGlobalEntityConverter,
+        update: ComponentFieldUpdate,
+    ) -> Result<(), SerdeErr>;
     /// Returns a list of LocalEntities contained within the Component's EntityProperty fields, which are waiting to be converted to GlobalEntities
     fn relations_waiting(&self) -> Option<HashSet<LocalEntity>>;
     /// Converts any LocalEntities contained within the Component's EntityProperty fields to GlobalEntities
@@ -78,6 +78,51 @@ impl LocalEntityAndGlobalEntityConverterMut for FakeEntityConverter {
     }
 }
 
+/// Converts no Entities, but records whether any were asked for. Data written
+/// without asking for an Entity reads the same on every connection.
+#[derive(Default)]
+pub struct EntityReferenceDetector {
+    found_reference: bool,
+}
+
+impl EntityReferenceDetector {
+    pub fn new() -> Self {
+        Self {
+            found_reference: false,
+        }
+    }
+
+    pub fn found_reference(&self) -> bool {
+        self.found_reference
+    }
+}
+
+impl LocalEntityAndGlobalEntityConverter for EntityReferenceDetector {
+    fn global_entity_to_local_entity(
+        &self,
+        _: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        Err(EntityDoesNotExistError)
+    }
+
+    fn local_entity_to_global_entity(
+        &self,
+        _: &LocalEntity,
+    ) -> Result<GlobalEntity, EntityDoesNotExistError> {
+        Err(EntityDoesNotExistError)
+    }
+}
+
+impl LocalEntityAndGlobalEntityConverterMut for EntityReferenceDetector {
+    fn get_or_reserve_host_entity(
+        &mut self,
+        _global_entity: &GlobalEntity,
+    ) -> Result<LocalEntity, EntityDoesNotExistError> {
+        self.found_reference = true;
+        Err(EntityDoesNotExistError)
+    }
+}
+
 pub struct EntityConverter<'a, 'b, E: Eq + Copy + Hash> {
     global_entity_converter: &'a dyn EntityAndGlobalEntityConverter<E>,
     local_entity_converter: &'b dyn LocalEntityConverter<E>,
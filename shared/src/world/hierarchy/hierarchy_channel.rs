@@ -0,0 +1,22 @@
+use crate::{
+    messages::channels::channel::{ChannelDirection, ChannelMode, ReliableSettings},
+    world::hierarchy::hierarchy_messages::{EntityParentRemoved, EntityParentSet},
+    Channel, Protocol, ProtocolPlugin,
+};
+
+/// Internal Channel used to replicate parent/child relations between Entities
+#[derive(Channel)]
+pub struct EntityHierarchyChannel;
+
+pub(crate) struct EntityHierarchyPlugin;
+impl ProtocolPlugin for EntityHierarchyPlugin {
+    fn build(&self, protocol: &mut Protocol) {
+        protocol
+            .add_channel::<EntityHierarchyChannel>(
+                ChannelDirection::ServerToClient,
+                ChannelMode::OrderedReliable(ReliableSettings::default()),
+            )
+            .add_message::<EntityParentSet>()
+            .add_message::<EntityParentRemoved>();
+    }
+}
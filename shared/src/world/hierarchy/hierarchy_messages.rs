@@ -0,0 +1,48 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+/// Sent from the Server when an Entity becomes the child of another. Only
+/// delivered once both Entities exist on the Client.
+#[derive(MessageInternal)]
+pub struct EntityParentSet {
+    pub child: EntityProperty,
+    pub parent: EntityProperty,
+}
+
+impl EntityParentSet {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        child: &E,
+        parent: &E,
+    ) -> Self {
+        let mut message = Self {
+            child: EntityProperty::new(),
+            parent: EntityProperty::new(),
+        };
+        message.child.set(converter, child);
+        message.parent.set(converter, parent);
+        message
+    }
+}
+
+/// Sent from the Server when an Entity is detached from its parent
+#[derive(MessageInternal)]
+pub struct EntityParentRemoved {
+    pub child: EntityProperty,
+}
+
+impl EntityParentRemoved {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        child: &E,
+    ) -> Self {
+        let mut message = Self {
+            child: EntityProperty::new(),
+        };
+        message.child.set(converter, child);
+        message
+    }
+}
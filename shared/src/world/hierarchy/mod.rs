@@ -0,0 +1,2 @@
+pub mod hierarchy_channel;
+pub mod hierarchy_messages;
@@ -11,7 +11,7 @@ use crate::{
     world::{
         entity::entity_converters::GlobalWorldManagerType, local_world_manager::LocalWorldManager,
     },
-    ComponentKind, DiffMask, EntityAction, Instant, MessageIndex, PacketIndex,
+    ComponentKind, DiffMask, EntityAction, Instant, MessageIndex, PacketIndex, Replicate,
 };
 
 use super::{entity_action_event::EntityActionEvent, world_channel::WorldChannel};
@@ -21,6 +21,17 @@ const ACTION_RECORD_TTL: Duration = Duration::from_secs(60);
 
 pub type ActionId = MessageIndex;
 
+/// Modifies a copy of a Component before it is written to one connection, so
+/// that its User sees different values of some Properties
+pub type PropertyOverride = Box<dyn Fn(&mut dyn Replicate) + Send + Sync>;
+
+/// A connection's override of a Component, and the overridden copy of the
+/// Component which is written to it
+struct OverriddenComponent {
+    property_override: PropertyOverride,
+    copy: Option<Box<dyn Replicate>>,
+}
+
 /// Manages Entities for a given Client connection and keeps them in
 /// sync on the Client
 pub struct HostWorldManager<E: Copy + Eq + Hash + Send + Sync> {
@@ -35,6 +46,7 @@ pub struct HostWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     pub sent_updates: HashMap<PacketIndex, (Instant, HashMap<(E, ComponentKind), DiffMask>)>,
     /// Last [`PacketIndex`] where a component update was written by the server
     pub last_update_packet_index: PacketIndex,
+    property_overrides: HashMap<(E, ComponentKind), OverriddenComponent>,
 }
 
 pub struct HostWorldEvents<E: Copy + Eq + Hash + Send + Sync> {
@@ -49,19 +61,22 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldEvents<E> {
 }
 
 impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
-    /// Create a new HostWorldManager, given the client's address
+    /// Create a new HostWorldManager, given the client's address, and whether
+    /// acknowledged spawns & inserts should be recorded for the host application
     pub fn new(
         address: &Option<SocketAddr>,
+        records_acks: bool,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
     ) -> Self {
         HostWorldManager {
             // World
-            world_channel: WorldChannel::new(address, global_world_manager),
+            world_channel: WorldChannel::new(address, records_acks, global_world_manager),
             sent_action_packets: SequenceList::new(),
 
             // Update
             sent_updates: HashMap::new(),
             last_update_packet_index: 0,
+            property_overrides: HashMap::new(),
         }
     }
 
@@ -104,10 +119,108 @@ impl<E: Copy + Eq + Hash + Send + Sync> HostWorldManager<E> {
         self.world_channel.host_has_entity(entity)
     }
 
+    pub fn host_entities(&self) -> Vec<E> {
+        self.world_channel.host_entities()
+    }
+
+    /// Returns the Entity spawns & Component inserts the remote has
+    /// acknowledged since the last call
+    pub fn take_acked_actions(&mut self) -> Vec<EntityAction<E>> {
+        self.world_channel.take_acked_actions()
+    }
+
     pub fn entity_channel_is_open(&self, entity: &E) -> bool {
         self.world_channel.entity_channel_is_open(entity)
     }
 
+    // Property Overrides
+
+    pub fn set_property_override(
+        &mut self,
+        entity: &E,
+        component_kind: &ComponentKind,
+        property_override: PropertyOverride,
+    ) {
+        self.property_overrides.insert(
+            (*entity, *component_kind),
+            OverriddenComponent {
+                property_override,
+                copy: None,
+            },
+        );
+        self.resend_component(entity, component_kind);
+    }
+
+    pub fn clear_property_override(&mut self, entity: &E, component_kind: &ComponentKind) {
+        if self
+            .property_overrides
+            .remove(&(*entity, *component_kind))
+            .is_some()
+        {
+            self.resend_component(entity, component_kind);
+        }
+    }
+
+    pub fn clear_entity_property_overrides(&mut self, entity: &E) {
+        self.property_overrides
+            .retain(|(overridden_entity, _), _| overridden_entity != entity);
+    }
+
+    pub fn has_property_override(&self, entity: &E, component_kind: &ComponentKind) -> bool {
+        self.property_overrides
+            .contains_key(&(*entity, *component_kind))
+    }
+
+    /// Brings the overridden copy of a Component up to date with the World's
+    /// Component, reusing the copy made for previous writes
+    pub fn refresh_overridden_component(
+        &mut self,
+        entity: &E,
+        component_kind: &ComponentKind,
+        component: &dyn Replicate,
+    ) {
+        let Some(overridden) = self.property_overrides.get_mut(&(*entity, *component_kind)) else {
+            return;
+        };
+        let copy = match &mut overridden.copy {
+            Some(copy) => {
+                copy.mirror(component);
+                copy
+            }
+            copy => copy.insert(component.copy_to_box()),
+        };
+        (overridden.property_override)(copy.as_mut());
+    }
+
+    /// The overridden copy of a Component, as of its last refresh
+    pub fn overridden_component(
+        &self,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) -> Option<&dyn Replicate> {
+        self.property_overrides
+            .get(&(*entity, *component_kind))
+            .and_then(|overridden| overridden.copy.as_deref())
+    }
+
+    /// Marks every Property of a replicated Component as changed, so that the
+    /// values this connection sees are written again
+    fn resend_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        let diff_handler = &mut self.world_channel.diff_handler;
+        let Some(byte_number) = diff_handler
+            .diff_mask(entity, component_kind)
+            .map(|diff_mask| diff_mask.byte_number())
+        else {
+            // not yet replicated, the whole Component will be written on insert
+            return;
+        };
+        let mut diff_mask = DiffMask::new(byte_number);
+        for index in 0..(byte_number * 8) {
+            diff_mask.set_bit(index, true);
+        }
+        diff_handler.or_diff_mask(entity, component_kind, &diff_mask);
+    }
+
     // Messages
 
     pub fn collect_outgoing_messages(&mut self, rtt_millis: &f32) {
@@ -2,17 +2,20 @@ use std::{
     clone::Clone,
     collections::{HashMap, HashSet, VecDeque},
     hash::Hash,
+    sync::Arc,
 };
 
 use crate::{
     messages::channels::senders::indexed_message_writer::IndexedMessageWriter,
     sequence_list::SequenceList,
     world::{
-        entity::entity_converters::GlobalWorldManagerType, local_world_manager::LocalWorldManager,
+        entity::entity_converters::{EntityReferenceDetector, GlobalWorldManagerType},
+        local_world_manager::LocalWorldManager,
     },
-    BitWrite, BitWriter, ComponentKind, ComponentKinds, ConstBitLength, EntityAction,
-    EntityActionType, EntityConverterMut, HostWorldEvents, HostWorldManager, Instant,
-    LocalEntityConverter, MessageIndex, PacketIndex, Serde, UnsignedVariableInteger, WorldRefType,
+    BitBuffer, BitWrite, BitWriter, ComponentKind, ComponentKinds, ConstBitLength, DiffMask,
+    EntityAction, EntityActionType, EntityConverterMut, HostWorldEvents, HostWorldManager, Instant,
+    LocalEntityConverter, MessageIndex, PacketIndex, Replicate, Serde, UnsignedVariableInteger,
+    UpdateCache, WorldRefType,
 };
 
 use super::entity_action_event::EntityActionEvent;
@@ -31,6 +34,88 @@ impl HostWorldWriter {
         *last_id_opt = Some(*current_id);
     }
 
+    /// Calls the given function with the Component as this connection sees
+    /// it. Overridden Components must be refreshed before they are written.
+    fn with_component<E: Copy + Eq + Hash + Send + Sync, W: WorldRefType<E>, R>(
+        world: &W,
+        host_manager: &HostWorldManager<E>,
+        entity: &E,
+        component_kind: &ComponentKind,
+        f: impl FnOnce(&dyn Replicate) -> R,
+    ) -> R {
+        if let Some(overridden) = host_manager.overridden_component(entity, component_kind) {
+            return f(overridden);
+        }
+        let component = world
+            .component_of_kind(entity, component_kind)
+            .expect("Component does not exist in World");
+        f(&*component)
+    }
+
+    /// Applies this connection's Property overrides to its copy of a
+    /// Component, once for each time the Component is written
+    fn refresh_override<E: Copy + Eq + Hash + Send + Sync, W: WorldRefType<E>>(
+        world: &W,
+        host_manager: &mut HostWorldManager<E>,
+        entity: &E,
+        component_kind: &ComponentKind,
+    ) {
+        if !host_manager.has_property_override(entity, component_kind) {
+            return;
+        }
+        if let Some(component) = world.component_of_kind(entity, component_kind) {
+            host_manager.refresh_overridden_component(entity, component_kind, &*component);
+        }
+    }
+
+    /// Refreshes the overridden Components which the next action writes
+    fn refresh_action_overrides<E: Copy + Eq + Hash + Send + Sync, W: WorldRefType<E>>(
+        world: &W,
+        host_manager: &mut HostWorldManager<E>,
+        action: &EntityActionEvent<E>,
+    ) {
+        match action {
+            EntityActionEvent::SpawnEntity(entity) => {
+                let component_kinds = host_manager.world_channel.host_component_kinds(entity);
+                for component_kind in &component_kinds {
+                    Self::refresh_override(world, host_manager, entity, component_kind);
+                }
+            }
+            EntityActionEvent::InsertComponent(entity, component_kind) => {
+                Self::refresh_override(world, host_manager, entity, component_kind);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the serialized bits of a Component update which can be shared
+    /// with every connection sending the same update. Updates with Property
+    /// overrides or references to Entities are written per connection.
+    fn shared_update_bits<E: Copy + Eq + Hash + Send + Sync, W: WorldRefType<E>>(
+        world: &W,
+        host_manager: &HostWorldManager<E>,
+        update_cache: &mut UpdateCache<E>,
+        entity: &E,
+        component_kind: &ComponentKind,
+        diff_mask: &DiffMask,
+    ) -> Option<Arc<BitBuffer>> {
+        if host_manager.has_property_override(entity, component_kind) {
+            return None;
+        }
+        update_cache.get_or_write(entity, component_kind, diff_mask, || {
+            let mut detector = EntityReferenceDetector::new();
+            let mut bits = BitBuffer::new();
+            Self::with_component(world, host_manager, entity, component_kind, |component| {
+                component.write_update(diff_mask, &mut bits, &mut detector)
+            });
+            if detector.found_reference() {
+                None
+            } else {
+                Some(bits)
+            }
+        })
+    }
+
     pub fn write_into_packet<E: Copy + Eq + Hash + Send + Sync, W: WorldRefType<E>>(
         component_kinds: &ComponentKinds,
         now: &Instant,
@@ -42,6 +127,7 @@ impl HostWorldWriter {
         has_written: &mut bool,
         host_manager: &mut HostWorldManager<E>,
         world_events: &mut HostWorldEvents<E>,
+        update_cache: &mut UpdateCache<E>,
     ) {
         // write entity updates
         {
@@ -56,6 +142,7 @@ impl HostWorldWriter {
                 has_written,
                 host_manager,
                 &mut world_events.next_send_updates,
+                update_cache,
             );
 
             // finish updates
@@ -104,6 +191,10 @@ impl HostWorldWriter {
                 break;
             }
 
+            if let Some((_, action)) = next_send_actions.front() {
+                Self::refresh_action_overrides(world, host_manager, action);
+            }
+
             // check that we can write the next message
             let mut counter = writer.counter();
             Self::write_action(
@@ -196,11 +287,10 @@ impl HostWorldWriter {
                     .unwrap()
                     .host_ser(writer);
 
-                // get component list
-                let component_kind_list = match global_world_manager.component_kinds(world_entity) {
-                    Some(kind_list) => kind_list,
-                    None => Vec::new(),
-                };
+                // get component list, which may exclude Components hidden from this connection
+                let component_kind_list = host_manager
+                    .world_channel
+                    .host_component_kinds(world_entity);
 
                 // write number of components
                 let components_num =
@@ -212,10 +302,13 @@ impl HostWorldWriter {
                         EntityConverterMut::new(global_world_manager, local_world_manager);
 
                     // write component payload
-                    world
-                        .component_of_kind(world_entity, component_kind)
-                        .expect("Component does not exist in World")
-                        .write(component_kinds, writer, &mut converter);
+                    Self::with_component(
+                        world,
+                        host_manager,
+                        world_entity,
+                        component_kind,
+                        |component| component.write(component_kinds, writer, &mut converter),
+                    );
                 }
 
                 // if we are writing to this packet, add it to record
@@ -278,10 +371,13 @@ impl HostWorldWriter {
                         EntityConverterMut::new(global_world_manager, local_world_manager);
 
                     // write component payload
-                    world
-                        .component_of_kind(world_entity, component)
-                        .expect("Component does not exist in World")
-                        .write(component_kinds, writer, &mut converter);
+                    Self::with_component(
+                        world,
+                        host_manager,
+                        world_entity,
+                        component,
+                        |component| component.write(component_kinds, writer, &mut converter),
+                    );
 
                     // if we are actually writing this packet
                     if is_writing {
@@ -406,6 +502,7 @@ impl HostWorldWriter {
         has_written: &mut bool,
         host_manager: &mut HostWorldManager<E>,
         next_send_updates: &mut HashMap<E, HashSet<ComponentKind>>,
+        update_cache: &mut UpdateCache<E>,
     ) {
         let all_update_entities: Vec<E> = next_send_updates.keys().copied().collect();
 
@@ -446,6 +543,7 @@ impl HostWorldWriter {
                 has_written,
                 host_manager,
                 next_send_updates,
+                update_cache,
             );
 
             // write ComponentContinue finish bit, release
@@ -468,6 +566,7 @@ impl HostWorldWriter {
         has_written: &mut bool,
         host_manager: &mut HostWorldManager<E>,
         next_send_updates: &mut HashMap<E, HashSet<ComponentKind>>,
+        update_cache: &mut UpdateCache<E>,
     ) {
         let mut written_component_kinds = Vec::new();
         let component_kind_set = next_send_updates.get(entity).unwrap();
@@ -481,14 +580,30 @@ impl HostWorldWriter {
                 .clone();
 
             let mut converter = EntityConverterMut::new(global_world_manager, local_world_manager);
+            let shared_bits = Self::shared_update_bits(
+                world,
+                host_manager,
+                update_cache,
+                entity,
+                component_kind,
+                &diff_mask,
+            );
+
+            if shared_bits.is_none() {
+                Self::refresh_override(world, host_manager, entity, component_kind);
+            }
 
             // check that we can write the next component update
             let mut counter = writer.counter();
             counter.write_bits(<ComponentKind as ConstBitLength>::const_bit_length());
-            world
-                .component_of_kind(entity, component_kind)
-                .expect("Component does not exist in World")
-                .write_update(&diff_mask, &mut counter, &mut converter);
+            match &shared_bits {
+                Some(bits) => bits.write_to(&mut counter),
+                None => {
+                    Self::with_component(world, host_manager, entity, component_kind, |component| {
+                        component.write_update(&diff_mask, &mut counter, &mut converter)
+                    })
+                }
+            }
 
             if counter.overflowed() {
                 // if nothing useful has been written in this packet yet,
@@ -514,10 +629,14 @@ impl HostWorldWriter {
             component_kind.ser(component_kinds, writer);
 
             // write data
-            world
-                .component_of_kind(entity, component_kind)
-                .expect("Component does not exist in World")
-                .write_update(&diff_mask, writer, &mut converter);
+            match &shared_bits {
+                Some(bits) => bits.write_to(writer),
+                None => {
+                    Self::with_component(world, host_manager, entity, component_kind, |component| {
+                        component.write_update(&diff_mask, writer, &mut converter)
+                    })
+                }
+            }
 
             written_component_kinds.push(*component_kind);
 
@@ -2,6 +2,7 @@ pub mod global_diff_handler;
 pub mod host_world_manager;
 pub mod host_world_writer;
 pub mod mut_channel;
+pub mod update_cache;
 pub mod user_diff_handler;
 pub mod world_channel;
 
@@ -0,0 +1,116 @@
+use std::{collections::HashMap, hash::Hash, sync::Arc};
+
+use naia_serde::BitBuffer;
+
+use crate::{ComponentKind, DiffMask};
+
+/// The serialized bits of one Component's update for each DiffMask written
+/// this pass
+type CachedUpdates = Vec<(DiffMask, Option<Arc<BitBuffer>>)>;
+
+/// Holds the serialized Component updates written during one send pass, so
+/// that connections sharing an update copy its bits instead of serializing it
+/// again. Must not outlive the pass, as Components change in between.
+pub struct UpdateCache<E: Copy + Eq + Hash> {
+    enabled: bool,
+    /// Entries are None for updates which refer to Entities, as those are
+    /// written differently for each connection
+    updates: HashMap<(E, ComponentKind), CachedUpdates>,
+}
+
+impl<E: Copy + Eq + Hash> UpdateCache<E> {
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            updates: HashMap::new(),
+        }
+    }
+
+    /// A cache which holds nothing, for hosts with a single connection, where
+    /// no update is ever shared
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            updates: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached bits of an update, serializing them with `write` the
+    /// first time they are asked for
+    pub fn get_or_write(
+        &mut self,
+        entity: &E,
+        component_kind: &ComponentKind,
+        diff_mask: &DiffMask,
+        write: impl FnOnce() -> Option<BitBuffer>,
+    ) -> Option<Arc<BitBuffer>> {
+        if !self.enabled {
+            return None;
+        }
+        let entries = self.updates.entry((*entity, *component_kind)).or_default();
+        if let Some((_, bits)) = entries.iter().find(|(mask, _)| mask == diff_mask) {
+            return bits.clone();
+        }
+        let bits = write().map(Arc::new);
+        entries.push((diff_mask.clone(), bits.clone()));
+        bits
+    }
+}
+
+impl<E: Copy + Eq + Hash> Default for UpdateCache<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use naia_serde::{BitBuffer, BitWrite};
+
+    use crate::{ComponentKind, DiffMask};
+
+    use super::UpdateCache;
+
+    struct TestComponent;
+
+    fn bits(bit: bool) -> Option<BitBuffer> {
+        let mut bits = BitBuffer::new();
+        bits.write_bit(bit);
+        Some(bits)
+    }
+
+    #[test]
+    fn serializes_each_update_once() {
+        let component_kind = ComponentKind::from(TypeId::of::<TestComponent>());
+        let mut first_mask = DiffMask::new(1);
+        first_mask.set_bit(0, true);
+        let second_mask = DiffMask::new(1);
+
+        let mut cache = UpdateCache::new();
+        let mut writes = 0;
+        for _ in 0..3 {
+            cache.get_or_write(&1u32, &component_kind, &first_mask, || {
+                writes += 1;
+                bits(true)
+            });
+        }
+        assert_eq!(writes, 1);
+
+        let cached = cache.get_or_write(&1u32, &component_kind, &second_mask, || {
+            writes += 1;
+            None
+        });
+        assert!(cached.is_none());
+        assert_eq!(writes, 2);
+    }
+
+    #[test]
+    fn disabled_cache_holds_nothing() {
+        let component_kind = ComponentKind::from(TypeId::of::<TestComponent>());
+        let mut cache = UpdateCache::disabled();
+        let cached = cache.get_or_write(&1u32, &component_kind, &DiffMask::new(1), || bits(true));
+        assert!(cached.is_none());
+    }
+}
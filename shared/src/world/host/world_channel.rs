@@ -48,6 +48,9 @@ pub struct WorldChannel<E: Copy + Eq + Hash + Send + Sync> {
     entity_channels: CheckedMap<E, EntityChannel>,
     outgoing_actions: ReliableSender<EntityActionEvent<E>>,
     delivered_actions: EntityActionReceiver<E>,
+    /// Spawns & inserts which the remote has acknowledged, for the host
+    /// application. Only recorded if the host surfaces them.
+    acked_actions: Option<Vec<EntityAction<E>>>,
 
     address: Option<SocketAddr>,
     pub diff_handler: UserDiffHandler<E>,
@@ -56,6 +59,7 @@ pub struct WorldChannel<E: Copy + Eq + Hash + Send + Sync> {
 impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
     pub fn new(
         address: &Option<SocketAddr>,
+        records_acks: bool,
         global_world_manager: &dyn GlobalWorldManagerType<E>,
     ) -> Self {
         Self {
@@ -64,6 +68,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
             entity_channels: CheckedMap::new(),
             outgoing_actions: ReliableSender::new(RESEND_ACTION_RTT_FACTOR),
             delivered_actions: EntityActionReceiver::new(),
+            acked_actions: records_acks.then(Vec::new),
 
             address: *address,
             diff_handler: UserDiffHandler::new(global_world_manager),
@@ -76,6 +81,20 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         self.host_world.contains_key(entity)
     }
 
+    /// Returns the Entities which are replicated on this connection
+    pub fn host_entities(&self) -> Vec<E> {
+        self.host_world.iter().map(|(entity, _)| *entity).collect()
+    }
+
+    /// Returns the Components of an Entity which are replicated on this
+    /// connection
+    pub fn host_component_kinds(&self, entity: &E) -> Vec<ComponentKind> {
+        match self.host_world.get(entity) {
+            Some(components) => components.inner.iter().copied().collect(),
+            None => Vec::new(),
+        }
+    }
+
     pub fn entity_channel_is_open(&self, entity: &E) -> bool {
         matches!(
             self.entity_channels.get(entity),
@@ -372,7 +391,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         world_manager.host_spawn_entity(entity);
     }
 
-    fn on_entity_channel_opened(&mut self, _world_entity: &E) {}
+    fn on_entity_channel_opened(&mut self, entity: &E) {
+        // Components acknowledged along with the spawn follow as inserts
+        self.record_ack(EntityAction::SpawnEntity(*entity, Vec::new()));
+    }
 
     fn on_entity_channel_closing(&mut self, _world_entity: &E) {}
 
@@ -383,6 +405,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
     fn on_component_channel_opened(&mut self, entity: &E, component_kind: &ComponentKind) {
         self.diff_handler
             .register_component(&self.address, entity, component_kind);
+        self.record_ack(EntityAction::InsertComponent(*entity, *component_kind));
     }
 
     fn on_component_channel_closing(&mut self, entity: &E, component_kind: &ComponentKind) {
@@ -429,6 +452,21 @@ impl<E: Copy + Eq + Hash + Send + Sync> WorldChannel<E> {
         }
     }
 
+    /// Returns the Entity spawns & Component inserts which the remote has
+    /// acknowledged since the last call
+    pub fn take_acked_actions(&mut self) -> Vec<EntityAction<E>> {
+        match &mut self.acked_actions {
+            Some(acked_actions) => std::mem::take(acked_actions),
+            None => Vec::new(),
+        }
+    }
+
+    fn record_ack(&mut self, action: EntityAction<E>) {
+        if let Some(acked_actions) = &mut self.acked_actions {
+            acked_actions.push(action);
+        }
+    }
+
     // Collect
 
     pub fn take_next_actions(
@@ -5,6 +5,8 @@ use std::{
 
 use log::warn;
 
+use naia_serde::SerdeErr;
+
 use crate::{
     world::{
         local_world_manager::LocalWorldManager,
@@ -13,6 +15,7 @@ use crate::{
             entity_waitlist::{EntityWaitlist, WaitlistHandle, WaitlistStore},
             remote_world_reader::RemoteWorldEvents,
         },
+        validation::component_validator::{RemoteComponentValidator, ValidationResult},
     },
     ComponentFieldUpdate, ComponentKind, ComponentKinds, ComponentUpdate, EntityAction,
     EntityConverter, GlobalWorldManagerType, LocalEntity, Replicate, Tick, WorldMutType,
@@ -24,6 +27,9 @@ pub struct RemoteWorldManager<E: Copy + Eq + Hash + Send + Sync> {
     insert_waitlist_map: HashMap<(E, ComponentKind), WaitlistHandle>,
     update_waitlist_store: WaitlistStore<(Tick, E, ComponentKind, ComponentFieldUpdate)>,
     update_waitlist_map: HashMap<(E, ComponentKind), HashMap<u8, WaitlistHandle>>,
+    /// Components which this host holds authority over, and has replaced in
+    /// the World with host-owned copies
+    authority_components: HashSet<(E, ComponentKind)>,
     outgoing_events: Vec<EntityEvent<E>>,
 }
 
@@ -35,10 +41,33 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             insert_waitlist_map: HashMap::new(),
             update_waitlist_store: WaitlistStore::new(),
             update_waitlist_map: HashMap::new(),
+            authority_components: HashSet::new(),
             outgoing_events: Vec::new(),
         }
     }
 
+    // Authority
+
+    /// Marks a Component as replaced with a host-owned copy, so that further
+    /// updates to it are read through the authority read path
+    pub fn insert_authority_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        self.authority_components.insert((*entity, *component_kind));
+    }
+
+    pub fn remove_authority_component(&mut self, entity: &E, component_kind: &ComponentKind) {
+        self.authority_components
+            .remove(&(*entity, *component_kind));
+    }
+
+    pub fn has_authority_component(&self, entity: &E, component_kind: &ComponentKind) -> bool {
+        self.authority_components
+            .contains(&(*entity, *component_kind))
+    }
+
+    pub fn authority_components(&self) -> Vec<(E, ComponentKind)> {
+        self.authority_components.iter().copied().collect()
+    }
+
     fn on_entity_channel_opened(&mut self, local_entity: &LocalEntity) {
         self.entity_waitlist.add_entity(local_entity);
     }
@@ -53,6 +82,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         local_world_manager: &mut LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
         world_events: RemoteWorldEvents<E>,
     ) -> Vec<EntityEvent<E>> {
         self.process_updates(
@@ -60,12 +90,14 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             local_world_manager,
             component_kinds,
             world,
+            validator,
             world_events.incoming_updates,
         );
         self.process_actions(
             global_world_manager,
             local_world_manager,
             world,
+            validator,
             world_events.incoming_actions,
             world_events.incoming_components,
         );
@@ -82,6 +114,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
         incoming_actions: Vec<EntityAction<LocalEntity>>,
         incoming_components: HashMap<(LocalEntity, ComponentKind), Box<dyn Replicate>>,
     ) {
@@ -89,10 +122,11 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             global_world_manager,
             local_world_manager,
             world,
+            validator,
             incoming_actions,
             incoming_components,
         );
-        self.process_waitlist_actions(global_world_manager, local_world_manager, world);
+        self.process_waitlist_actions(global_world_manager, local_world_manager, world, validator);
     }
 
     /// For each [`EntityAction`] that can be executed now,
@@ -102,6 +136,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
         incoming_actions: Vec<EntityAction<LocalEntity>>,
         mut incoming_components: HashMap<(LocalEntity, ComponentKind), Box<dyn Replicate>>,
     ) {
@@ -125,7 +160,13 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                             .remove(&(local_entity, component_kind))
                             .unwrap();
 
-                        self.process_insert(world, world_entity, component, &component_kind);
+                        self.process_insert(
+                            world,
+                            validator,
+                            world_entity,
+                            component,
+                            &component_kind,
+                        );
                     }
                 }
                 EntityAction::DespawnEntity(local_entity) => {
@@ -150,7 +191,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
 
                     let world_entity = local_world_manager.get_world_entity(&local_entity);
 
-                    self.process_insert(world, world_entity, component, &component_kind);
+                    self.process_insert(world, validator, world_entity, component, &component_kind);
                 }
                 EntityAction::RemoveComponent(local_entity, component_kind) => {
                     let world_entity = local_world_manager.get_world_entity(&local_entity);
@@ -166,6 +207,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
     fn process_insert<W: WorldMutType<E>>(
         &mut self,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
         world_entity: E,
         component: Box<dyn Replicate>,
         component_kind: &ComponentKind,
@@ -179,6 +221,9 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             self.insert_waitlist_map
                 .insert((world_entity, *component_kind), handle);
         } else {
+            let Some(component) = Self::validate_insert(validator, &world_entity, component) else {
+                return;
+            };
             world.insert_boxed_component(&world_entity, component);
 
             self.outgoing_events.push(EntityEvent::<E>::InsertComponent(
@@ -188,6 +233,86 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         }
     }
 
+    /// Returns the Component to insert into the World, or None if the insert
+    /// was rejected
+    fn validate_insert(
+        validator: &mut dyn RemoteComponentValidator<E>,
+        world_entity: &E,
+        component: Box<dyn Replicate>,
+    ) -> Option<Box<dyn Replicate>> {
+        if !validator.validates(&component.kind()) {
+            return Some(component);
+        }
+
+        Self::validate_copy(validator, world_entity, None, component.copy_to_box())
+    }
+
+    /// Validates a change made to a host-owned copy of a Component, which the
+    /// validator may clamp, and returns the copy unless the change was
+    /// rejected. Both inserts & updates are validated on copies: an accepted
+    /// insert puts its copy into the World, so the World's Components of
+    /// validated kinds are always host-owned, and accepted updates are
+    /// mirrored into them
+    fn validate_copy(
+        validator: &mut dyn RemoteComponentValidator<E>,
+        world_entity: &E,
+        current: Option<&dyn Replicate>,
+        mut proposed: Box<dyn Replicate>,
+    ) -> Option<Box<dyn Replicate>> {
+        match validator.validate(world_entity, current, proposed.as_mut()) {
+            ValidationResult::Accept | ValidationResult::Clamp => Some(proposed),
+            ValidationResult::Reject => None,
+        }
+    }
+
+    /// Applies an update to a copy of the Component and, if the validator
+    /// allows it, mirrors the result into the World's Component. Returns
+    /// whether the World was changed. The copy is host-owned, so `apply`
+    /// must read the update through the authority read path
+    fn apply_validated_update<W: WorldMutType<E>>(
+        world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
+        world_entity: &E,
+        component_kind: &ComponentKind,
+        apply: impl FnOnce(&mut dyn Replicate) -> Result<(), SerdeErr>,
+    ) -> Result<bool, SerdeErr> {
+        let Some(current) = world
+            .component_of_kind(world_entity, component_kind)
+            .map(|component| component.copy_to_box())
+        else {
+            // the Component's insert was rejected
+            return Ok(false);
+        };
+        let mut proposed = current.copy_to_box();
+        apply(proposed.as_mut())?;
+
+        let Some(accepted) =
+            Self::validate_copy(validator, world_entity, Some(current.as_ref()), proposed)
+        else {
+            return Ok(false);
+        };
+        let mut component = world
+            .component_mut_of_kind(world_entity, component_kind)
+            .expect("Component should exist, was just copied");
+        component.mirror(accepted.as_ref());
+        Ok(true)
+    }
+
+    /// Applies an update to a Component which has been replaced with a
+    /// host-owned copy. Returns whether the World was changed.
+    fn apply_authority_update<W: WorldMutType<E>>(
+        world: &mut W,
+        world_entity: &E,
+        component_kind: &ComponentKind,
+        apply: impl FnOnce(&mut dyn Replicate) -> Result<(), SerdeErr>,
+    ) -> Result<bool, SerdeErr> {
+        let Some(mut component) = world.component_mut_of_kind(world_entity, component_kind) else {
+            return Ok(false);
+        };
+        apply(&mut *component)?;
+        Ok(true)
+    }
+
     fn process_remove<W: WorldMutType<E>>(
         &mut self,
         world: &mut W,
@@ -214,6 +339,8 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             }
             return;
         }
+        self.authority_components
+            .remove(&(world_entity, component_kind));
         // Remove from world
         if let Some(component) = world.remove_component_of_kind(&world_entity, &component_kind) {
             // Send out event
@@ -227,6 +354,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &mut LocalWorldManager<E>,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
     ) {
         let converter = EntityConverter::new(
             global_world_manager.to_global_entity_converter(),
@@ -242,6 +370,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                 self.insert_waitlist_map
                     .remove(&(world_entity, component_kind));
                 component.relations_complete(&converter);
+                let Some(component) = Self::validate_insert(validator, &world_entity, component)
+                else {
+                    continue;
+                };
                 world.insert_boxed_component(&world_entity, component);
 
                 self.outgoing_events.push(EntityEvent::<E>::InsertComponent(
@@ -262,6 +394,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         local_world_manager: &mut LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
         incoming_updates: Vec<(Tick, E, ComponentUpdate)>,
     ) {
         self.process_ready_updates(
@@ -269,9 +402,10 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             local_world_manager,
             component_kinds,
             world,
+            validator,
             incoming_updates,
         );
-        self.process_waitlist_updates(global_world_manager, local_world_manager, world);
+        self.process_waitlist_updates(global_world_manager, local_world_manager, world, validator);
     }
 
     /// Process component updates from raw bits for a given entity
@@ -281,6 +415,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         local_world_manager: &LocalWorldManager<E>,
         component_kinds: &ComponentKinds,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
         mut incoming_updates: Vec<(Tick, E, ComponentUpdate)>,
     ) {
         let converter = EntityConverter::new(
@@ -343,17 +478,40 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
             }
             // if it exists, apply the ready part of the component update
             if let Some(ready_update) = ready_update_opt {
-                if world
-                    .component_apply_update(
-                        &converter,
+                let result = if validator.validates(&component_kind) {
+                    Self::apply_validated_update(
+                        world,
+                        validator,
                         &world_entity,
                         &component_kind,
-                        ready_update,
+                        |component| component.read_apply_authority_update(&converter, ready_update),
                     )
-                    .is_err()
-                {
-                    warn!("Remote World Manager: cannot read malformed component update message");
-                    continue;
+                } else if self.has_authority_component(&world_entity, &component_kind) {
+                    Self::apply_authority_update(
+                        world,
+                        &world_entity,
+                        &component_kind,
+                        |component| component.read_apply_authority_update(&converter, ready_update),
+                    )
+                } else {
+                    world
+                        .component_apply_update(
+                            &converter,
+                            &world_entity,
+                            &component_kind,
+                            ready_update,
+                        )
+                        .map(|_| true)
+                };
+                match result {
+                    Ok(true) => {}
+                    Ok(false) => continue,
+                    Err(_) => {
+                        warn!(
+                            "Remote World Manager: cannot read malformed component update message"
+                        );
+                        continue;
+                    }
                 }
 
                 self.outgoing_events.push(EntityEvent::UpdateComponent(
@@ -370,6 +528,7 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
         global_world_manager: &mut dyn GlobalWorldManagerType<E>,
         local_world_manager: &LocalWorldManager<E>,
         world: &mut W,
+        validator: &mut dyn RemoteComponentValidator<E>,
     ) {
         let converter = EntityConverter::new(
             global_world_manager.to_global_entity_converter(),
@@ -392,17 +551,42 @@ impl<E: Copy + Eq + Hash + Send + Sync> RemoteWorldManager<E> {
                     self.update_waitlist_map.remove(&component_key);
                 }
 
-                if world
-                    .component_apply_field_update(
-                        &converter,
+                let result = if validator.validates(&component_kind) {
+                    Self::apply_validated_update(
+                        world,
+                        validator,
                         &world_entity,
                         &component_kind,
-                        ready_update,
+                        |component| {
+                            component.read_apply_authority_field_update(&converter, ready_update)
+                        },
                     )
-                    .is_err()
-                {
-                    warn!("Remote World Manager: cannot read malformed complete waitlisted component update message");
-                    continue;
+                } else if self.has_authority_component(&world_entity, &component_kind) {
+                    Self::apply_authority_update(
+                        world,
+                        &world_entity,
+                        &component_kind,
+                        |component| {
+                            component.read_apply_authority_field_update(&converter, ready_update)
+                        },
+                    )
+                } else {
+                    world
+                        .component_apply_field_update(
+                            &converter,
+                            &world_entity,
+                            &component_kind,
+                            ready_update,
+                        )
+                        .map(|_| true)
+                };
+                match result {
+                    Ok(true) => {}
+                    Ok(false) => continue,
+                    Err(_) => {
+                        warn!("Remote World Manager: cannot read malformed complete waitlisted component update message");
+                        continue;
+                    }
                 }
 
                 self.outgoing_events.push(EntityEvent::<E>::UpdateComponent(
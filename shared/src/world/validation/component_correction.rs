@@ -0,0 +1,33 @@
+use std::hash::Hash;
+
+use naia_derive::MessageInternal;
+
+use crate::{EntityAndGlobalEntityConverter, EntityProperty};
+
+/// Sent from the Server when it has rejected or clamped a change a Client made
+/// to a Component of one of its Entities. `bytes` holds the ComponentKind,
+/// followed by an update of every Property unless the Component is to be
+/// removed
+#[derive(MessageInternal)]
+pub struct ComponentCorrection {
+    pub entity: EntityProperty,
+    pub remove: bool,
+    pub bytes: Vec<u8>,
+}
+
+impl ComponentCorrection {
+    pub fn new<E: Copy + Eq + Hash>(
+        converter: &dyn EntityAndGlobalEntityConverter<E>,
+        entity: &E,
+        remove: bool,
+        bytes: Vec<u8>,
+    ) -> Self {
+        let mut message = Self {
+            entity: EntityProperty::new(),
+            remove,
+            bytes,
+        };
+        message.entity.set(converter, entity);
+        message
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{ComponentKind, Replicate};
+
+/// The outcome of validating a change a remote host made to a Component
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ValidationResult {
+    /// Apply the change as it was received
+    Accept,
+    /// Apply the change as it was modified by the validator, and send the
+    /// corrected value back to the remote host
+    Clamp,
+    /// Discard the change, and revert it on the remote host
+    Reject,
+}
+
+/// Inspects inserts & updates of Components owned by a remote host before they
+/// are applied to the World
+pub trait RemoteComponentValidator<E> {
+    /// Returns whether Components of the given kind should be validated
+    fn validates(&self, component_kind: &ComponentKind) -> bool;
+    /// Validates a change. `current` is None when the Component is being
+    /// inserted, and `proposed` may be modified in order to clamp the change
+    fn validate(
+        &mut self,
+        entity: &E,
+        current: Option<&dyn Replicate>,
+        proposed: &mut dyn Replicate,
+    ) -> ValidationResult;
+}
+
+/// Validator which accepts every change
+pub struct AcceptAllValidator;
+
+impl<E> RemoteComponentValidator<E> for AcceptAllValidator {
+    fn validates(&self, _component_kind: &ComponentKind) -> bool {
+        false
+    }
+
+    fn validate(
+        &mut self,
+        _entity: &E,
+        _current: Option<&dyn Replicate>,
+        _proposed: &mut dyn Replicate,
+    ) -> ValidationResult {
+        ValidationResult::Accept
+    }
+}
@@ -0,0 +1,3 @@
+pub mod component_correction;
+pub mod component_validator;
+pub mod validation_channel;
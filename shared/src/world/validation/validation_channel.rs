@@ -0,0 +1,22 @@
+use crate::{
+    messages::channels::channel::{ChannelDirection, ChannelMode, ReliableSettings},
+    world::validation::component_correction::ComponentCorrection,
+    Channel, Protocol, ProtocolPlugin,
+};
+
+/// Internal Channel used to send corrections of rejected Client changes back
+/// to the Client
+#[derive(Channel)]
+pub struct ValidationChannel;
+
+pub(crate) struct ValidationPlugin;
+impl ProtocolPlugin for ValidationPlugin {
+    fn build(&self, protocol: &mut Protocol) {
+        protocol
+            .add_channel::<ValidationChannel>(
+                ChannelDirection::ServerToClient,
+                ChannelMode::OrderedReliable(ReliableSettings::default()),
+            )
+            .add_message::<ComponentCorrection>();
+    }
+}
@@ -0,0 +1,86 @@
+mod common;
+
+mod some_replica {
+    use naia_shared::{Property, Replicate};
+
+    #[derive(Replicate)]
+    pub struct Position {
+        pub x: Property<i16>,
+        pub y: Property<i16>,
+    }
+
+    impl Position {
+        pub fn new(x: i16, y: i16) -> Self {
+            Position::new_complete(x, y)
+        }
+    }
+}
+
+use naia_shared::{
+    BitReader, BitWriter, ComponentKinds, DiffMask, FakeEntityConverter, PropertyMutator, Protocol,
+    Replicate,
+};
+
+use common::RecordingMutator;
+
+use some_replica::Position;
+
+/// Writes an update of `y` only, as the Client holding authority would
+fn write_y_update(component_kinds: &ComponentKinds) -> Vec<u8> {
+    let client_position = Position::new(5, 6);
+
+    let mut diff_mask = DiffMask::new(client_position.diff_mask_size());
+    diff_mask.set_bit(1, true);
+
+    let mut writer = BitWriter::new();
+    client_position.kind().ser(component_kinds, &mut writer);
+    client_position.write_update(&diff_mask, &mut writer, &mut FakeEntityConverter);
+    writer.to_bytes().to_vec()
+}
+
+#[test]
+fn host_owned_applies_authority_update() {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_component::<Position>();
+    let component_kinds = protocol.component_kinds;
+
+    // Server's copy, host-owned & tracked
+    let recorder = RecordingMutator::default();
+    let mut server_position = Position::new(0, 0);
+    server_position.set_mutator(&PropertyMutator::new(recorder.clone()));
+
+    // Read
+    let bytes = write_y_update(&component_kinds);
+    let mut reader = BitReader::new(&bytes);
+    let update = component_kinds
+        .read_create_update(&mut reader)
+        .expect("should deserialize correctly");
+    server_position
+        .read_apply_authority_update(&FakeEntityConverter, update)
+        .expect("should apply update correctly");
+
+    assert_eq!(*server_position.x, 0);
+    assert_eq!(*server_position.y, 6);
+
+    // Applied values are queued to be relayed onwards
+    assert_eq!(recorder.mutated(), vec![1]);
+}
+
+#[test]
+#[should_panic(expected = "Host Property should never read.")]
+fn host_owned_refuses_plain_update() {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_component::<Position>();
+    let component_kinds = protocol.component_kinds;
+
+    // only updates from the holder of an Entity's authority apply to host-owned Components
+    let mut server_position = Position::new(0, 0);
+    let bytes = write_y_update(&component_kinds);
+    let mut reader = BitReader::new(&bytes);
+    let update = component_kinds
+        .read_create_update(&mut reader)
+        .expect("should deserialize correctly");
+    let _ = server_position.read_apply_update(&FakeEntityConverter, update);
+}
@@ -0,0 +1,21 @@
+use std::sync::{Arc, Mutex};
+
+use naia_shared::PropertyMutate;
+
+/// Records the indices of the Properties it is told have mutated
+#[derive(Clone, Default)]
+pub struct RecordingMutator {
+    mutated: Arc<Mutex<Vec<u8>>>,
+}
+
+impl RecordingMutator {
+    pub fn mutated(&self) -> Vec<u8> {
+        self.mutated.lock().unwrap().clone()
+    }
+}
+
+impl PropertyMutate for RecordingMutator {
+    fn mutate(&mut self, property_index: u8) {
+        self.mutated.lock().unwrap().push(property_index);
+    }
+}
@@ -0,0 +1,245 @@
+mod some_encodings {
+    use naia_shared::{BitReader, BitWrite, Serde, SerdeErr};
+
+    /// Writes a u32 as a single bit, true for any non-zero value
+    pub mod as_bool {
+        use super::*;
+
+        pub fn ser(value: &u32, writer: &mut dyn BitWrite) {
+            (*value != 0).ser(writer);
+        }
+
+        pub fn de(reader: &mut BitReader) -> Result<u32, SerdeErr> {
+            Ok(u32::from(bool::de(reader)?))
+        }
+
+        pub fn bit_length(_value: &u32) -> u32 {
+            1
+        }
+    }
+
+    pub fn seven() -> u8 {
+        7
+    }
+}
+
+mod some_struct {
+    use naia_shared::Serde;
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeStruct {
+        #[naia(bits = 10)]
+        pub some_bits: u16,
+        #[naia(varint)]
+        pub some_varint: i64,
+        #[naia(with = "crate::some_encodings::as_bool")]
+        pub some_with: u32,
+        #[naia(skip)]
+        pub some_skipped: String,
+        #[naia(skip, default = "crate::some_encodings::seven")]
+        pub some_defaulted: u8,
+    }
+}
+
+mod some_tuple_struct {
+    use naia_shared::Serde;
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub struct SomeTupleStruct(#[naia(bits = 3)] pub u8, pub bool);
+}
+
+mod some_enum {
+    use naia_shared::Serde;
+
+    #[derive(Clone, Debug, PartialEq, Serde)]
+    pub enum SomeEnum {
+        Named {
+            #[naia(bits = 5)]
+            value: u8,
+        },
+        Unnamed(#[naia(varint)] u64),
+    }
+}
+
+mod some_message {
+    use naia_shared::Message;
+
+    #[derive(Message)]
+    pub struct SomeMessage {
+        #[naia(bits = 4)]
+        pub some_bits: u8,
+        #[naia(skip)]
+        pub some_skipped: u32,
+    }
+}
+
+mod some_replica {
+    use naia_shared::{Property, Replicate};
+
+    #[derive(Replicate)]
+    pub struct SomeReplica {
+        #[naia(bits = 7)]
+        pub some_bits: Property<u8>,
+        #[naia(with = "crate::some_encodings::as_bool")]
+        pub some_with: Property<u32>,
+        #[naia(skip, default = "crate::some_encodings::seven")]
+        pub some_local: u8,
+    }
+
+    impl SomeReplica {
+        pub fn new(some_bits: u8, some_with: u32, some_local: u8) -> Self {
+            SomeReplica::new_complete(some_bits, some_with, some_local)
+        }
+    }
+}
+
+use naia_shared::{BitReader, BitWriter, FakeEntityConverter, Message, Protocol, Replicate, Serde};
+
+use some_enum::SomeEnum;
+use some_message::SomeMessage;
+use some_replica::SomeReplica;
+use some_struct::SomeStruct;
+use some_tuple_struct::SomeTupleStruct;
+
+#[test]
+fn read_write_struct_with_attributes() {
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeStruct {
+        some_bits: 1000,
+        some_varint: -3,
+        some_with: 1,
+        some_skipped: "not sent".to_string(),
+        some_defaulted: 12,
+    };
+
+    in_1.ser(&mut writer);
+
+    // 10 + (1 sign bit + 8) + 1
+    assert_eq!(in_1.bit_length(), 20);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1: SomeStruct = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(out_1.some_bits, 1000);
+    assert_eq!(out_1.some_varint, -3);
+    assert_eq!(out_1.some_with, 1);
+    assert_eq!(out_1.some_skipped, String::new());
+    assert_eq!(out_1.some_defaulted, 7);
+}
+
+#[test]
+fn read_write_tuple_struct_with_attributes() {
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeTupleStruct(5, true);
+
+    in_1.ser(&mut writer);
+
+    assert_eq!(in_1.bit_length(), 4);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(in_1, out_1);
+}
+
+#[test]
+fn read_write_enum_with_attributes() {
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeEnum::Named { value: 31 };
+    let in_2 = SomeEnum::Unnamed(100_000);
+
+    in_1.ser(&mut writer);
+    in_2.ser(&mut writer);
+
+    // 2 bits for the variant index
+    assert_eq!(in_1.bit_length(), 7);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = Serde::de(&mut reader).unwrap();
+    let out_2 = Serde::de(&mut reader).unwrap();
+
+    assert_eq!(in_1, out_1);
+    assert_eq!(in_2, out_2);
+}
+
+#[test]
+fn read_write_message_with_attributes() {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_message::<SomeMessage>();
+    let message_kinds = protocol.message_kinds;
+
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeMessage {
+        some_bits: 9,
+        some_skipped: 42,
+    };
+
+    in_1.write(&message_kinds, &mut writer, &mut FakeEntityConverter);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = message_kinds
+        .read(&mut reader, &FakeEntityConverter)
+        .expect("should deserialize correctly")
+        .to_boxed_any()
+        .downcast::<SomeMessage>()
+        .unwrap();
+
+    assert_eq!(out_1.some_bits, 9);
+    assert_eq!(out_1.some_skipped, 0);
+}
+
+#[test]
+fn read_write_replica_with_attributes() {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_component::<SomeReplica>();
+    let component_kinds = protocol.component_kinds;
+
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = SomeReplica::new(100, 5, 12);
+
+    in_1.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = component_kinds
+        .read(&mut reader, &FakeEntityConverter)
+        .expect("should deserialize correctly")
+        .to_boxed_any()
+        .downcast::<SomeReplica>()
+        .unwrap();
+
+    assert_eq!(*out_1.some_bits, 100);
+    assert_eq!(*out_1.some_with, 1);
+    assert_eq!(out_1.some_local, 7);
+}
@@ -0,0 +1,176 @@
+mod common;
+
+mod some_nested {
+    use naia_shared::{NestedProperty, NestedReplicate, Property};
+
+    #[derive(NestedReplicate)]
+    pub struct Inner {
+        pub a: Property<u8>,
+        pub b: Property<u16>,
+    }
+
+    impl Inner {
+        pub fn new(a: u8, b: u16) -> Self {
+            Inner::new_complete(a, b)
+        }
+    }
+
+    #[derive(NestedReplicate)]
+    pub struct Middle {
+        pub inner: NestedProperty<Inner>,
+        pub c: Property<bool>,
+    }
+
+    impl Middle {
+        pub fn new(inner: Inner, c: bool) -> Self {
+            Middle::new_complete(inner, c)
+        }
+    }
+}
+
+mod some_replica {
+    use naia_shared::{NestedProperty, Property, Replicate};
+
+    use crate::some_nested::Middle;
+
+    #[derive(Replicate)]
+    pub struct NestedReplica {
+        pub before: Property<u32>,
+        pub middle: NestedProperty<Middle>,
+        pub after: Property<String>,
+    }
+
+    impl NestedReplica {
+        pub fn new(before: u32, middle: Middle, after: String) -> Self {
+            NestedReplica::new_complete(before, middle, after)
+        }
+    }
+}
+
+use naia_shared::{
+    BitReader, BitWriter, ComponentKind, DiffMask, FakeEntityConverter, NestedReplicate,
+    PropertyMutator, Protocol, Replicate,
+};
+
+use common::RecordingMutator;
+
+use some_nested::{Inner, Middle};
+use some_replica::NestedReplica;
+
+#[test]
+fn property_count() {
+    assert_eq!(Inner::PROPERTY_COUNT, 2);
+    assert_eq!(Middle::PROPERTY_COUNT, 3);
+
+    let replica = NestedReplica::new(1, Middle::new(Inner::new(2, 3), true), "4".to_string());
+    // 1 + 3 + 1 bits
+    assert_eq!(replica.diff_mask_size(), 1);
+}
+
+#[test]
+fn nested_mutation_offsets() {
+    let recorder = RecordingMutator::default();
+
+    let mut replica = NestedReplica::new(1, Middle::new(Inner::new(2, 3), true), "4".to_string());
+    replica.set_mutator(&PropertyMutator::new(recorder.clone()));
+
+    *replica.before = 5;
+    *replica.middle.inner.a = 6;
+    *replica.middle.inner.b = 7;
+    *replica.middle.c = false;
+    *replica.after = "8".to_string();
+
+    assert_eq!(recorder.mutated(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn read_write_nested() {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_component::<NestedReplica>();
+    let component_kinds = protocol.component_kinds;
+
+    // Write
+    let mut writer = BitWriter::new();
+
+    let in_1 = NestedReplica::new(
+        10,
+        Middle::new(Inner::new(20, 300), true),
+        "hello".to_string(),
+    );
+
+    in_1.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
+
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+
+    let out_1 = component_kinds
+        .read(&mut reader, &FakeEntityConverter)
+        .expect("should deserialize correctly")
+        .to_boxed_any()
+        .downcast::<NestedReplica>()
+        .unwrap();
+
+    assert_eq!(*out_1.before, 10);
+    assert_eq!(*out_1.middle.inner.a, 20);
+    assert_eq!(*out_1.middle.inner.b, 300);
+    assert!(*out_1.middle.c);
+    assert_eq!(*out_1.after, "hello");
+}
+
+#[test]
+fn read_write_nested_update() {
+    // Protocol
+    let mut protocol = Protocol::builder();
+    protocol.add_component::<NestedReplica>();
+    let component_kinds = protocol.component_kinds;
+
+    let in_1 = NestedReplica::new(
+        10,
+        Middle::new(Inner::new(20, 300), true),
+        "hello".to_string(),
+    );
+    // Remote copy, created from a full write
+    let mut out_1 = {
+        let initial = NestedReplica::new(0, Middle::new(Inner::new(0, 0), false), String::new());
+        let mut writer = BitWriter::new();
+        initial.write(&component_kinds, &mut writer, &mut FakeEntityConverter);
+        let bytes = writer.to_bytes();
+        let mut reader = BitReader::new(&bytes);
+        component_kinds
+            .read(&mut reader, &FakeEntityConverter)
+            .expect("should deserialize correctly")
+            .to_boxed_any()
+            .downcast::<NestedReplica>()
+            .unwrap()
+    };
+
+    // Only `middle.inner.b` has changed, which is bit 0 + 1 + 1
+    let mut diff_mask = DiffMask::new(in_1.diff_mask_size());
+    diff_mask.set_bit(2, true);
+
+    // Write
+    let mut writer = BitWriter::new();
+    in_1.kind().ser(&component_kinds, &mut writer);
+    in_1.write_update(&diff_mask, &mut writer, &mut FakeEntityConverter);
+    let bytes = writer.to_bytes();
+
+    // Read
+    let mut reader = BitReader::new(&bytes);
+    let update = component_kinds
+        .read_create_update(&mut reader)
+        .expect("should deserialize correctly");
+    assert!(update.kind == ComponentKind::of::<NestedReplica>());
+
+    out_1
+        .read_apply_update(&FakeEntityConverter, update)
+        .expect("should apply update correctly");
+
+    assert_eq!(*out_1.before, 0);
+    assert_eq!(*out_1.middle.inner.a, 0);
+    assert_eq!(*out_1.middle.inner.b, 300);
+    assert!(!*out_1.middle.c);
+    assert_eq!(*out_1.after, "");
+}
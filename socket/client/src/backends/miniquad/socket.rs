@@ -3,8 +3,11 @@ use std::collections::VecDeque;
 use naia_socket_shared::{parse_server_url, SocketConfig};
 
 use crate::{
-    backends::socket::SocketTrait, conditioned_packet_receiver::ConditionedPacketReceiver,
-    packet_receiver::PacketReceiver, packet_sender::PacketSender,
+    backends::socket::SocketTrait,
+    conditioned_packet_receiver::ConditionedPacketReceiver,
+    conditioned_packet_sender::{ConditionedPacketSender, OutgoingConditioner},
+    packet_receiver::PacketReceiver,
+    packet_sender::PacketSender,
 };
 
 use super::{
@@ -34,20 +37,26 @@ impl Socket {
             );
         }
 
-        let conditioner_config = config.link_condition.clone();
+        let conditioner_schedule = config.conditioner_schedule();
 
-        // setup sender
-        let packet_sender: Box<dyn PacketSender> = Box::new(PacketSenderImpl);
+        // setup sender & receiver
+        let inner_sender: Box<dyn PacketSender> = Box::new(PacketSenderImpl);
+        let inner_receiver: Box<dyn PacketReceiver> = Box::new(PacketReceiverImpl::new());
 
-        // setup receiver
-        let packet_receiver: Box<dyn PacketReceiver> = {
-            let inner_receiver = Box::new(PacketReceiverImpl::new());
-            if let Some(config) = &conditioner_config {
-                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+        let (packet_sender, packet_receiver): (Box<dyn PacketSender>, Box<dyn PacketReceiver>) =
+            if let Some(schedule) = &conditioner_schedule {
+                let outgoing = OutgoingConditioner::new(inner_sender, schedule);
+                (
+                    Box::new(ConditionedPacketSender::new(outgoing.clone())),
+                    Box::new(ConditionedPacketReceiver::new(
+                        inner_receiver,
+                        schedule,
+                        outgoing,
+                    )),
+                )
             } else {
-                inner_receiver
-            }
-        };
+                (inner_sender, inner_receiver)
+            };
 
         return (packet_sender, packet_receiver);
     }
@@ -5,6 +5,7 @@ use webrtc_unreliable_client::Socket as RTCSocket;
 use crate::{
     backends::{native::runtime::get_runtime, socket::SocketTrait},
     conditioned_packet_receiver::ConditionedPacketReceiver,
+    conditioned_packet_sender::{ConditionedPacketSender, OutgoingConditioner},
     packet_receiver::PacketReceiver,
     packet_sender::PacketSender,
 };
@@ -26,25 +27,31 @@ impl Socket {
             parse_server_url(server_session_url),
             config.rtc_endpoint_path.clone()
         );
-        let conditioner_config = config.link_condition.clone();
+        let conditioner_schedule = config.conditioner_schedule();
 
         let (socket, io) = RTCSocket::new();
         get_runtime().spawn(async move { socket.connect(&server_session_string).await });
 
-        // Setup Packet Sender
+        // Setup Packet Sender & Receiver
         let packet_sender_impl = PacketSenderImpl::new(io.addr_cell.clone(), io.to_server_sender);
-        let packet_sender: Box<dyn PacketSender> = Box::new(packet_sender_impl);
-
-        // Setup Packet Receiver
         let packet_receiver_impl = PacketReceiverImpl::new(io.addr_cell, io.to_client_receiver);
-        let packet_receiver: Box<dyn PacketReceiver> = {
-            let inner_receiver = Box::new(packet_receiver_impl);
-            if let Some(config) = &conditioner_config {
-                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+        let inner_sender: Box<dyn PacketSender> = Box::new(packet_sender_impl);
+        let inner_receiver: Box<dyn PacketReceiver> = Box::new(packet_receiver_impl);
+
+        let (packet_sender, packet_receiver): (Box<dyn PacketSender>, Box<dyn PacketReceiver>) =
+            if let Some(schedule) = &conditioner_schedule {
+                let outgoing = OutgoingConditioner::new(inner_sender, schedule);
+                (
+                    Box::new(ConditionedPacketSender::new(outgoing.clone())),
+                    Box::new(ConditionedPacketReceiver::new(
+                        inner_receiver,
+                        schedule,
+                        outgoing,
+                    )),
+                )
             } else {
-                inner_receiver
-            }
-        };
+                (inner_sender, inner_receiver)
+            };
 
         return (packet_sender, packet_receiver);
     }
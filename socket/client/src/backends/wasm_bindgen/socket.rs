@@ -1,8 +1,11 @@
 use naia_socket_shared::SocketConfig;
 
 use crate::{
-    backends::socket::SocketTrait, conditioned_packet_receiver::ConditionedPacketReceiver,
-    packet_receiver::PacketReceiver, packet_sender::PacketSender,
+    backends::socket::SocketTrait,
+    conditioned_packet_receiver::ConditionedPacketReceiver,
+    conditioned_packet_sender::{ConditionedPacketSender, OutgoingConditioner},
+    packet_receiver::PacketReceiver,
+    packet_sender::PacketSender,
 };
 
 use super::{
@@ -46,22 +49,26 @@ impl Socket {
         addr_cell: &AddrCell,
         data_port: &DataPort,
     ) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>) {
-        // Setup Packet Sender
-        let packet_sender_impl = PacketSenderImpl::new(&data_port, addr_cell);
+        // Setup Packet Sender & Receiver
+        let inner_sender: Box<dyn PacketSender> =
+            Box::new(PacketSenderImpl::new(&data_port, addr_cell));
+        let inner_receiver: Box<dyn PacketReceiver> =
+            Box::new(PacketReceiverImpl::new(&data_port, addr_cell));
 
-        let packet_sender: Box<dyn PacketSender> = Box::new(packet_sender_impl);
-
-        // Setup Packet Receiver
-        let packet_receiver_impl = PacketReceiverImpl::new(&data_port, addr_cell);
-
-        let packet_receiver: Box<dyn PacketReceiver> = {
-            let inner_receiver = Box::new(packet_receiver_impl);
-            if let Some(config) = &config.link_condition {
-                Box::new(ConditionedPacketReceiver::new(inner_receiver, config))
+        let (packet_sender, packet_receiver): (Box<dyn PacketSender>, Box<dyn PacketReceiver>) =
+            if let Some(schedule) = &config.conditioner_schedule() {
+                let outgoing = OutgoingConditioner::new(inner_sender, schedule);
+                (
+                    Box::new(ConditionedPacketSender::new(outgoing.clone())),
+                    Box::new(ConditionedPacketReceiver::new(
+                        inner_receiver,
+                        schedule,
+                        outgoing,
+                    )),
+                )
             } else {
-                inner_receiver
-            }
-        };
+                (inner_sender, inner_receiver)
+            };
 
         return (packet_sender, packet_receiver);
     }
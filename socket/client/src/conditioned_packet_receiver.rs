@@ -1,35 +1,49 @@
-use naia_socket_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use naia_socket_shared::{
+    link_condition_logic::{self, LinkState},
+    Instant, LinkConditionerSchedule, TimeQueue,
+};
 
 use super::{
-    error::NaiaClientSocketError, packet_receiver::PacketReceiver, server_addr::ServerAddr,
+    conditioned_packet_sender::OutgoingConditioner, error::NaiaClientSocketError,
+    packet_receiver::PacketReceiver, server_addr::ServerAddr,
 };
 
 /// Used to receive packets from the Client Socket
 #[derive(Clone)]
 pub struct ConditionedPacketReceiver {
     inner_receiver: Box<dyn PacketReceiver>,
-    link_conditioner_config: LinkConditionerConfig,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_state: LinkState,
     time_queue: TimeQueue<Box<[u8]>>,
     last_payload: Option<Box<[u8]>>,
+    outgoing: OutgoingConditioner,
 }
 
 impl ConditionedPacketReceiver {
-    /// Creates a new ConditionedPacketReceiver
+    /// Creates a new ConditionedPacketReceiver, which also sends the packets
+    /// held by the given OutgoingConditioner as they become due
     pub fn new(
         inner_receiver: Box<dyn PacketReceiver>,
-        link_conditioner_config: &LinkConditionerConfig,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+        outgoing: OutgoingConditioner,
     ) -> Self {
         ConditionedPacketReceiver {
             inner_receiver,
-            link_conditioner_config: link_conditioner_config.clone(),
+            link_conditioner_schedule: link_conditioner_schedule.clone(),
+            started: Instant::now(),
+            link_state: LinkState::new(),
             time_queue: TimeQueue::new(),
             last_payload: None,
+            outgoing,
         }
     }
 }
 
 impl PacketReceiver for ConditionedPacketReceiver {
     fn receive(&mut self) -> Result<Option<&[u8]>, NaiaClientSocketError> {
+        self.outgoing.flush();
+
         loop {
             match self.inner_receiver.receive() {
                 Ok(option) => match option {
@@ -37,10 +51,14 @@ impl PacketReceiver for ConditionedPacketReceiver {
                         break;
                     }
                     Some(payload) => {
-                        link_condition_logic::process_packet(
-                            &self.link_conditioner_config,
+                        link_condition_logic::process_incoming_packet(
+                            &self
+                                .link_conditioner_schedule
+                                .config_at(self.started.elapsed()),
+                            &mut self.link_state,
                             &mut self.time_queue,
                             payload.into(),
+                            payload.len(),
                         );
                     }
                 },
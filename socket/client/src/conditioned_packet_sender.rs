@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+
+use naia_socket_shared::{
+    link_condition_logic::{self, LinkState},
+    Instant, LinkConditionerSchedule, TimeQueue,
+};
+
+use super::{error::NaiaClientSocketError, packet_sender::PacketSender, server_addr::ServerAddr};
+
+/// Holds outgoing packets until the time the link conditioner sends them
+#[derive(Clone)]
+pub struct OutgoingConditioner {
+    inner: Arc<Mutex<OutgoingConditionerInner>>,
+}
+
+struct OutgoingConditionerInner {
+    inner_sender: Box<dyn PacketSender>,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_state: LinkState,
+    time_queue: TimeQueue<Box<[u8]>>,
+}
+
+impl OutgoingConditioner {
+    /// Creates a new OutgoingConditioner
+    pub fn new(
+        inner_sender: Box<dyn PacketSender>,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(OutgoingConditionerInner {
+                inner_sender,
+                link_conditioner_schedule: link_conditioner_schedule.clone(),
+                started: Instant::now(),
+                link_state: LinkState::new(),
+                time_queue: TimeQueue::new(),
+            })),
+        }
+    }
+
+    /// Sends every held packet which has become due. Packets which fail to
+    /// send are lost, as they would be on a real link
+    pub fn flush(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        while let Some(payload) = inner.time_queue.pop_item() {
+            let _ = inner.inner_sender.send(&payload);
+        }
+    }
+}
+
+/// Used to send packets from the Client Socket through the link conditioner
+#[derive(Clone)]
+pub struct ConditionedPacketSender {
+    outgoing: OutgoingConditioner,
+}
+
+impl ConditionedPacketSender {
+    /// Creates a new ConditionedPacketSender
+    pub fn new(outgoing: OutgoingConditioner) -> Self {
+        Self { outgoing }
+    }
+}
+
+impl PacketSender for ConditionedPacketSender {
+    fn send(&self, payload: &[u8]) -> Result<(), NaiaClientSocketError> {
+        {
+            let mut guard = self.outgoing.inner.lock().unwrap();
+            let inner = &mut *guard;
+            link_condition_logic::process_outgoing_packet(
+                &inner
+                    .link_conditioner_schedule
+                    .config_at(inner.started.elapsed()),
+                &mut inner.link_state,
+                &mut inner.time_queue,
+                payload.into(),
+                payload.len(),
+            );
+        }
+        self.outgoing.flush();
+        Ok(())
+    }
+
+    /// Get the Server's Socket address
+    fn server_addr(&self) -> ServerAddr {
+        self.outgoing
+            .inner
+            .lock()
+            .unwrap()
+            .inner_sender
+            .server_addr()
+    }
+}
@@ -17,6 +17,7 @@ cfg_if! {
 
 mod backends;
 mod conditioned_packet_receiver;
+mod conditioned_packet_sender;
 mod error;
 mod packet_receiver;
 mod packet_sender;
@@ -6,11 +6,13 @@ use webrtc_unreliable::{
     MessageResult, MessageType, SendError, Server as InnerRtcServer, SessionEndpoint,
 };
 
-use naia_socket_shared::{parse_server_url, url_to_socket_addr, SocketConfig};
+use naia_socket_shared::{parse_server_url, url_to_socket_addr};
 
-use crate::{error::NaiaServerSocketError, server_addrs::ServerAddrs};
-
-use super::session::start_session_server;
+use crate::{
+    error::NaiaServerSocketError,
+    server_addrs::ServerAddrs,
+    signaling::{SessionAnswerer, Signaling},
+};
 
 const CLIENT_CHANNEL_SIZE: usize = 8;
 
@@ -25,7 +27,7 @@ pub struct Socket {
 
 impl Socket {
     /// Returns a new ServerSocket, listening at the given socket address
-    pub async fn listen(server_addrs: ServerAddrs, config: SocketConfig) -> Self {
+    pub async fn listen(server_addrs: ServerAddrs, signaling: Box<dyn Signaling>) -> Self {
         let (to_client_sender, to_client_receiver) = mpsc::channel(CLIENT_CHANNEL_SIZE);
 
         let rtc_server = RtcServer::new(
@@ -40,7 +42,7 @@ impl Socket {
             to_client_receiver,
         };
 
-        start_session_server(server_addrs, config, socket.rtc_server.session_endpoint());
+        signaling.start(SessionAnswerer::new(socket.rtc_server.session_endpoint()));
 
         socket
     }
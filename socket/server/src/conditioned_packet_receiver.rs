@@ -1,46 +1,68 @@
-use std::net::SocketAddr;
+use std::{collections::HashMap, net::SocketAddr};
 
 use smol::channel::Receiver;
 
-use naia_socket_shared::{link_condition_logic, LinkConditionerConfig, TimeQueue};
+use naia_socket_shared::{
+    link_condition_logic::{self, LinkState},
+    Instant, LinkConditionerSchedule, TimeQueue,
+};
 
-use super::{error::NaiaServerSocketError, packet_receiver::PacketReceiver};
+use super::{
+    conditioned_packet_sender::OutgoingConditioner, error::NaiaServerSocketError,
+    packet_receiver::PacketReceiver,
+};
 
 /// Used to receive packets from the Server Socket
 #[derive(Clone)]
 pub struct ConditionedPacketReceiverImpl {
     #[allow(clippy::type_complexity)]
     channel_receiver: Receiver<Result<(SocketAddr, Box<[u8]>), NaiaServerSocketError>>,
-    link_conditioner_config: LinkConditionerConfig,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_states: HashMap<SocketAddr, LinkState>,
     time_queue: TimeQueue<(SocketAddr, Box<[u8]>)>,
     last_payload: Option<Box<[u8]>>,
+    outgoing: OutgoingConditioner,
 }
 
 impl ConditionedPacketReceiverImpl {
-    /// Creates a new PacketReceiver
+    /// Creates a new PacketReceiver, which also sends the packets held by the
+    /// given OutgoingConditioner as they become due
     #[allow(clippy::type_complexity)]
     pub fn new(
         channel_receiver: Receiver<Result<(SocketAddr, Box<[u8]>), NaiaServerSocketError>>,
-        link_conditioner_config: &LinkConditionerConfig,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+        outgoing: OutgoingConditioner,
     ) -> Self {
         ConditionedPacketReceiverImpl {
             channel_receiver,
-            link_conditioner_config: link_conditioner_config.clone(),
+            link_conditioner_schedule: link_conditioner_schedule.clone(),
+            started: Instant::now(),
+            link_states: HashMap::new(),
             time_queue: TimeQueue::new(),
             last_payload: None,
+            outgoing,
         }
     }
 }
 
 impl PacketReceiver for ConditionedPacketReceiverImpl {
     fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, NaiaServerSocketError> {
+        self.outgoing.flush();
+
         while let Ok(result) = self.channel_receiver.try_recv() {
             match result {
                 Ok(packet) => {
-                    link_condition_logic::process_packet(
-                        &self.link_conditioner_config,
+                    let packet_size = packet.1.len();
+                    let link_state = self.link_states.entry(packet.0).or_default();
+                    link_condition_logic::process_incoming_packet(
+                        &self
+                            .link_conditioner_schedule
+                            .config_at(self.started.elapsed()),
+                        link_state,
                         &mut self.time_queue,
                         packet,
+                        packet_size,
                     );
                 }
                 Err(_) => {
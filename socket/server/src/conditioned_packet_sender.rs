@@ -0,0 +1,153 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use naia_socket_shared::{
+    link_condition_logic::{self, LinkState},
+    Instant, LinkConditionerSchedule, TimeQueue,
+};
+
+use super::{error::NaiaServerSocketError, packet_sender::PacketSender};
+
+/// Holds outgoing packets until the time the link conditioner sends them
+#[derive(Clone)]
+pub struct OutgoingConditioner {
+    inner: Arc<Mutex<OutgoingConditionerInner>>,
+}
+
+struct OutgoingConditionerInner {
+    inner_sender: Box<dyn PacketSender>,
+    link_conditioner_schedule: LinkConditionerSchedule,
+    started: Instant,
+    link_states: HashMap<SocketAddr, LinkState>,
+    time_queue: TimeQueue<(SocketAddr, Box<[u8]>)>,
+}
+
+impl OutgoingConditioner {
+    /// Creates a new OutgoingConditioner
+    pub fn new(
+        inner_sender: Box<dyn PacketSender>,
+        link_conditioner_schedule: &LinkConditionerSchedule,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(OutgoingConditionerInner {
+                inner_sender,
+                link_conditioner_schedule: link_conditioner_schedule.clone(),
+                started: Instant::now(),
+                link_states: HashMap::new(),
+                time_queue: TimeQueue::new(),
+            })),
+        }
+    }
+
+    /// Sends every held packet which has become due. Packets which fail to
+    /// send are lost, as they would be on a real link
+    pub fn flush(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        while let Some((address, payload)) = inner.time_queue.pop_item() {
+            let _ = inner.inner_sender.send(&address, &payload);
+        }
+    }
+}
+
+/// Used to send packets from the Server Socket through the link conditioner
+#[derive(Clone)]
+pub struct ConditionedPacketSenderImpl {
+    outgoing: OutgoingConditioner,
+}
+
+impl ConditionedPacketSenderImpl {
+    /// Creates a new ConditionedPacketSender
+    pub fn new(outgoing: OutgoingConditioner) -> Self {
+        Self { outgoing }
+    }
+}
+
+impl PacketSender for ConditionedPacketSenderImpl {
+    /// Sends a packet to the Server Socket
+    fn send(&self, address: &SocketAddr, payload: &[u8]) -> Result<(), NaiaServerSocketError> {
+        {
+            let mut guard = self.outgoing.inner.lock().unwrap();
+            let inner = &mut *guard;
+            let link_state = inner.link_states.entry(*address).or_default();
+            link_condition_logic::process_outgoing_packet(
+                &inner
+                    .link_conditioner_schedule
+                    .config_at(inner.started.elapsed()),
+                link_state,
+                &mut inner.time_queue,
+                (*address, payload.into()),
+                payload.len(),
+            );
+        }
+        self.outgoing.flush();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, thread::sleep, time::Duration};
+
+    use smol::channel;
+
+    use naia_socket_shared::{LinkConditionerConfig, LinkConditionerSchedule};
+
+    use super::{ConditionedPacketSenderImpl, OutgoingConditioner};
+    use crate::packet_sender::{PacketSender, PacketSenderImpl};
+
+    #[allow(clippy::type_complexity)]
+    fn conditioned_sender(
+        config: LinkConditionerConfig,
+    ) -> (
+        ConditionedPacketSenderImpl,
+        OutgoingConditioner,
+        channel::Receiver<(SocketAddr, Box<[u8]>)>,
+    ) {
+        let (sender, receiver) = channel::unbounded();
+        let outgoing = OutgoingConditioner::new(
+            Box::new(PacketSenderImpl::new(sender)),
+            &LinkConditionerSchedule::new(config),
+        );
+        (
+            ConditionedPacketSenderImpl::new(outgoing.clone()),
+            outgoing,
+            receiver,
+        )
+    }
+
+    #[test]
+    fn holds_outgoing_packets_until_due() {
+        let (sender, outgoing, receiver) = conditioned_sender(LinkConditionerConfig {
+            outgoing_latency: 50,
+            ..Default::default()
+        });
+        let address: SocketAddr = "127.0.0.1:1234".parse().unwrap();
+
+        sender.send(&address, &[1, 2, 3]).unwrap();
+        assert!(receiver.try_recv().is_err());
+
+        sleep(Duration::from_millis(60));
+        outgoing.flush();
+        let (sent_address, payload) = receiver.try_recv().unwrap();
+        assert_eq!(sent_address, address);
+        assert_eq!(&*payload, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn drops_lost_outgoing_packets() {
+        let (sender, outgoing, receiver) = conditioned_sender(LinkConditionerConfig {
+            outgoing_loss: 1.0,
+            ..Default::default()
+        });
+        let address: SocketAddr = "127.0.0.1:1234".parse().unwrap();
+
+        for _ in 0..10 {
+            sender.send(&address, &[1]).unwrap();
+        }
+        outgoing.flush();
+        assert!(receiver.try_recv().is_err());
+    }
+}
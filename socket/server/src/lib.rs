@@ -15,11 +15,13 @@ extern crate log;
 
 mod async_socket;
 mod conditioned_packet_receiver;
+mod conditioned_packet_sender;
 mod error;
 mod packet_receiver;
 mod packet_sender;
 mod server_addrs;
 mod session;
+mod signaling;
 mod socket;
 
 /// Executor for Server
@@ -30,4 +32,6 @@ pub use naia_socket_shared as shared;
 pub use packet_receiver::PacketReceiver;
 pub use packet_sender::PacketSender;
 pub use server_addrs::ServerAddrs;
+pub use session::{AuthorizeFn, HttpSignaling, HttpSignalingConfig, RateLimit};
+pub use signaling::{SessionAnswerer, Signaling};
 pub use socket::Socket;
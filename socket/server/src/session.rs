@@ -1,240 +1,491 @@
 use std::{
-    net::{TcpListener, TcpStream},
-    pin::Pin,
-    task::{Context, Poll},
+    collections::HashMap,
+    net::{IpAddr, Ipv6Addr, SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
-use async_dup::Arc;
-use futures_core::Stream;
-use http::{header, HeaderValue, Response};
-use log::info;
-use once_cell::sync::OnceCell;
+use http::{header, HeaderValue, Response, StatusCode};
+use log::{info, warn};
 use smol::{
-    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
-    prelude::*,
-    Async,
+    future,
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    Async, Timer,
 };
-use webrtc_unreliable::SessionEndpoint;
 
-use naia_socket_shared::SocketConfig;
+use crate::{
+    executor,
+    signaling::{SessionAnswerer, Signaling},
+};
 
-use crate::{executor, server_addrs::ServerAddrs};
+const DEFAULT_MAX_BODY_SIZE: usize = 16 * 1024;
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_HEAD_SIZE: usize = 8 * 1024;
+/// Rate limit entries are pruned once there are this many
+const RATE_LIMIT_PRUNE_SIZE: usize = 1024;
+/// Most addresses the rate limit keeps track of at once, past this the oldest
+/// window is forgotten
+const RATE_LIMIT_MAX_ADDRESSES: usize = 4 * 1024;
+
+/// Decides whether a session request may proceed, given its headers
+pub type AuthorizeFn = dyn Fn(&[(String, String)]) -> bool + Send + Sync;
+
+/// Configuration of the built-in HTTP signaling server
+#[derive(Clone)]
+pub struct HttpSignalingConfig {
+    /// Origins which browsers may request sessions from. If empty, any origin
+    /// is allowed.
+    pub allowed_origins: Vec<String>,
+    /// Largest session request body accepted, in bytes. Larger requests are
+    /// answered with 413.
+    pub max_body_size: usize,
+    /// Called with the headers of every session request, so that, for
+    /// example, an `Authorization` header can be checked. Requests it refuses
+    /// are answered with 401. Note that the Client's data channel address is
+    /// not known until after signaling, so identifying the User should still
+    /// happen through naia's own auth message.
+    pub authorize: Option<Arc<AuthorizeFn>>,
+    /// Time a Client has to send its whole request, and to receive the
+    /// response. Slower requests are answered with 408.
+    pub request_timeout: Duration,
+    /// Limits how many sessions each IP address may request. Requests over
+    /// the limit are answered with 429. IPv6 addresses are limited by their
+    /// /64 prefix. Off by default, as Clients sharing an address (behind a
+    /// NAT, for example) would share the limit; set it to
+    /// `Some(RateLimit::default())` to allow 10 sessions per minute.
+    pub session_rate_limit: Option<RateLimit>,
+}
 
-static RTC_URL_PATH: OnceCell<String> = OnceCell::new();
+impl Default for HttpSignalingConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            authorize: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            session_rate_limit: None,
+        }
+    }
+}
 
-pub fn start_session_server(
-    server_addrs: ServerAddrs,
-    config: SocketConfig,
-    session_endpoint: SessionEndpoint,
-) {
-    RTC_URL_PATH
-        .set(format!("POST /{}", config.rtc_endpoint_path))
-        .expect("unable to set the URL Path");
-    executor::spawn(async move {
-        listen(server_addrs, config, session_endpoint.clone()).await;
-    })
-    .detach();
+/// Allows up to `max_requests` in every `period`
+#[derive(Clone, Copy)]
+pub struct RateLimit {
+    pub max_requests: u32,
+    pub period: Duration,
 }
 
-/// Listens for incoming connections and serves them.
-async fn listen(
-    server_addrs: ServerAddrs,
-    config: SocketConfig,
-    session_endpoint: SessionEndpoint,
-) {
-    let socket_address = server_addrs.session_listen_addr;
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            max_requests: 10,
+            period: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Counts the requests of each IP address, or IPv6 /64 prefix, over fixed
+/// windows of time
+struct RateLimiter {
+    limit: RateLimit,
+    windows: HashMap<IpAddr, (Instant, u32)>,
+}
+
+impl RateLimiter {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Records a request, returning whether it is within the limit
+    fn allow(&mut self, address: IpAddr, now: Instant) -> bool {
+        let address = rate_limit_key(address);
+        let period = self.limit.period;
+        if self.windows.len() >= RATE_LIMIT_PRUNE_SIZE {
+            self.windows
+                .retain(|_, (start, _)| now.duration_since(*start) < period);
+        }
+        if self.windows.len() >= RATE_LIMIT_MAX_ADDRESSES && !self.windows.contains_key(&address) {
+            let oldest = self
+                .windows
+                .iter()
+                .min_by_key(|(_, (start, _))| *start)
+                .map(|(oldest, _)| *oldest);
+            if let Some(oldest) = oldest {
+                self.windows.remove(&oldest);
+            }
+        }
+
+        let (start, count) = self.windows.entry(address).or_insert((now, 0));
+        if now.duration_since(*start) >= period {
+            *start = now;
+            *count = 0;
+        }
+        if *count >= self.limit.max_requests {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
 
-    let listener = Async::<TcpListener>::bind(socket_address)
-        .expect("unable to bind a TCP Listener to the supplied socket address");
+/// The address a request is counted against. A single IPv6 host usually
+/// controls a whole /64, so those are limited together.
+fn rate_limit_key(address: IpAddr) -> IpAddr {
+    match address {
+        IpAddr::V4(_) => address,
+        IpAddr::V6(address_v6) => {
+            if let Some(address_v4) = address_v6.to_ipv4_mapped() {
+                return IpAddr::V4(address_v4);
+            }
+            let prefix = u128::from(address_v6) & !(u64::MAX as u128);
+            IpAddr::V6(Ipv6Addr::from(prefix))
+        }
+    }
+}
+
+/// A minimal HTTP/1.1 server which answers `POST /<rtc_endpoint_path>`
+/// session requests. Used unless another `Signaling` is supplied.
+pub struct HttpSignaling {
+    listen_addr: SocketAddr,
+    rtc_endpoint_path: String,
+    config: HttpSignalingConfig,
+    rate_limiter: Option<Mutex<RateLimiter>>,
+}
+
+impl HttpSignaling {
+    pub fn new(
+        listen_addr: SocketAddr,
+        rtc_endpoint_path: &str,
+        config: HttpSignalingConfig,
+    ) -> Self {
+        Self {
+            listen_addr,
+            rtc_endpoint_path: format!("/{}", rtc_endpoint_path.trim_start_matches('/')),
+            rate_limiter: config
+                .session_rate_limit
+                .map(|limit| Mutex::new(RateLimiter::new(limit))),
+            config,
+        }
+    }
+}
+
+impl Signaling for HttpSignaling {
+    fn start(self: Box<Self>, answerer: SessionAnswerer) {
+        executor::spawn(async move {
+            listen(Arc::new(*self), answerer).await;
+        })
+        .detach();
+    }
+}
+
+/// Listens for incoming connections and serves them.
+async fn listen(signaling: Arc<HttpSignaling>, answerer: SessionAnswerer) {
+    let listener = match Async::<TcpListener>::bind(signaling.listen_addr) {
+        Ok(listener) => listener,
+        Err(error) => {
+            warn!(
+                "Unable to bind the session server to {}: {}",
+                signaling.listen_addr, error
+            );
+            return;
+        }
+    };
     info!(
-        "Session initiator available at POST http://{}/{}",
-        listener
-            .get_ref()
-            .local_addr()
-            .expect("Listener does not have a local address"),
-        config.rtc_endpoint_path
+        "Session initiator available at POST http://{}{}",
+        signaling.listen_addr, signaling.rtc_endpoint_path
     );
 
     loop {
         // Accept the next connection.
-        let (response_stream, _) = listener
-            .accept()
-            .await
-            .expect("was not able to accept the incoming stream from the listener");
+        let (stream, remote_addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                warn!("Unable to accept a session connection: {}", error);
+                continue;
+            }
+        };
 
-        let session_endpoint_clone = session_endpoint.clone();
+        let signaling = signaling.clone();
+        let answerer = answerer.clone();
 
         // Spawn a background task serving this connection.
         executor::spawn(async move {
-            serve(session_endpoint_clone, Arc::new(response_stream)).await;
+            serve(&signaling, answerer, stream, remote_addr).await;
         })
         .detach();
     }
 }
 
-/// Reads a request from the client and sends it a response.
-async fn serve(mut session_endpoint: SessionEndpoint, mut stream: Arc<Async<TcpStream>>) {
-    let remote_addr = stream
-        .get_ref()
-        .local_addr()
-        .expect("stream does not have a local address");
-    let mut success: bool = false;
-    let mut headers_read: bool = false;
-    let mut content_length: Option<usize> = None;
-    let mut rtc_url_match = false;
-    let mut body: Vec<u8> = Vec::new();
-
-    let buf_reader = BufReader::new(stream.clone());
-    let mut bytes = buf_reader.bytes();
-    {
-        let mut line: Vec<u8> = Vec::new();
-        while let Some(byte) = bytes.next().await {
-            let byte = byte.expect("unable to read a byte from incoming stream");
+/// A parsed HTTP request
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
 
-            if headers_read {
-                if let Some(content_length) = content_length {
-                    body.push(byte);
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
 
-                    if body.len() >= content_length {
-                        success = true;
-                        break;
-                    }
-                } else {
-                    info!("request was missing Content-Length header");
-                    break;
-                }
-            }
+#[derive(Debug, Eq, PartialEq)]
+enum RequestError {
+    /// The connection failed, there is no one to respond to
+    Io,
+    Malformed,
+    TooLarge,
+    TimedOut,
+}
 
-            if byte == b'\r' {
-                continue;
-            } else if byte == b'\n' {
-                let mut str = String::from_utf8(line.clone())
-                    .expect("unable to parse string from UTF-8 bytes");
-                line.clear();
-
-                if rtc_url_match {
-                    if str.to_lowercase().starts_with("content-length: ") {
-                        let (_, last) = str.split_at(16);
-                        str = last.to_string();
-                        content_length = str.parse::<usize>().ok();
-                    } else if str.is_empty() {
-                        headers_read = true;
-                    }
-                } else if str.starts_with(
-                    RTC_URL_PATH
-                        .get()
-                        .expect("unable to retrieve URL path, was it not configured?"),
-                ) {
-                    rtc_url_match = true;
-                }
+/// Reads a request from the client and sends it a response.
+async fn serve(
+    signaling: &HttpSignaling,
+    mut answerer: SessionAnswerer,
+    stream: Async<TcpStream>,
+    remote_addr: SocketAddr,
+) {
+    let timeout = signaling.config.request_timeout;
+    let mut reader = BufReader::new(&stream);
+    let read = read_request(&mut reader, signaling.config.max_body_size);
+    let response = match future::or(read, timed_out(timeout)).await {
+        Ok(request) => respond(signaling, &mut answerer, &request, remote_addr).await,
+        Err(RequestError::Io) => return,
+        Err(RequestError::Malformed) => empty_response(StatusCode::BAD_REQUEST),
+        Err(RequestError::TooLarge) => empty_response(StatusCode::PAYLOAD_TOO_LARGE),
+        Err(RequestError::TimedOut) => {
+            info!("Session request from {} timed out", remote_addr);
+            empty_response(StatusCode::REQUEST_TIMEOUT)
+        }
+    };
+
+    let mut out = response_header_to_vec(&response);
+    out.extend_from_slice(response.body().as_bytes());
+
+    // a Client which never reads its response must not hold the connection
+    let write = async {
+        let mut writer = &stream;
+        writer.write_all(&out).await.map_err(|_| RequestError::Io)?;
+        let _ = writer.flush().await;
+        let _ = writer.close().await;
+        Ok(())
+    };
+    let _ = future::or(write, timed_out(timeout)).await;
+}
+
+async fn timed_out<T>(timeout: Duration) -> Result<T, RequestError> {
+    Timer::after(timeout).await;
+    Err(RequestError::TimedOut)
+}
+
+async fn read_request<R: AsyncBufReadExt + AsyncReadExt + Unpin>(
+    reader: &mut R,
+    max_body_size: usize,
+) -> Result<Request, RequestError> {
+    let mut head_size = 0;
+    let mut lines = Vec::new();
+    loop {
+        let mut line = Vec::new();
+        let read = (&mut *reader)
+            .take((MAX_HEAD_SIZE - head_size) as u64)
+            .read_until(b'\n', &mut line)
+            .await
+            .map_err(|_| RequestError::Io)?;
+        head_size += read;
+        if !line.ends_with(b"\n") {
+            // the stream ended, or the head is larger than allowed
+            return Err(if head_size >= MAX_HEAD_SIZE {
+                RequestError::TooLarge
             } else {
-                line.push(byte);
-            }
+                RequestError::Malformed
+            });
         }
 
-        if success {
-            success = false;
+        let line = String::from_utf8(line).map_err(|_| RequestError::Malformed)?;
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line.to_string());
+    }
 
-            let mut lines = body.lines();
-            let buf = RequestBuffer::new(&mut lines);
+    let mut lines = lines.into_iter();
+    let request_line = lines.next().ok_or(RequestError::Malformed)?;
+    let mut request_parts = request_line.split_whitespace();
+    let method = request_parts.next().ok_or(RequestError::Malformed)?;
+    let path = request_parts.next().ok_or(RequestError::Malformed)?;
+    if !request_parts
+        .next()
+        .is_some_and(|version| version.starts_with("HTTP/1."))
+    {
+        return Err(RequestError::Malformed);
+    }
 
-            match session_endpoint.http_session_request(buf).await {
-                Ok(mut resp) => {
-                    success = true;
+    let mut headers = Vec::new();
+    for line in lines {
+        let (name, value) = line.split_once(':').ok_or(RequestError::Malformed)?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
 
-                    resp.headers_mut().insert(
-                        header::ACCESS_CONTROL_ALLOW_ORIGIN,
-                        HeaderValue::from_static("*"),
-                    );
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+        body: Vec::new(),
+    };
+
+    let content_length = match request.header("Content-Length") {
+        Some(value) => value
+            .parse::<usize>()
+            .map_err(|_| RequestError::Malformed)?,
+        None => 0,
+    };
+    if content_length > max_body_size {
+        return Err(RequestError::TooLarge);
+    }
+    request.body = vec![0; content_length];
+    reader
+        .read_exact(&mut request.body)
+        .await
+        .map_err(|_| RequestError::Io)?;
 
-                    let mut out = response_header_to_vec(&resp);
-                    out.extend_from_slice(resp.body().as_bytes());
+    Ok(request)
+}
 
-                    info!("Successful WebRTC session request from {}", remote_addr);
+async fn respond(
+    signaling: &HttpSignaling,
+    answerer: &mut SessionAnswerer,
+    request: &Request,
+    remote_addr: SocketAddr,
+) -> Response<String> {
+    let path = request.path.split('?').next().unwrap_or_default();
+    if path != signaling.rtc_endpoint_path {
+        return empty_response(StatusCode::NOT_FOUND);
+    }
 
-                    stream
-                        .write_all(&out)
-                        .await
-                        .expect("found an error while writing to a stream");
-                }
-                Err(err) => {
-                    info!(
-                        "Invalid WebRTC session request from {}. Error: {}",
-                        remote_addr, err
-                    );
-                }
+    let allow_origin = match request.header("Origin") {
+        _ if signaling.config.allowed_origins.is_empty() => Some("*".to_string()),
+        Some(origin) => signaling
+            .config
+            .allowed_origins
+            .iter()
+            .find(|allowed| allowed.as_str() == origin)
+            .cloned(),
+        // not a browser, CORS does not apply
+        None => Some("*".to_string()),
+    };
+    let Some(allow_origin) = allow_origin else {
+        info!(
+            "Refused WebRTC session request from {}, its origin is not allowed",
+            remote_addr
+        );
+        return empty_response(StatusCode::FORBIDDEN);
+    };
+
+    let mut response = match request.method.as_str() {
+        // CORS preflight, sent by browsers before requests with custom headers
+        "OPTIONS" => {
+            let mut response = empty_response(StatusCode::NO_CONTENT);
+            let headers = response.headers_mut();
+            headers.insert(
+                header::ACCESS_CONTROL_ALLOW_METHODS,
+                HeaderValue::from_static("POST, OPTIONS"),
+            );
+            if let Some(requested) = request
+                .header("Access-Control-Request-Headers")
+                .and_then(|value| HeaderValue::from_str(value).ok())
+            {
+                headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, requested);
             }
+            response
         }
+        "POST" => answer_session(signaling, answerer, request, remote_addr).await,
+        _ => empty_response(StatusCode::METHOD_NOT_ALLOWED),
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&allow_origin) {
+        response
+            .headers_mut()
+            .insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, value);
     }
-
-    if !success {
-        stream.write_all(RESPONSE_BAD).await.expect("found");
+    if allow_origin != "*" {
+        response
+            .headers_mut()
+            .insert(header::VARY, HeaderValue::from_static("Origin"));
     }
 
-    stream.flush().await.expect("unable to flush the stream");
-    stream.close().await.expect("unable to close the stream");
-}
-
-const RESPONSE_BAD: &[u8] = br#"
-HTTP/1.1 404 NOT FOUND
-Content-Type: text/html
-Content-Length: 0
-Access-Control-Allow-Origin: *
-"#;
-
-struct RequestBuffer<'a, R: AsyncBufRead + Unpin> {
-    buffer: &'a mut Lines<R>,
-    add_newline: bool,
+    response
 }
 
-impl<'a, R: AsyncBufRead + Unpin> RequestBuffer<'a, R> {
-    fn new(buf: &'a mut Lines<R>) -> Self {
-        RequestBuffer {
-            add_newline: false,
-            buffer: buf,
+/// Answers a session request, unless it is rate limited or unauthorized
+async fn answer_session(
+    signaling: &HttpSignaling,
+    answerer: &mut SessionAnswerer,
+    request: &Request,
+    remote_addr: SocketAddr,
+) -> Response<String> {
+    if let Some(rate_limiter) = &signaling.rate_limiter {
+        let allowed = rate_limiter
+            .lock()
+            .expect("rate limiter lock was poisoned")
+            .allow(remote_addr.ip(), Instant::now());
+        if !allowed {
+            info!("Rate limited WebRTC session request from {}", remote_addr);
+            return empty_response(StatusCode::TOO_MANY_REQUESTS);
         }
     }
-}
-
-type ReqError = std::io::Error; //Box<dyn error::Error + Send + Sync>;
-
-const NEWLINE_STR: &str = "\n";
 
-impl<'a, R: AsyncBufRead + Unpin> Stream for RequestBuffer<'a, R> {
-    type Item = Result<String, ReqError>;
+    let authorized = match &signaling.config.authorize {
+        Some(authorize) => authorize(&request.headers),
+        None => true,
+    };
+    if !authorized {
+        info!("Unauthorized WebRTC session request from {}", remote_addr);
+        return empty_response(StatusCode::UNAUTHORIZED);
+    }
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        if self.add_newline {
-            self.add_newline = false;
-            Poll::Ready(Some(Ok(String::from(NEWLINE_STR))))
-        } else {
-            unsafe {
-                let mut_ref = Pin::new_unchecked(&mut self.buffer);
-                match Stream::poll_next(mut_ref, cx) {
-                    Poll::Ready(Some(item)) => {
-                        self.add_newline = true;
-                        Poll::Ready(Some(item))
-                    }
-                    Poll::Ready(None) => Poll::Ready(None),
-                    Poll::Pending => {
-                        // TODO: This could be catastrophic.. I don't understand futures very
-                        // well!
-                        Poll::Ready(None)
-                    }
-                }
-            }
+    match answerer.answer(&request.body).await {
+        Ok(answer) => {
+            info!("Successful WebRTC session request from {}", remote_addr);
+            let mut response = Response::new(answer);
+            response.headers_mut().insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(SessionAnswerer::ANSWER_CONTENT_TYPE),
+            );
+            response
+        }
+        Err(err) => {
+            info!(
+                "Invalid WebRTC session request from {}. Error: {}",
+                remote_addr, err
+            );
+            empty_response(StatusCode::BAD_REQUEST)
         }
     }
 }
 
-fn response_header_to_vec<T>(r: &Response<T>) -> Vec<u8> {
+fn empty_response(status: StatusCode) -> Response<String> {
+    let mut response = Response::new(String::new());
+    *response.status_mut() = status;
+    response
+}
+
+fn response_header_to_vec<T: AsRef<str>>(r: &Response<T>) -> Vec<u8> {
     let v = Vec::with_capacity(120);
     let mut c = std::io::Cursor::new(v);
     write_response_header(r, &mut c).expect("unable to write response header to stream");
     c.into_inner()
 }
 
-fn write_response_header<T>(
+fn write_response_header<T: AsRef<str>>(
     r: &Response<T>,
     mut io: impl std::io::Write,
 ) -> std::io::Result<usize> {
@@ -264,6 +515,239 @@ fn write_response_header<T>(
         w!(b"\r\n");
     }
 
+    if status != StatusCode::NO_CONTENT {
+        w!(b"Content-Length: ");
+        w!(r.body().as_ref().len().to_string().as_bytes());
+        w!(b"\r\n");
+    }
+
     w!(b"\r\n");
     Ok(len)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{IpAddr, Ipv4Addr, Ipv6Addr},
+        pin::Pin,
+        task::{Context, Poll},
+        time::{Duration, Instant},
+    };
+
+    use smol::{future, io::AsyncRead};
+
+    use super::{
+        read_request, timed_out, RateLimit, RateLimiter, RequestError, MAX_HEAD_SIZE,
+        RATE_LIMIT_MAX_ADDRESSES,
+    };
+
+    const MAX_BODY_SIZE: usize = 256;
+    const VALID_REQUEST: &[u8] =
+        b"POST /rtc_session HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\noffer";
+
+    fn parse(bytes: &[u8]) -> Result<super::Request, RequestError> {
+        let mut reader = bytes;
+        smol::block_on(read_request(&mut reader, MAX_BODY_SIZE))
+    }
+
+    /// Deterministic xorshift, so that failures can be reproduced
+    struct Fuzzer(u64);
+
+    impl Fuzzer {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: usize) -> usize {
+            (self.next() % bound as u64) as usize
+        }
+
+        /// Bytes biased towards those which are meaningful to the parser
+        fn byte(&mut self) -> u8 {
+            const INTERESTING: &[u8] = b"\r\n: /0123456789POSTHTP.\xff\x00";
+            if self.below(2) == 0 {
+                INTERESTING[self.below(INTERESTING.len())]
+            } else {
+                self.next() as u8
+            }
+        }
+    }
+
+    fn check_parsed(result: Result<super::Request, RequestError>) {
+        if let Ok(request) = result {
+            assert!(request.body.len() <= MAX_BODY_SIZE);
+            assert!(request.method.len() + request.path.len() < MAX_HEAD_SIZE);
+        }
+    }
+
+    #[test]
+    fn parses_valid_requests() {
+        let request = parse(VALID_REQUEST).expect("request should parse");
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/rtc_session");
+        assert_eq!(request.header("content-length"), Some("5"));
+        assert_eq!(request.body, b"offer");
+    }
+
+    #[test]
+    fn refuses_malformed_requests() {
+        let malformed: &[&[u8]] = &[
+            b"",
+            b"\r\n\r\n",
+            b"POST\r\n\r\n",
+            b"POST /rtc_session\r\n\r\n",
+            b"POST /rtc_session FTP/1.1\r\n\r\n",
+            b"POST /rtc_session HTTP/1.1\r\nno colon\r\n\r\n",
+            b"POST /rtc_session HTTP/1.1\r\nContent-Length: -1\r\n\r\n",
+            b"POST /rtc_session HTTP/1.1\r\nContent-Length: 1e3\r\n\r\n",
+            b"POST /\xff\xfe HTTP/1.1\r\n\r\n",
+            b"POST /rtc_session HTTP/1.1\r\nHost: localhost",
+        ];
+        for bytes in malformed {
+            assert_eq!(parse(bytes).err(), Some(RequestError::Malformed));
+        }
+    }
+
+    #[test]
+    fn refuses_oversized_requests() {
+        let body_too_large = format!(
+            "POST /rtc_session HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_SIZE + 1
+        );
+        assert_eq!(
+            parse(body_too_large.as_bytes()).err(),
+            Some(RequestError::TooLarge)
+        );
+
+        let huge_length =
+            "POST /rtc_session HTTP/1.1\r\nContent-Length: 99999999999999999999\r\n\r\n";
+        assert_eq!(
+            parse(huge_length.as_bytes()).err(),
+            Some(RequestError::Malformed)
+        );
+
+        let mut head_too_large = b"POST /rtc_session HTTP/1.1\r\n".to_vec();
+        while head_too_large.len() <= MAX_HEAD_SIZE {
+            head_too_large.extend_from_slice(b"X-Padding: padding\r\n");
+        }
+        head_too_large.extend_from_slice(b"\r\n");
+        assert_eq!(parse(&head_too_large).err(), Some(RequestError::TooLarge));
+
+        let endless_line = vec![b'a'; MAX_HEAD_SIZE * 2];
+        assert_eq!(parse(&endless_line).err(), Some(RequestError::TooLarge));
+    }
+
+    #[test]
+    fn truncated_body_is_an_io_error() {
+        let truncated = &VALID_REQUEST[..VALID_REQUEST.len() - 2];
+        assert_eq!(parse(truncated).err(), Some(RequestError::Io));
+    }
+
+    #[test]
+    fn fuzz_random_requests() {
+        let mut fuzzer = Fuzzer(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..2000 {
+            let length = fuzzer.below(MAX_HEAD_SIZE + 512);
+            let bytes: Vec<u8> = (0..length).map(|_| fuzzer.byte()).collect();
+            check_parsed(parse(&bytes));
+        }
+    }
+
+    #[test]
+    fn fuzz_mutated_requests() {
+        let mut fuzzer = Fuzzer(0x2545_F491_4F6C_DD1D);
+        for _ in 0..5000 {
+            let mut bytes = VALID_REQUEST.to_vec();
+            for _ in 0..=fuzzer.below(4) {
+                let index = fuzzer.below(bytes.len());
+                match fuzzer.below(4) {
+                    0 => bytes[index] = fuzzer.byte(),
+                    1 => bytes.insert(index, fuzzer.byte()),
+                    2 => {
+                        bytes.remove(index);
+                    }
+                    _ => bytes.truncate(index),
+                }
+                if bytes.is_empty() {
+                    break;
+                }
+            }
+            check_parsed(parse(&bytes));
+        }
+    }
+
+    /// A Client which connects, then never sends anything
+    struct SilentReader;
+
+    impl AsyncRead for SilentReader {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            _: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn silent_clients_time_out() {
+        let mut reader = smol::io::BufReader::new(SilentReader);
+        let read = read_request(&mut reader, MAX_BODY_SIZE);
+        let result = smol::block_on(future::or(read, timed_out(Duration::from_millis(10))));
+        assert_eq!(result.err(), Some(RequestError::TimedOut));
+    }
+
+    #[test]
+    fn rate_limits_each_address() {
+        let mut rate_limiter = RateLimiter::new(RateLimit {
+            max_requests: 2,
+            period: Duration::from_secs(10),
+        });
+        let first = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let second = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let now = Instant::now();
+
+        assert!(rate_limiter.allow(first, now));
+        assert!(rate_limiter.allow(first, now));
+        assert!(!rate_limiter.allow(first, now));
+        assert!(rate_limiter.allow(second, now));
+
+        // the window resets once its period has passed
+        assert!(rate_limiter.allow(first, now + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn rate_limits_ipv6_by_prefix() {
+        let mut rate_limiter = RateLimiter::new(RateLimit {
+            max_requests: 2,
+            period: Duration::from_secs(10),
+        });
+        let first = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1));
+        let same_prefix = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0xffff, 0, 0, 2));
+        let other_prefix = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 2, 0, 0, 0, 1));
+        let now = Instant::now();
+
+        assert!(rate_limiter.allow(first, now));
+        assert!(rate_limiter.allow(same_prefix, now));
+        assert!(!rate_limiter.allow(first, now));
+        assert!(rate_limiter.allow(other_prefix, now));
+    }
+
+    #[test]
+    fn rate_limit_tracks_bounded_addresses() {
+        let mut rate_limiter = RateLimiter::new(RateLimit {
+            max_requests: 1,
+            period: Duration::from_secs(10),
+        });
+        let now = Instant::now();
+
+        for index in 0..(RATE_LIMIT_MAX_ADDRESSES as u32 + 100) {
+            let address = IpAddr::V4(Ipv4Addr::from(index));
+            assert!(rate_limiter.allow(address, now + Duration::from_nanos(index as u64)));
+        }
+        assert_eq!(rate_limiter.windows.len(), RATE_LIMIT_MAX_ADDRESSES);
+    }
+}
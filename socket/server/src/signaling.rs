@@ -0,0 +1,42 @@
+use std::io::Error as IoError;
+
+use futures_util::stream;
+use webrtc_unreliable::SessionEndpoint;
+
+use crate::error::NaiaServerSocketError;
+
+/// Serves the offer/answer exchange which begins every WebRTC session.
+/// Implement this to serve that exchange from your own HTTP stack (or an
+/// existing web server) instead of the built-in `HttpSignaling` server.
+pub trait Signaling: Send {
+    /// Called once, when the Socket starts listening. Every session offer a
+    /// Client sends should be passed to the given `SessionAnswerer`, and the
+    /// resulting answer returned to that Client.
+    fn start(self: Box<Self>, answerer: SessionAnswerer);
+}
+
+/// Answers the session offers of Clients, on behalf of the Socket.
+/// Can be cloned & used from any async runtime.
+#[derive(Clone)]
+pub struct SessionAnswerer {
+    session_endpoint: SessionEndpoint,
+}
+
+impl SessionAnswerer {
+    pub(crate) fn new(session_endpoint: SessionEndpoint) -> Self {
+        Self { session_endpoint }
+    }
+
+    /// The content type of the answers returned by `answer()`
+    pub const ANSWER_CONTENT_TYPE: &'static str = "application/json";
+
+    /// Takes the body of a Client's session request (its SDP offer), and
+    /// returns the body to respond to it with
+    pub async fn answer(&mut self, offer: &[u8]) -> Result<String, NaiaServerSocketError> {
+        let offer_stream = stream::iter(std::iter::once(Ok::<&[u8], IoError>(offer)));
+        self.session_endpoint
+            .session_request(offer_stream)
+            .await
+            .map_err(|error| NaiaServerSocketError::Wrapped(Box::new(error)))
+    }
+}
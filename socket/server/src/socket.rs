@@ -6,11 +6,14 @@ use naia_socket_shared::SocketConfig;
 use super::{
     async_socket::Socket as AsyncSocket,
     conditioned_packet_receiver::ConditionedPacketReceiverImpl,
+    conditioned_packet_sender::{ConditionedPacketSenderImpl, OutgoingConditioner},
     executor,
     packet_receiver::{PacketReceiver, PacketReceiverImpl},
     packet_sender::PacketSender,
     packet_sender::PacketSenderImpl,
     server_addrs::ServerAddrs,
+    session::{HttpSignaling, HttpSignalingConfig},
+    signaling::Signaling,
 };
 
 /// Used to send packets from the Server Socket
@@ -29,17 +32,32 @@ impl Socket {
     pub fn listen(
         server_addrs: &ServerAddrs,
         config: &SocketConfig,
+    ) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>) {
+        let signaling = HttpSignaling::new(
+            server_addrs.session_listen_addr,
+            &config.rtc_endpoint_path,
+            HttpSignalingConfig::default(),
+        );
+        Self::listen_with_signaling(server_addrs, config, Box::new(signaling))
+    }
+
+    /// Listens on the Socket for incoming communication from Clients, with
+    /// session offers served by the given `Signaling` instead of the
+    /// built-in HTTP server
+    pub fn listen_with_signaling(
+        server_addrs: &ServerAddrs,
+        config: &SocketConfig,
+        signaling: Box<dyn Signaling>,
     ) -> (Box<dyn PacketSender>, Box<dyn PacketReceiver>) {
         // Set up receiver loop
         let (from_client_sender, from_client_receiver) = channel::unbounded();
         let (sender_sender, sender_receiver) = channel::bounded(1);
 
         let server_addrs_clone = server_addrs.clone();
-        let config_clone = config.clone();
 
         executor::spawn(async move {
             // Create async socket
-            let mut async_socket = AsyncSocket::listen(server_addrs_clone, config_clone).await;
+            let mut async_socket = AsyncSocket::listen(server_addrs_clone, signaling).await;
 
             sender_sender.send(async_socket.sender()).await.unwrap();
             //TODO: handle result..
@@ -68,21 +86,29 @@ impl Socket {
         })
         .detach();
 
-        let conditioner_config = config.link_condition.clone();
-
-        // Setup Sender
-        let packet_sender_impl = PacketSenderImpl::new(to_client_sender);
-
-        let packet_sender: Box<dyn PacketSender> = Box::new(packet_sender_impl);
-
-        // Setup Receiver
-        let packet_receiver: Box<dyn PacketReceiver> = match &conditioner_config {
-            Some(config) => Box::new(ConditionedPacketReceiverImpl::new(
-                from_client_receiver,
-                config,
-            )),
-            None => Box::new(PacketReceiverImpl::new(from_client_receiver)),
-        };
+        let conditioner_schedule = config.conditioner_schedule();
+
+        // Setup Sender & Receiver
+        let inner_sender: Box<dyn PacketSender> = Box::new(PacketSenderImpl::new(to_client_sender));
+
+        let (packet_sender, packet_receiver): (Box<dyn PacketSender>, Box<dyn PacketReceiver>) =
+            match &conditioner_schedule {
+                Some(schedule) => {
+                    let outgoing = OutgoingConditioner::new(inner_sender, schedule);
+                    (
+                        Box::new(ConditionedPacketSenderImpl::new(outgoing.clone())),
+                        Box::new(ConditionedPacketReceiverImpl::new(
+                            from_client_receiver,
+                            schedule,
+                            outgoing,
+                        )),
+                    )
+                }
+                None => (
+                    inner_sender,
+                    Box::new(PacketReceiverImpl::new(from_client_receiver)),
+                ),
+            };
 
         return (packet_sender, packet_receiver);
     }
@@ -1,4 +1,9 @@
-use std::time::Duration;
+use std::{cell::Cell, time::Duration};
+
+thread_local! {
+    /// The time this thread's clock reads while it is simulated
+    static SIMULATED_NOW: Cell<Option<std::time::Instant>> = const { Cell::new(None) };
+}
 
 /// Represents a specific moment in time
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -10,18 +15,20 @@ impl Instant {
     /// Creates an Instant from the moment the method is called
     pub fn now() -> Self {
         Instant {
-            inner: std::time::Instant::now(),
+            inner: SIMULATED_NOW
+                .with(Cell::get)
+                .unwrap_or_else(std::time::Instant::now),
         }
     }
 
     /// Returns time elapsed since the Instant
     pub fn elapsed(&self) -> Duration {
-        self.inner.elapsed()
+        Self::now().inner.saturating_duration_since(self.inner)
     }
 
     /// Returns time until the Instant occurs
     pub fn until(&self) -> Duration {
-        self.inner.duration_since(std::time::Instant::now())
+        self.inner.saturating_duration_since(Self::now().inner)
     }
 
     /// Adds a given number of milliseconds to the Instant
@@ -38,4 +45,36 @@ impl Instant {
     pub fn inner(&self) -> std::time::Instant {
         self.inner
     }
+
+    /// Makes `Instant::now` read the given time on the calling thread, rather
+    /// than the system clock, until called with None. Replays use this to run
+    /// a Server or Client on the time of their capture
+    pub fn simulate_now(now: Option<std::time::Instant>) {
+        SIMULATED_NOW.with(|simulated| simulated.set(now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Instant;
+
+    #[test]
+    fn reads_the_simulated_time() {
+        let start = Instant::now();
+        let mut later = start.clone();
+        later.add_millis(5000);
+
+        Instant::simulate_now(Some(later.inner()));
+        assert!(Instant::now() == later);
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+
+        // other threads keep the system clock
+        let other_thread_now = std::thread::spawn(Instant::now).join().unwrap();
+        assert!(other_thread_now < later);
+
+        Instant::simulate_now(None);
+        assert!(Instant::now() < later);
+    }
 }
@@ -16,14 +16,20 @@ extern crate cfg_if;
 /// conditions
 pub mod link_condition_logic;
 
+/// Framing & handshake helpers for carrying packets over WebSockets
+#[cfg(all(feature = "websocket", not(target_arch = "wasm32")))]
+pub mod websocket;
+
 mod backends;
 mod link_conditioner_config;
+mod link_conditioner_schedule;
 mod socket_config;
 mod time_queue;
 mod url_parse;
 
 pub use backends::{Instant, Random};
-pub use link_conditioner_config::LinkConditionerConfig;
+pub use link_conditioner_config::{BurstLoss, LinkConditionerConfig};
+pub use link_conditioner_schedule::LinkConditionerSchedule;
 pub use socket_config::SocketConfig;
 pub use time_queue::TimeQueue;
 pub use url_parse::{parse_server_url, url_to_socket_addr};
@@ -1,30 +1,271 @@
 extern crate log;
 // use log::info;
 
-use super::{link_conditioner_config::LinkConditionerConfig, time_queue::TimeQueue, Instant};
+use super::{
+    link_conditioner_config::{BurstLoss, LinkConditionerConfig},
+    time_queue::TimeQueue,
+    Instant,
+};
 use crate::Random;
 
+/// The state of one direction of a conditioned link, kept between packets for
+/// the impairments which depend on earlier packets: bursts of loss, and the
+/// queue behind a bandwidth cap
+#[derive(Clone, Default)]
+pub struct LinkState {
+    in_burst: bool,
+    /// Bytes waiting behind the bandwidth cap, as of `last_drain`
+    backlog: f32,
+    last_drain: Option<Instant>,
+}
+
+impl LinkState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves between the good & bad states of the Gilbert-Elliott model,
+    /// returning whether the link is now in a burst of loss
+    fn step_burst(&mut self, burst_loss: &BurstLoss) -> bool {
+        let chance = if self.in_burst {
+            burst_loss.exit_chance
+        } else {
+            burst_loss.enter_chance
+        };
+        if Random::gen_range_f32(0.0, 1.0) < chance {
+            self.in_burst = !self.in_burst;
+        }
+        self.in_burst
+    }
+
+    /// Queues a packet behind the bandwidth cap, returning how long it waits
+    /// in milliseconds, or None if the queue is full
+    fn enqueue(&mut self, bandwidth: u32, queue_size: u32, packet_size: usize) -> Option<u32> {
+        let bytes_per_milli = bandwidth.max(1) as f32 / 1000.0;
+        if let Some(last_drain) = &self.last_drain {
+            let drained = last_drain.elapsed().as_secs_f32() * 1000.0 * bytes_per_milli;
+            self.backlog = (self.backlog - drained).max(0.0);
+        }
+        self.last_drain = Some(Instant::now());
+
+        if self.backlog > queue_size as f32 {
+            return None;
+        }
+        self.backlog += packet_size as f32;
+        Some((self.backlog / bytes_per_milli) as u32)
+    }
+}
+
+struct Direction {
+    latency: u32,
+    jitter: u32,
+    loss: f32,
+}
+
 /// Given a config object which describes the network conditions to be
 /// simulated, process an incoming packet, adding it to a TimeQueue at the
 /// correct timestamp
+pub fn process_incoming_packet<T: Eq + Clone>(
+    config: &LinkConditionerConfig,
+    state: &mut LinkState,
+    time_queue: &mut TimeQueue<T>,
+    packet: T,
+    packet_size: usize,
+) {
+    let direction = Direction {
+        latency: config.incoming_latency,
+        jitter: config.incoming_jitter,
+        loss: config.incoming_loss,
+    };
+    condition_packet(config, &direction, state, time_queue, packet, packet_size);
+}
+
+/// Given a config object which describes the network conditions to be
+/// simulated, process an outgoing packet, adding it to a TimeQueue at the
+/// timestamp it should be sent
+pub fn process_outgoing_packet<T: Eq + Clone>(
+    config: &LinkConditionerConfig,
+    state: &mut LinkState,
+    time_queue: &mut TimeQueue<T>,
+    packet: T,
+    packet_size: usize,
+) {
+    let direction = Direction {
+        latency: config.outgoing_latency,
+        jitter: config.outgoing_jitter,
+        loss: config.outgoing_loss,
+    };
+    condition_packet(config, &direction, state, time_queue, packet, packet_size);
+}
+
+/// Given a config object which describes the network conditions to be
+/// simulated, process an incoming packet, adding it to a TimeQueue at the
+/// correct timestamp. Impairments which depend on earlier packets, bandwidth
+/// caps & bursts of loss, are not applied, nor is duplication
+#[deprecated(since = "0.20.1", note = "use `process_incoming_packet` instead")]
 pub fn process_packet<T: Eq>(
     config: &LinkConditionerConfig,
     time_queue: &mut TimeQueue<T>,
     packet: T,
 ) {
-    if Random::gen_range_f32(0.0, 1.0) <= config.incoming_loss {
+    if Random::gen_range_f32(0.0, 1.0) < config.incoming_loss {
         // drop the packet
         return;
     }
-    let mut latency: u32 = config.incoming_latency;
-    if config.incoming_jitter > 0 {
+    let direction = Direction {
+        latency: config.incoming_latency,
+        jitter: config.incoming_jitter,
+        loss: config.incoming_loss,
+    };
+    add_packet(config, &direction, time_queue, packet, 0);
+}
+
+fn condition_packet<T: Eq + Clone>(
+    config: &LinkConditionerConfig,
+    direction: &Direction,
+    state: &mut LinkState,
+    time_queue: &mut TimeQueue<T>,
+    packet: T,
+    packet_size: usize,
+) {
+    let loss = match &config.burst_loss {
+        Some(burst_loss) if state.step_burst(burst_loss) => burst_loss.loss,
+        _ => direction.loss,
+    };
+    if Random::gen_range_f32(0.0, 1.0) < loss {
+        // drop the packet
+        return;
+    }
+
+    let mut queue_delay = 0;
+    if let Some(bandwidth) = config.bandwidth {
+        let Some(delay) = state.enqueue(bandwidth, config.bandwidth_queue, packet_size) else {
+            // the queue is full, drop the packet
+            return;
+        };
+        queue_delay = delay;
+    }
+
+    if Random::gen_range_f32(0.0, 1.0) < config.duplicate_chance {
+        add_packet(config, direction, time_queue, packet.clone(), queue_delay);
+    }
+    add_packet(config, direction, time_queue, packet, queue_delay);
+}
+
+fn add_packet<T: Eq>(
+    config: &LinkConditionerConfig,
+    direction: &Direction,
+    time_queue: &mut TimeQueue<T>,
+    packet: T,
+    queue_delay: u32,
+) {
+    let mut latency: u32 = direction.latency + queue_delay;
+    if direction.jitter > 0 {
         if Random::gen_bool() {
-            latency += Random::gen_range_u32(0, config.incoming_jitter);
+            latency += Random::gen_range_u32(0, direction.jitter);
         } else {
-            latency -= Random::gen_range_u32(0, config.incoming_jitter);
+            latency = latency.saturating_sub(Random::gen_range_u32(0, direction.jitter));
         }
     }
+    if Random::gen_range_f32(0.0, 1.0) < config.reorder_chance {
+        latency += config.reorder_delay;
+    }
     let mut packet_timestamp = Instant::now();
     packet_timestamp.add_millis(latency);
     time_queue.add_item(packet_timestamp, packet);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{BurstLoss, LinkConditionerConfig, TimeQueue};
+
+    #[allow(deprecated)]
+    use super::{process_incoming_packet, process_outgoing_packet, process_packet, LinkState};
+
+    #[test]
+    fn conditions_each_direction() {
+        let config = LinkConditionerConfig {
+            outgoing_loss: 1.0,
+            ..Default::default()
+        };
+
+        let mut state = LinkState::new();
+        let mut time_queue = TimeQueue::new();
+        for packet in 0..10u32 {
+            process_incoming_packet(&config, &mut state, &mut time_queue, packet, 100);
+        }
+        assert_eq!(time_queue.len(), 10);
+
+        let mut time_queue = TimeQueue::new();
+        for packet in 0..10u32 {
+            process_outgoing_packet(&config, &mut state, &mut time_queue, packet, 100);
+        }
+        assert!(time_queue.is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn process_packet_conditions_incoming() {
+        let mut config = LinkConditionerConfig::default();
+
+        let mut time_queue = TimeQueue::new();
+        process_packet(&config, &mut time_queue, 7u32);
+        assert_eq!(time_queue.pop_item(), Some(7));
+
+        config.incoming_loss = 1.0;
+        process_packet(&config, &mut time_queue, 7u32);
+        assert!(time_queue.is_empty());
+    }
+
+    #[test]
+    fn duplicates_packets() {
+        let config = LinkConditionerConfig {
+            duplicate_chance: 1.0,
+            ..Default::default()
+        };
+
+        let mut state = LinkState::new();
+        let mut time_queue = TimeQueue::new();
+        process_incoming_packet(&config, &mut state, &mut time_queue, 7u32, 100);
+        assert_eq!(time_queue.pop_item(), Some(7));
+        assert_eq!(time_queue.pop_item(), Some(7));
+        assert_eq!(time_queue.pop_item(), None);
+    }
+
+    #[test]
+    fn drops_packets_over_a_full_bandwidth_queue() {
+        let config = LinkConditionerConfig {
+            bandwidth: Some(1000),
+            bandwidth_queue: 300,
+            ..Default::default()
+        };
+
+        let mut state = LinkState::new();
+        let mut time_queue = TimeQueue::new();
+        for packet in 0..10u32 {
+            process_incoming_packet(&config, &mut state, &mut time_queue, packet, 100);
+        }
+        // the packet in transmission, and the three behind it
+        assert_eq!(time_queue.len(), 4);
+        assert!(!time_queue.has_item());
+    }
+
+    #[test]
+    fn loses_packets_in_bursts() {
+        let config = LinkConditionerConfig {
+            burst_loss: Some(BurstLoss {
+                enter_chance: 1.0,
+                exit_chance: 0.0,
+                loss: 1.0,
+            }),
+            ..Default::default()
+        };
+
+        let mut state = LinkState::new();
+        let mut time_queue = TimeQueue::new();
+        for packet in 0..10u32 {
+            process_incoming_packet(&config, &mut state, &mut time_queue, packet, 100);
+        }
+        assert!(time_queue.is_empty());
+    }
+}
@@ -1,4 +1,15 @@
-/// Contains configuration required to initialize a LinkConditioner
+/// Contains configuration required to initialize a LinkConditioner. The
+/// default config leaves the link unconditioned, so that only the wanted
+/// impairments need be set:
+///
+/// ```
+/// # use naia_socket_shared::LinkConditionerConfig;
+/// let config = LinkConditionerConfig {
+///     outgoing_latency: 100,
+///     duplicate_chance: 0.01,
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Clone)]
 pub struct LinkConditionerConfig {
     /// Delay to receive incoming messages in milliseconds
@@ -10,6 +21,45 @@ pub struct LinkConditionerConfig {
     /// The % chance that an incoming packet will be dropped.
     /// Represented as a value between 0 and 1
     pub incoming_loss: f32,
+    /// Delay to send outgoing messages in milliseconds
+    pub outgoing_latency: u32,
+    /// The maximum additional random latency to delay sent outgoing messages
+    /// in milliseconds, added OR subtracted as with `incoming_jitter`
+    pub outgoing_jitter: u32,
+    /// The % chance that an outgoing packet will be dropped.
+    /// Represented as a value between 0 and 1
+    pub outgoing_loss: f32,
+    /// Caps the bytes per second which each direction of the link can carry.
+    /// Packets over the cap wait in a queue behind the packets before them
+    pub bandwidth: Option<u32>,
+    /// The most bytes which may wait behind the bandwidth cap, packets
+    /// arriving at a full queue are dropped
+    pub bandwidth_queue: u32,
+    /// The % chance that a packet will be delivered twice.
+    /// Represented as a value between 0 and 1
+    pub duplicate_chance: f32,
+    /// The % chance that a packet will be held back by `reorder_delay`, so
+    /// that packets sent after it arrive first.
+    /// Represented as a value between 0 and 1
+    pub reorder_chance: f32,
+    /// Additional delay of reordered packets in milliseconds
+    pub reorder_delay: u32,
+    /// Bursty loss, replacing the uniform loss chances above while a burst
+    /// lasts
+    pub burst_loss: Option<BurstLoss>,
+}
+
+/// Configures a Gilbert-Elliott loss model, in which the link moves between a
+/// good state, with the usual loss chance, and a bad state, in which most
+/// packets are lost. Each chance is represented as a value between 0 and 1
+#[derive(Clone, Copy)]
+pub struct BurstLoss {
+    /// The % chance, on each packet, that a burst of loss begins
+    pub enter_chance: f32,
+    /// The % chance, on each packet during a burst, that the burst ends
+    pub exit_chance: f32,
+    /// The % chance that a packet will be dropped during a burst
+    pub loss: f32,
 }
 
 impl LinkConditionerConfig {
@@ -19,6 +69,7 @@ impl LinkConditionerConfig {
             incoming_latency,
             incoming_jitter,
             incoming_loss,
+            ..Self::default()
         }
     }
 
@@ -29,6 +80,7 @@ impl LinkConditionerConfig {
             incoming_latency: 40,
             incoming_jitter: 6,
             incoming_loss: 0.002,
+            ..Self::default()
         }
     }
 
@@ -39,6 +91,7 @@ impl LinkConditionerConfig {
             incoming_latency: 170,
             incoming_jitter: 45,
             incoming_loss: 0.02,
+            ..Self::default()
         }
     }
 
@@ -49,6 +102,49 @@ impl LinkConditionerConfig {
             incoming_latency: 300,
             incoming_jitter: 84,
             incoming_loss: 0.04,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new LinkConditioner that simulates a mobile connection, with
+    /// limited bandwidth and loss which comes in bursts
+    pub fn mobile_condition() -> Self {
+        LinkConditionerConfig {
+            incoming_latency: 60,
+            incoming_jitter: 30,
+            incoming_loss: 0.005,
+            outgoing_latency: 60,
+            outgoing_jitter: 30,
+            outgoing_loss: 0.005,
+            bandwidth: Some(256 * 1024),
+            bandwidth_queue: 64 * 1024,
+            duplicate_chance: 0.001,
+            reorder_chance: 0.01,
+            reorder_delay: 40,
+            burst_loss: Some(BurstLoss {
+                enter_chance: 0.01,
+                exit_chance: 0.2,
+                loss: 0.6,
+            }),
+        }
+    }
+}
+
+impl Default for LinkConditionerConfig {
+    fn default() -> Self {
+        LinkConditionerConfig {
+            incoming_latency: 0,
+            incoming_jitter: 0,
+            incoming_loss: 0.0,
+            outgoing_latency: 0,
+            outgoing_jitter: 0,
+            outgoing_loss: 0.0,
+            bandwidth: None,
+            bandwidth_queue: 0,
+            duplicate_chance: 0.0,
+            reorder_chance: 0.0,
+            reorder_delay: 0,
+            burst_loss: None,
         }
     }
 }
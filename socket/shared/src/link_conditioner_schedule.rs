@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use super::link_conditioner_config::{BurstLoss, LinkConditionerConfig};
+
+/// Describes how simulated network conditions change over time, for scripting
+/// outages, loss & latency ramps in tests. Time is measured from when the
+/// conditioned socket is created.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use naia_socket_shared::{LinkConditionerConfig, LinkConditionerSchedule};
+/// // good for 5s, then a 2s outage, then 30% loss
+/// let schedule = LinkConditionerSchedule::new(LinkConditionerConfig::good_condition())
+///     .hold(Duration::from_secs(5))
+///     .outage(Duration::from_secs(2))
+///     .then(LinkConditionerConfig::new(0, 0, 0.3));
+/// ```
+#[derive(Clone)]
+pub struct LinkConditionerSchedule {
+    segments: Vec<Segment>,
+    /// The conditions once every segment has passed
+    last: LinkConditionerConfig,
+    repeating: bool,
+}
+
+#[derive(Clone)]
+struct Segment {
+    duration: Duration,
+    from: LinkConditionerConfig,
+    to: LinkConditionerConfig,
+}
+
+impl LinkConditionerSchedule {
+    /// Creates a new LinkConditionerSchedule, which begins with, and until
+    /// told otherwise keeps, the given conditions
+    pub fn new(config: LinkConditionerConfig) -> Self {
+        Self {
+            segments: Vec::new(),
+            last: config,
+            repeating: false,
+        }
+    }
+
+    /// Keeps the current conditions for the given duration
+    pub fn hold(mut self, duration: Duration) -> Self {
+        let current = self.last.clone();
+        self.push(duration, current.clone(), current);
+        self
+    }
+
+    /// Switches to the given conditions immediately
+    pub fn then(mut self, config: LinkConditionerConfig) -> Self {
+        self.last = config;
+        self
+    }
+
+    /// Changes linearly from the current conditions to the given ones over
+    /// the given duration. Optional properties (`bandwidth` & `burst_loss`)
+    /// are only interpolated when set on both ends, otherwise they switch
+    /// once the ramp ends
+    pub fn ramp(mut self, duration: Duration, config: LinkConditionerConfig) -> Self {
+        let current = self.last.clone();
+        self.push(duration, current, config.clone());
+        self.last = config;
+        self
+    }
+
+    /// Drops every packet, in both directions, for the given duration. The
+    /// conditions from before the outage resume once it ends
+    pub fn outage(mut self, duration: Duration) -> Self {
+        let mut outage = self.last.clone();
+        outage.incoming_loss = 1.0;
+        outage.outgoing_loss = 1.0;
+        // a burst's own loss would otherwise stand in for the total loss
+        outage.burst_loss = None;
+        self.push(duration, outage.clone(), outage);
+        self
+    }
+
+    /// Starts the schedule over once it ends, rather than keeping its final
+    /// conditions
+    pub fn repeating(mut self) -> Self {
+        self.repeating = true;
+        self
+    }
+
+    /// The total duration of the schedule
+    pub fn duration(&self) -> Duration {
+        self.segments.iter().map(|segment| segment.duration).sum()
+    }
+
+    /// Returns the conditions to simulate at the given time since the
+    /// schedule began
+    pub fn config_at(&self, elapsed: Duration) -> LinkConditionerConfig {
+        let total = self.duration();
+        let mut elapsed = elapsed;
+        if self.repeating && !total.is_zero() {
+            elapsed = Duration::from_nanos((elapsed.as_nanos() % total.as_nanos()) as u64);
+        }
+
+        for segment in &self.segments {
+            if elapsed < segment.duration {
+                let progress = elapsed.as_secs_f32() / segment.duration.as_secs_f32();
+                return interpolate(&segment.from, &segment.to, progress);
+            }
+            elapsed -= segment.duration;
+        }
+
+        self.last.clone()
+    }
+
+    fn push(&mut self, duration: Duration, from: LinkConditionerConfig, to: LinkConditionerConfig) {
+        if duration.is_zero() {
+            return;
+        }
+        self.segments.push(Segment { duration, from, to });
+    }
+}
+
+impl From<LinkConditionerConfig> for LinkConditionerSchedule {
+    fn from(config: LinkConditionerConfig) -> Self {
+        Self::new(config)
+    }
+}
+
+fn interpolate(
+    from: &LinkConditionerConfig,
+    to: &LinkConditionerConfig,
+    progress: f32,
+) -> LinkConditionerConfig {
+    let lerp = |a: f32, b: f32| a + (b - a) * progress;
+    let lerp_u32 = |a: u32, b: u32| lerp(a as f32, b as f32).round() as u32;
+
+    LinkConditionerConfig {
+        incoming_latency: lerp_u32(from.incoming_latency, to.incoming_latency),
+        incoming_jitter: lerp_u32(from.incoming_jitter, to.incoming_jitter),
+        incoming_loss: lerp(from.incoming_loss, to.incoming_loss),
+        outgoing_latency: lerp_u32(from.outgoing_latency, to.outgoing_latency),
+        outgoing_jitter: lerp_u32(from.outgoing_jitter, to.outgoing_jitter),
+        outgoing_loss: lerp(from.outgoing_loss, to.outgoing_loss),
+        bandwidth: match (from.bandwidth, to.bandwidth) {
+            (Some(a), Some(b)) => Some(lerp_u32(a, b)),
+            (bandwidth, _) => bandwidth,
+        },
+        bandwidth_queue: lerp_u32(from.bandwidth_queue, to.bandwidth_queue),
+        duplicate_chance: lerp(from.duplicate_chance, to.duplicate_chance),
+        reorder_chance: lerp(from.reorder_chance, to.reorder_chance),
+        reorder_delay: lerp_u32(from.reorder_delay, to.reorder_delay),
+        burst_loss: match (&from.burst_loss, &to.burst_loss) {
+            (Some(a), Some(b)) => Some(BurstLoss {
+                enter_chance: lerp(a.enter_chance, b.enter_chance),
+                exit_chance: lerp(a.exit_chance, b.exit_chance),
+                loss: lerp(a.loss, b.loss),
+            }),
+            (burst_loss, _) => *burst_loss,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        link_condition_logic::{process_incoming_packet, process_outgoing_packet, LinkState},
+        LinkConditionerConfig, TimeQueue,
+    };
+
+    use super::LinkConditionerSchedule;
+
+    fn secs(secs: f32) -> Duration {
+        Duration::from_secs_f32(secs)
+    }
+
+    #[test]
+    fn follows_holds_outages_and_switches() {
+        let schedule = LinkConditionerSchedule::new(LinkConditionerConfig::new(50, 0, 0.0))
+            .hold(secs(5.0))
+            .outage(secs(2.0))
+            .then(LinkConditionerConfig::new(0, 0, 0.3));
+
+        assert_eq!(schedule.config_at(secs(1.0)).incoming_latency, 50);
+        assert_eq!(schedule.config_at(secs(1.0)).incoming_loss, 0.0);
+        assert_eq!(schedule.config_at(secs(6.0)).incoming_loss, 1.0);
+        assert_eq!(schedule.config_at(secs(6.0)).outgoing_loss, 1.0);
+        assert_eq!(schedule.config_at(secs(8.0)).incoming_loss, 0.3);
+        assert_eq!(schedule.config_at(secs(1000.0)).incoming_loss, 0.3);
+    }
+
+    #[test]
+    fn outages_resume_the_previous_conditions() {
+        let schedule = LinkConditionerSchedule::new(LinkConditionerConfig::mobile_condition())
+            .outage(secs(2.0));
+
+        let outage = schedule.config_at(secs(1.0));
+        assert_eq!(outage.incoming_loss, 1.0);
+        assert!(outage.burst_loss.is_none());
+
+        let mut incoming_state = LinkState::new();
+        let mut outgoing_state = LinkState::new();
+        let mut time_queue = TimeQueue::new();
+        for packet in 0..1000 {
+            process_incoming_packet(&outage, &mut incoming_state, &mut time_queue, packet, 100);
+            process_outgoing_packet(&outage, &mut outgoing_state, &mut time_queue, packet, 100);
+        }
+        assert!(time_queue.is_empty());
+
+        let resumed = schedule.config_at(secs(3.0));
+        assert_eq!(
+            resumed.incoming_loss,
+            LinkConditionerConfig::mobile_condition().incoming_loss
+        );
+        assert!(resumed.burst_loss.is_some());
+    }
+
+    #[test]
+    fn ramps_between_conditions() {
+        let schedule = LinkConditionerSchedule::new(LinkConditionerConfig::new(50, 0, 0.0))
+            .ramp(secs(10.0), LinkConditionerConfig::new(400, 0, 0.0));
+
+        assert_eq!(schedule.config_at(secs(0.0)).incoming_latency, 50);
+        assert_eq!(schedule.config_at(secs(5.0)).incoming_latency, 225);
+        assert_eq!(schedule.config_at(secs(20.0)).incoming_latency, 400);
+    }
+
+    #[test]
+    fn repeats() {
+        let schedule = LinkConditionerSchedule::new(LinkConditionerConfig::new(50, 0, 0.0))
+            .hold(secs(1.0))
+            .outage(secs(1.0))
+            .repeating();
+
+        assert_eq!(schedule.config_at(secs(0.5)).incoming_loss, 0.0);
+        assert_eq!(schedule.config_at(secs(1.5)).incoming_loss, 1.0);
+        assert_eq!(schedule.config_at(secs(10.5)).incoming_loss, 0.0);
+        assert_eq!(schedule.config_at(secs(11.5)).incoming_loss, 1.0);
+    }
+}
@@ -1,6 +1,9 @@
 use std::default::Default;
 
-use super::link_conditioner_config::LinkConditionerConfig;
+use super::{
+    link_conditioner_config::LinkConditionerConfig,
+    link_conditioner_schedule::LinkConditionerSchedule,
+};
 
 const DEFAULT_RTC_PATH: &str = "rtc_session";
 
@@ -9,6 +12,9 @@ const DEFAULT_RTC_PATH: &str = "rtc_session";
 pub struct SocketConfig {
     /// Configuration used to simulate network conditions
     pub link_condition: Option<LinkConditionerConfig>,
+    /// Network conditions which change over time, used instead of
+    /// `link_condition` when set
+    pub link_condition_schedule: Option<LinkConditionerSchedule>,
     /// The endpoint URL path to use for initiating new WebRTC sessions
     pub rtc_endpoint_path: String,
 }
@@ -29,15 +35,27 @@ impl SocketConfig {
 
         SocketConfig {
             link_condition,
+            link_condition_schedule: None,
             rtc_endpoint_path: endpoint_path,
         }
     }
+
+    /// Returns the schedule of network conditions to simulate, if any
+    pub fn conditioner_schedule(&self) -> Option<LinkConditionerSchedule> {
+        if let Some(schedule) = &self.link_condition_schedule {
+            return Some(schedule.clone());
+        }
+        self.link_condition
+            .clone()
+            .map(LinkConditionerSchedule::from)
+    }
 }
 
 impl Default for SocketConfig {
     fn default() -> Self {
         Self {
             link_condition: None,
+            link_condition_schedule: None,
             rtc_endpoint_path: DEFAULT_RTC_PATH.to_string(),
         }
     }
@@ -0,0 +1,300 @@
+//! Minimal RFC 6455 framing, enough to carry packets as binary WebSocket
+//! messages over any byte stream
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::digest::{Context, SHA1_FOR_LEGACY_USE_ONLY};
+
+pub const OPCODE_CONTINUATION: u8 = 0x0;
+pub const OPCODE_TEXT: u8 = 0x1;
+pub const OPCODE_BINARY: u8 = 0x2;
+pub const OPCODE_CLOSE: u8 = 0x8;
+pub const OPCODE_PING: u8 = 0x9;
+pub const OPCODE_PONG: u8 = 0xA;
+
+/// Messages larger than this are refused, a packet is never near this size
+pub const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+/// Upper bound on the size of an HTTP upgrade request or response head
+pub const MAX_HANDSHAKE_SIZE: usize = 8 * 1024;
+
+/// The GUID appended to a client's key to compute the accept key
+pub const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// A complete message read from a WebSocket
+#[derive(Debug, Eq, PartialEq)]
+pub enum Message {
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong,
+    Close,
+}
+
+/// Encodes a single, final frame. Clients must pass a mask, servers must not.
+pub fn encode_frame(opcode: u8, payload: &[u8], mask: Option<[u8; 4]>) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(payload.len() + 14);
+    frame.push(0x80 | opcode);
+
+    let mask_bit = if mask.is_some() { 0x80 } else { 0 };
+    let length = payload.len();
+    if length < 126 {
+        frame.push(mask_bit | length as u8);
+    } else if length <= u16::MAX as usize {
+        frame.push(mask_bit | 126);
+        frame.extend_from_slice(&(length as u16).to_be_bytes());
+    } else {
+        frame.push(mask_bit | 127);
+        frame.extend_from_slice(&(length as u64).to_be_bytes());
+    }
+
+    match mask {
+        Some(mask) => {
+            frame.extend_from_slice(&mask);
+            frame.extend(
+                payload
+                    .iter()
+                    .enumerate()
+                    .map(|(index, byte)| byte ^ mask[index % 4]),
+            );
+        }
+        None => frame.extend_from_slice(payload),
+    }
+
+    frame
+}
+
+/// Writes a single, final frame
+pub fn write_frame<W: Write>(
+    writer: &mut W,
+    opcode: u8,
+    payload: &[u8],
+    mask: Option<[u8; 4]>,
+) -> Result<()> {
+    writer.write_all(&encode_frame(opcode, payload, mask))
+}
+
+/// The key a server accepts an upgrade request with, computed from the key
+/// the client sent
+pub fn accept_key(key: &str) -> String {
+    let mut context = Context::new(&SHA1_FOR_LEGACY_USE_ONLY);
+    context.update(key.as_bytes());
+    context.update(HANDSHAKE_GUID.as_bytes());
+    STANDARD.encode(context.finish())
+}
+
+/// Reads frames off a stream, joining fragmented messages back together.
+/// Control frames may arrive in between the fragments of a message.
+pub struct MessageReader {
+    fragments: Option<Vec<u8>>,
+    /// Whether frames must be masked, as those a client sends are, or must
+    /// not be, as those a server sends
+    masked: bool,
+}
+
+impl MessageReader {
+    /// Reads the frames a client sends, refusing any which aren't masked
+    pub fn from_client() -> Self {
+        Self {
+            fragments: None,
+            masked: true,
+        }
+    }
+
+    /// Reads the frames a server sends, refusing any which are masked
+    pub fn from_server() -> Self {
+        Self {
+            fragments: None,
+            masked: false,
+        }
+    }
+
+    pub fn read<R: Read>(&mut self, reader: &mut R) -> Result<Message> {
+        loop {
+            let mut head = [0u8; 2];
+            reader.read_exact(&mut head)?;
+
+            let is_final = head[0] & 0x80 != 0;
+            if head[0] & 0x70 != 0 {
+                return Err(invalid_data("reserved bits are set"));
+            }
+            let opcode = head[0] & 0x0F;
+            let is_masked = head[1] & 0x80 != 0;
+            if is_masked != self.masked {
+                return Err(invalid_data(if self.masked {
+                    "client frames must be masked"
+                } else {
+                    "server frames must not be masked"
+                }));
+            }
+
+            let length = match head[1] & 0x7F {
+                126 => {
+                    let mut bytes = [0u8; 2];
+                    reader.read_exact(&mut bytes)?;
+                    u16::from_be_bytes(bytes) as u64
+                }
+                127 => {
+                    let mut bytes = [0u8; 8];
+                    reader.read_exact(&mut bytes)?;
+                    u64::from_be_bytes(bytes)
+                }
+                length => length as u64,
+            };
+            let buffered = self.fragments.as_ref().map_or(0, |bytes| bytes.len());
+            if length > (MAX_MESSAGE_SIZE - buffered) as u64 {
+                return Err(invalid_data("message is too large"));
+            }
+
+            let mut mask = [0u8; 4];
+            if is_masked {
+                reader.read_exact(&mut mask)?;
+            }
+
+            let mut payload = vec![0u8; length as usize];
+            reader.read_exact(&mut payload)?;
+            if is_masked {
+                for (index, byte) in payload.iter_mut().enumerate() {
+                    *byte ^= mask[index % 4];
+                }
+            }
+
+            match opcode {
+                OPCODE_BINARY | OPCODE_TEXT => {
+                    if self.fragments.is_some() {
+                        return Err(invalid_data("expected a continuation frame"));
+                    }
+                    if is_final {
+                        return Ok(Message::Binary(payload));
+                    }
+                    self.fragments = Some(payload);
+                }
+                OPCODE_CONTINUATION => {
+                    let Some(fragments) = self.fragments.as_mut() else {
+                        return Err(invalid_data("unexpected continuation frame"));
+                    };
+                    fragments.extend_from_slice(&payload);
+                    if is_final {
+                        return Ok(Message::Binary(self.fragments.take().unwrap()));
+                    }
+                }
+                OPCODE_PING | OPCODE_PONG | OPCODE_CLOSE => {
+                    if !is_final || length > 125 {
+                        return Err(invalid_data("invalid control frame"));
+                    }
+                    return Ok(match opcode {
+                        OPCODE_PING => Message::Ping(payload),
+                        OPCODE_PONG => Message::Pong,
+                        _ => Message::Close,
+                    });
+                }
+                _ => {
+                    return Err(invalid_data("unknown opcode"));
+                }
+            }
+        }
+    }
+}
+
+/// Reads an HTTP head, up to and including the blank line which ends it.
+/// Reads one byte at a time so that no frame bytes after it are consumed.
+pub fn read_http_head<R: Read>(reader: &mut R) -> Result<String> {
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_HANDSHAKE_SIZE {
+            return Err(invalid_data("handshake is too large"));
+        }
+        reader.read_exact(&mut byte)?;
+        head.push(byte[0]);
+    }
+    String::from_utf8(head).map_err(|_| invalid_data("handshake is not valid UTF-8"))
+}
+
+/// Finds the value of a header in an HTTP head, ignoring the case of its name
+pub fn header_value<'h>(head: &'h str, name: &str) -> Option<&'h str> {
+    head.lines().skip(1).find_map(|line| {
+        let (header_name, value) = line.split_once(':')?;
+        if header_name.trim().eq_ignore_ascii_case(name) {
+            Some(value.trim())
+        } else {
+            None
+        }
+    })
+}
+
+fn invalid_data(reason: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, reason.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_masked_and_fragmented_messages() {
+        let payload: Vec<u8> = (0..300).map(|index| index as u8).collect();
+        let mask = Some([1, 2, 3, 4]);
+
+        let mut stream = Vec::new();
+        stream.extend(encode_frame(OPCODE_BINARY, &payload, mask));
+        // a message split into two fragments, with a ping in between
+        let mut first = encode_frame(OPCODE_BINARY, &payload[..10], mask);
+        first[0] &= 0x7F;
+        stream.extend(first);
+        stream.extend(encode_frame(OPCODE_PING, b"ping", mask));
+        stream.extend(encode_frame(OPCODE_CONTINUATION, &payload[10..], mask));
+        stream.extend(encode_frame(OPCODE_CLOSE, &[], mask));
+
+        let mut reader = MessageReader::from_client();
+        let mut stream = stream.as_slice();
+        assert_eq!(
+            reader.read(&mut stream).unwrap(),
+            Message::Binary(payload.clone())
+        );
+        assert_eq!(
+            reader.read(&mut stream).unwrap(),
+            Message::Ping(b"ping".to_vec())
+        );
+        assert_eq!(reader.read(&mut stream).unwrap(), Message::Binary(payload));
+        assert_eq!(reader.read(&mut stream).unwrap(), Message::Close);
+    }
+
+    #[test]
+    fn refuses_oversized_messages() {
+        let payload = vec![0u8; MAX_MESSAGE_SIZE + 1];
+        let stream = encode_frame(OPCODE_BINARY, &payload, None);
+        let result = MessageReader::from_server().read(&mut stream.as_slice());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn refuses_frames_masked_the_wrong_way() {
+        let unmasked = encode_frame(OPCODE_BINARY, b"packet", None);
+        let result = MessageReader::from_client().read(&mut unmasked.as_slice());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+
+        let masked = encode_frame(OPCODE_BINARY, b"packet", Some([1, 2, 3, 4]));
+        let result = MessageReader::from_server().read(&mut masked.as_slice());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn computes_accept_keys() {
+        // the example handshake from RFC 6455
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn reads_handshake_heads() {
+        let mut stream: &[u8] =
+            b"GET / HTTP/1.1\r\nHost: localhost\r\nsec-websocket-key: abc==\r\n\r\n\x82\x00";
+        let head = read_http_head(&mut stream).unwrap();
+        assert_eq!(header_value(&head, "Sec-WebSocket-Key"), Some("abc=="));
+        assert_eq!(header_value(&head, "Upgrade"), None);
+        assert_eq!(stream, b"\x82\x00");
+    }
+}
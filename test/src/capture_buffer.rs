@@ -0,0 +1,28 @@
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+};
+
+use naia_shared::PacketCapture;
+
+/// Collects a capture in memory, sharing it between its clones so that one
+/// can be handed to `start_capture` & another read back afterwards
+#[derive(Clone, Default)]
+pub struct CaptureBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl CaptureBuffer {
+    pub fn read(&self) -> PacketCapture {
+        PacketCapture::read(self.0.lock().unwrap().as_slice()).unwrap()
+    }
+}
+
+impl Write for CaptureBuffer {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use naia_client::ClientConfig;
+use naia_shared::ConnectionConfig;
+
+/// A ClientConfig which completes its handshake, and acknowledges the
+/// Server's packets, within a few milliseconds, for tests over a
+/// LocalTransport
+pub fn quick_client_config() -> ClientConfig {
+    ClientConfig {
+        connection: ConnectionConfig {
+            heartbeat_interval: Duration::from_millis(20),
+            ..Default::default()
+        },
+        send_handshake_interval: Duration::from_millis(10),
+        ping_interval: Duration::from_millis(20),
+        handshake_pings: 2,
+    }
+}
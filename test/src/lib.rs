@@ -1,3 +1,13 @@
 mod auth;
+mod capture_buffer;
+mod client_config;
+mod local_transport;
+mod scenario;
+mod simulated_clock;
 
 pub use auth::Auth;
+pub use capture_buffer::CaptureBuffer;
+pub use client_config::quick_client_config;
+pub use local_transport::{LocalClientSocket, LocalServerSocket, LocalTransport};
+pub use scenario::{Scenario, Step, TestClient};
+pub use simulated_clock::SimulatedClock;
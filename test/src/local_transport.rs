@@ -0,0 +1,188 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use naia_client::transport::{
+    PacketReceiver as ClientReceiver, PacketSender as ClientSender, RecvError as ClientRecvError,
+    SendError as ClientSendError, ServerAddr, Socket as ClientSocket,
+};
+use naia_server::transport::{
+    PacketReceiver as ServerReceiver, PacketSender as ServerSender, RecvError as ServerRecvError,
+    SendError as ServerSendError, Socket as ServerSocket,
+};
+
+const SERVER_ADDR: &str = "127.0.0.1:14191";
+
+/// Links a Server to its Clients in memory, so tests can run both in one
+/// thread. Each packet is delivered to the next receive of its recipient
+#[derive(Clone, Default)]
+pub struct LocalTransport {
+    queues: Arc<Mutex<Queues>>,
+}
+
+#[derive(Default)]
+struct Queues {
+    to_server: VecDeque<(SocketAddr, Box<[u8]>)>,
+    to_clients: HashMap<SocketAddr, VecDeque<Box<[u8]>>>,
+}
+
+impl LocalTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn server_addr() -> SocketAddr {
+        SERVER_ADDR.parse().unwrap()
+    }
+
+    /// A socket for the Server to listen on
+    pub fn server_socket(&self) -> LocalServerSocket {
+        LocalServerSocket {
+            queues: self.queues.clone(),
+        }
+    }
+
+    /// A socket for a Client to connect with, from an address of its own
+    pub fn client_socket(&self) -> LocalClientSocket {
+        let mut queues = self.queues.lock().unwrap();
+        let port = 10_000 + queues.to_clients.len() as u16;
+        let address = SocketAddr::new(Self::server_addr().ip(), port);
+        queues.to_clients.insert(address, VecDeque::new());
+
+        LocalClientSocket {
+            queues: self.queues.clone(),
+            address,
+        }
+    }
+}
+
+// Server Socket
+pub struct LocalServerSocket {
+    queues: Arc<Mutex<Queues>>,
+}
+
+impl From<LocalServerSocket> for Box<dyn ServerSocket> {
+    fn from(socket: LocalServerSocket) -> Self {
+        Box::new(socket)
+    }
+}
+
+impl ServerSocket for LocalServerSocket {
+    fn listen(self: Box<Self>) -> (Box<dyn ServerSender>, Box<dyn ServerReceiver>) {
+        let sender = LocalServerSender {
+            queues: self.queues.clone(),
+        };
+        let receiver = LocalServerReceiver {
+            queues: self.queues,
+            last_payload: None,
+        };
+        (Box::new(sender), Box::new(receiver))
+    }
+}
+
+struct LocalServerSender {
+    queues: Arc<Mutex<Queues>>,
+}
+
+impl ServerSender for LocalServerSender {
+    fn send(&mut self, address: &SocketAddr, payload: &[u8]) -> Result<(), ServerSendError> {
+        let mut queues = self.queues.lock().unwrap();
+        let Some(queue) = queues.to_clients.get_mut(address) else {
+            return Err(ServerSendError);
+        };
+        queue.push_back(payload.into());
+        Ok(())
+    }
+
+    fn local_addrs(&self) -> Vec<SocketAddr> {
+        vec![LocalTransport::server_addr()]
+    }
+}
+
+#[derive(Clone)]
+struct LocalServerReceiver {
+    queues: Arc<Mutex<Queues>>,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl ServerReceiver for LocalServerReceiver {
+    fn receive(&mut self) -> Result<Option<(SocketAddr, &[u8])>, ServerRecvError> {
+        let Some((address, payload)) = self.queues.lock().unwrap().to_server.pop_front() else {
+            return Ok(None);
+        };
+        self.last_payload = Some(payload);
+        Ok(Some((address, self.last_payload.as_ref().unwrap())))
+    }
+}
+
+// Client Socket
+pub struct LocalClientSocket {
+    queues: Arc<Mutex<Queues>>,
+    address: SocketAddr,
+}
+
+impl From<LocalClientSocket> for Box<dyn ClientSocket> {
+    fn from(socket: LocalClientSocket) -> Self {
+        Box::new(socket)
+    }
+}
+
+impl ClientSocket for LocalClientSocket {
+    fn connect(self: Box<Self>) -> (Box<dyn ClientSender>, Box<dyn ClientReceiver>) {
+        let sender = LocalClientSender {
+            queues: self.queues.clone(),
+            address: self.address,
+        };
+        let receiver = LocalClientReceiver {
+            queues: self.queues,
+            address: self.address,
+            last_payload: None,
+        };
+        (Box::new(sender), Box::new(receiver))
+    }
+}
+
+struct LocalClientSender {
+    queues: Arc<Mutex<Queues>>,
+    address: SocketAddr,
+}
+
+impl ClientSender for LocalClientSender {
+    fn send(&self, payload: &[u8]) -> Result<(), ClientSendError> {
+        let mut queues = self.queues.lock().unwrap();
+        queues.to_server.push_back((self.address, payload.into()));
+        Ok(())
+    }
+
+    fn server_addr(&self) -> ServerAddr {
+        ServerAddr::Found(LocalTransport::server_addr())
+    }
+}
+
+#[derive(Clone)]
+struct LocalClientReceiver {
+    queues: Arc<Mutex<Queues>>,
+    address: SocketAddr,
+    last_payload: Option<Box<[u8]>>,
+}
+
+impl ClientReceiver for LocalClientReceiver {
+    fn receive(&mut self) -> Result<Option<&[u8]>, ClientRecvError> {
+        let mut queues = self.queues.lock().unwrap();
+        let Some(payload) = queues
+            .to_clients
+            .get_mut(&self.address)
+            .and_then(VecDeque::pop_front)
+        else {
+            return Ok(None);
+        };
+        self.last_payload = Some(payload);
+        Ok(Some(self.last_payload.as_ref().unwrap()))
+    }
+
+    fn server_addr(&self) -> ServerAddr {
+        ServerAddr::Found(LocalTransport::server_addr())
+    }
+}
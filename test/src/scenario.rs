@@ -0,0 +1,219 @@
+use std::{collections::HashMap, time::Duration};
+
+use naia_client::{Client, EntityMut as ClientEntityMut, Events as ClientEvents};
+use naia_demo_world::{Entity, World, WorldMutType};
+use naia_server::{
+    AuthEvent, ConnectEvent, EntityMut as ServerEntityMut, Events as ServerEvents, RoomKey, Server,
+    ServerConfig, TickEvent, UserKey,
+};
+use naia_shared::{Protocol, Replicate};
+
+use crate::{quick_client_config, Auth, LocalTransport, SimulatedClock};
+
+/// How far the simulated clock moves on each step
+const STEP_DURATION: Duration = Duration::from_millis(1);
+/// The steps `run_until` takes before giving up, 10 simulated seconds
+const MAX_STEPS: usize = 10_000;
+
+/// A Server & its Clients, linked by a LocalTransport and stepped on a
+/// simulated clock. Every User is accepted into one Room, every Entity in
+/// scope of a User is included, and updates are sent on each tick, so tests
+/// need only state their own scenario
+pub struct Scenario {
+    pub server: Server<Entity>,
+    pub server_world: World,
+    pub room: RoomKey,
+    pub clients: Vec<TestClient>,
+    protocol: fn() -> Protocol,
+    transport: LocalTransport,
+    users: HashMap<String, UserKey>,
+    clock: SimulatedClock,
+}
+
+/// A Client of a Scenario, with its World
+pub struct TestClient {
+    pub client: Client<Entity>,
+    pub world: World,
+    receiving: bool,
+}
+
+/// The events of one step
+pub struct Step {
+    pub server_events: ServerEvents<Entity>,
+    /// The Users which connected during the step
+    pub connected: Vec<UserKey>,
+    /// The Server ticks which passed during the step
+    pub ticks: usize,
+    client_events: Vec<Option<ClientEvents<Entity>>>,
+}
+
+impl Scenario {
+    /// Creates a listening Server, with a Protocol built by the given function
+    /// for it & each Client
+    pub fn new(protocol: fn() -> Protocol) -> Self {
+        Self::with_setup(protocol, |_| {})
+    }
+
+    /// Creates a listening Server as `new` does, first passing it to `setup`
+    /// for anything which must be done before the Server listens
+    pub fn with_setup(protocol: fn() -> Protocol, setup: impl FnOnce(&mut Server<Entity>)) -> Self {
+        let clock = SimulatedClock::new();
+        let transport = LocalTransport::new();
+
+        let mut server = Server::new(ServerConfig::default(), protocol());
+        setup(&mut server);
+        server.listen(transport.server_socket());
+        let room = server.make_room().key();
+
+        Self {
+            server,
+            server_world: World::default(),
+            room,
+            clients: Vec::new(),
+            protocol,
+            transport,
+            users: HashMap::new(),
+            clock,
+        }
+    }
+
+    /// Connects a new Client, returning its index in `clients`
+    pub fn connect(&mut self, username: &str) -> usize {
+        let mut client = Client::new(quick_client_config(), (self.protocol)());
+        client.auth(Auth::new(username, "1234567"));
+        client.connect(self.transport.client_socket());
+
+        self.clients.push(TestClient {
+            client,
+            world: World::default(),
+            receiving: true,
+        });
+        self.clients.len() - 1
+    }
+
+    /// Disconnects a Client, which is no longer stepped
+    pub fn disconnect(&mut self, client_index: usize) {
+        let test_client = &mut self.clients[client_index];
+        test_client.client.disconnect();
+        test_client.receiving = false;
+    }
+
+    /// The UserKey of the Client which authenticated with the given username
+    pub fn user_key(&self, username: &str) -> Option<UserKey> {
+        self.users.get(username).copied()
+    }
+
+    /// Spawns an Entity with a Component, in the Room every User joins
+    pub fn spawn<R: Replicate>(&mut self, component: R) -> Entity {
+        let entity = self
+            .server
+            .spawn_entity(self.server_world.proxy_mut())
+            .insert_component(component)
+            .id();
+        self.server.room_mut(&self.room).add_entity(&entity);
+        entity
+    }
+
+    pub fn entity_mut(
+        &mut self,
+        entity: &Entity,
+    ) -> ServerEntityMut<'_, Entity, impl WorldMutType<Entity> + '_> {
+        self.server
+            .entity_mut(self.server_world.proxy_mut(), entity)
+    }
+
+    /// Reads a Component of one of the Server's Entities
+    pub fn read<R: Replicate, T>(&self, entity: &Entity, read: impl FnOnce(&R) -> T) -> Option<T> {
+        let entity_ref = self.server.entity(self.server_world.proxy(), entity);
+        let component = entity_ref.component::<R>()?;
+        Some(read(&component))
+    }
+
+    /// Moves the clock forward a step, and then receives on the Server &
+    /// each Client
+    pub fn step(&mut self) -> Step {
+        self.clock.advance(STEP_DURATION);
+
+        let mut server_events = self.server.receive(self.server_world.proxy_mut());
+        for (user_key, auth) in server_events.read::<AuthEvent<Auth>>() {
+            self.server.accept_connection(&user_key);
+            self.users.insert(auth.username, user_key);
+        }
+        let connected: Vec<UserKey> = server_events.read::<ConnectEvent>().collect();
+        for user_key in &connected {
+            self.server.room_mut(&self.room).add_user(user_key);
+        }
+        let ticks = server_events.read::<TickEvent>().count();
+        if ticks > 0 {
+            for (_, user_key, entity) in self.server.scope_checks() {
+                self.server.user_scope(&user_key).include(&entity);
+            }
+            self.server.send_all_updates(self.server_world.proxy());
+        }
+
+        let client_events = self
+            .clients
+            .iter_mut()
+            .map(|test_client| {
+                test_client
+                    .receiving
+                    .then(|| test_client.client.receive(test_client.world.proxy_mut()))
+            })
+            .collect();
+
+        Step {
+            server_events,
+            connected,
+            ticks,
+            client_events,
+        }
+    }
+
+    /// Steps until `done` returns true, panicking if it never does
+    pub fn run_until<F: FnMut(&mut Self, &mut Step) -> bool>(&mut self, mut done: F) {
+        for _ in 0..MAX_STEPS {
+            let mut step = self.step();
+            if done(self, &mut step) {
+                return;
+            }
+        }
+        panic!("Scenario did not finish within {} steps", MAX_STEPS);
+    }
+
+    /// Takes the given number of steps, so that packets in flight arrive
+    pub fn run_for<F: FnMut(&mut Self, &mut Step)>(&mut self, steps: usize, mut each: F) {
+        for _ in 0..steps {
+            let mut step = self.step();
+            each(self, &mut step);
+        }
+    }
+}
+
+impl TestClient {
+    pub fn entities(&self) -> Vec<Entity> {
+        self.client.entities(&self.world.proxy())
+    }
+
+    pub fn entity_mut(
+        &mut self,
+        entity: &Entity,
+    ) -> ClientEntityMut<'_, Entity, impl WorldMutType<Entity> + '_> {
+        self.client.entity_mut(self.world.proxy_mut(), entity)
+    }
+
+    /// Reads a Component of one of the Client's Entities
+    pub fn read<R: Replicate, T>(&self, entity: &Entity, read: impl FnOnce(&R) -> T) -> Option<T> {
+        let entity_ref = self.client.entity(self.world.proxy(), entity);
+        let component = entity_ref.component::<R>()?;
+        Some(read(&component))
+    }
+}
+
+impl Step {
+    /// The events of a Client, which must still be connected
+    pub fn client(&mut self, client_index: usize) -> &mut ClientEvents<Entity> {
+        self.client_events[client_index]
+            .as_mut()
+            .expect("Client has been disconnected")
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use naia_shared::Instant;
+
+/// Drives `Instant::now` on the calling thread, so that tests step through
+/// time rather than sleep. The thread returns to the system clock when the
+/// SimulatedClock is dropped
+pub struct SimulatedClock {
+    now: std::time::Instant,
+}
+
+impl SimulatedClock {
+    pub fn new() -> Self {
+        let now = Instant::now().inner();
+        Instant::simulate_now(Some(now));
+        Self { now }
+    }
+
+    pub fn advance(&mut self, duration: Duration) {
+        self.now += duration;
+        Instant::simulate_now(Some(self.now));
+    }
+}
+
+impl Default for SimulatedClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SimulatedClock {
+    fn drop(&mut self) {
+        Instant::simulate_now(None);
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use naia_client::AssetReadyEvent;
+use naia_shared::{AssetChannel, AssetData, Protocol};
+use naia_test::{Auth, Scenario};
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .enable_asset_cache()
+        .build()
+}
+
+#[test]
+fn assets_not_matching_their_hash_are_rejected() {
+    let mut scenario = Scenario::new(protocol);
+    let map = b"map bytes".to_vec();
+    let hash = scenario.server.register_asset(map.clone());
+    let alice = scenario.connect("alice");
+
+    let mut ready = Vec::new();
+    scenario.run_until(|scenario, step| {
+        for user_key in &step.connected {
+            // forged bytes arrive before the offer, and must not be cached
+            let forged = AssetData::new(&hash, b"forged bytes".to_vec());
+            scenario
+                .server
+                .send_message::<AssetChannel, _>(user_key, &forged);
+            scenario.server.offer_asset(user_key, &hash);
+        }
+        ready.extend(step.client(alice).read::<AssetReadyEvent>());
+        !ready.is_empty()
+    });
+    scenario.run_for(50, |_, step| {
+        ready.extend(step.client(alice).read::<AssetReadyEvent>());
+    });
+
+    assert_eq!(ready, [hash]);
+    assert_eq!(
+        scenario.clients[alice].client.cached_asset(&hash),
+        Some(map)
+    );
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use naia_client::{InsertComponentEvent, RemoveComponentEvent};
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario, Step};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+#[derive(Replicate)]
+pub struct Secret {
+    pub code: Property<u16>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Secret>()
+        .build()
+}
+
+/// The Secret code each Client has, if any
+fn secrets(scenario: &Scenario) -> Vec<Option<u16>> {
+    scenario
+        .clients
+        .iter()
+        .map(|test_client| {
+            let entity = test_client.entities().first().copied()?;
+            test_client.read::<Secret, _>(&entity, |secret| *secret.code)
+        })
+        .collect()
+}
+
+/// Records the inserts & removes of a Client's Secret
+fn record(step: &mut Step, client_index: usize, actions: &mut Vec<&'static str>) {
+    let events = step.client(client_index);
+    actions.extend(
+        events
+            .read::<InsertComponentEvent<Secret>>()
+            .map(|_| "insert"),
+    );
+    actions.extend(
+        events
+            .read::<RemoveComponentEvent<Secret>>()
+            .map(|_| "remove"),
+    );
+}
+
+#[test]
+fn excluded_components_are_hidden_from_one_user() {
+    let mut scenario = Scenario::new(protocol);
+    let entity = scenario
+        .server
+        .spawn_entity(scenario.server_world.proxy_mut())
+        .insert_component(Position::new_complete(1))
+        .insert_component(Secret::new_complete(7))
+        .id();
+    let alice = scenario.connect("alice");
+    let bob = scenario.connect("bob");
+    scenario.run_until(|scenario, _| {
+        scenario.user_key("alice").is_some() && scenario.user_key("bob").is_some()
+    });
+    let alice_key = scenario.user_key("alice").unwrap();
+
+    // hidden from Alice before the Entity enters her scope
+    scenario
+        .server
+        .user_scope(&alice_key)
+        .exclude_component::<Secret>(&entity);
+    scenario.server.room_mut(&scenario.room).add_entity(&entity);
+    let mut actions = Vec::new();
+    scenario.run_until(|scenario, step| {
+        record(step, alice, &mut actions);
+        scenario.clients[bob].entities().len() == 1 && scenario.clients[alice].entities().len() == 1
+    });
+    scenario.run_for(50, |_, step| record(step, alice, &mut actions));
+    assert_eq!(secrets(&scenario), [None, Some(7)]);
+    let alice_entity = scenario.clients[alice].entities()[0];
+    assert!(scenario.clients[alice]
+        .read::<Position, _>(&alice_entity, |position| *position.x)
+        .is_some());
+
+    // revealed
+    scenario
+        .server
+        .user_scope(&alice_key)
+        .include_component::<Secret>(&entity);
+    scenario.run_for(100, |_, step| record(step, alice, &mut actions));
+    assert_eq!(secrets(&scenario), [Some(7), Some(7)]);
+
+    // hidden again, while Bob keeps receiving its updates
+    scenario
+        .server
+        .user_scope(&alice_key)
+        .exclude_component::<Secret>(&entity);
+    scenario.run_for(100, |_, step| record(step, alice, &mut actions));
+    *scenario
+        .entity_mut(&entity)
+        .component::<Secret>()
+        .unwrap()
+        .code = 9;
+    scenario.run_for(100, |_, step| record(step, alice, &mut actions));
+    assert_eq!(secrets(&scenario), [None, Some(9)]);
+
+    // and revealed with its current value
+    scenario
+        .server
+        .user_scope(&alice_key)
+        .include_component::<Secret>(&entity);
+    scenario.run_for(100, |_, step| record(step, alice, &mut actions));
+    assert_eq!(secrets(&scenario), [Some(9), Some(9)]);
+
+    assert_eq!(actions, ["insert", "remove", "insert"]);
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use naia_demo_world::WorldRefType;
+use naia_server::{InsertComponentEvent, SpawnEntityEvent};
+use naia_shared::{Property, Protocol, Replicate, ValidationResult};
+use naia_test::{Auth, Scenario};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .enable_client_authoritative_entities()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .build()
+}
+
+/// A Scenario whose Server rejects a negative Position, and clamps one past 10
+fn validating_scenario() -> Scenario {
+    Scenario::with_setup(protocol, |server| {
+        server.validate_component::<Position>(|_user_key, _entity, _current, proposed| {
+            if *proposed.x < 0 {
+                return ValidationResult::Reject;
+            }
+            if *proposed.x > 10 {
+                *proposed.x = 10;
+                return ValidationResult::Clamp;
+            }
+            ValidationResult::Accept
+        })
+    })
+}
+
+#[test]
+fn rejects_invalid_insert() {
+    let mut scenario = validating_scenario();
+    let charlie = scenario.connect("charlie");
+    scenario.run_until(|scenario, _| scenario.clients[charlie].client.is_connected());
+
+    let spawn = |scenario: &mut Scenario, x| {
+        let test_client = &mut scenario.clients[charlie];
+        test_client
+            .client
+            .spawn_entity(test_client.world.proxy_mut())
+            .insert_component(Position::new_complete(x))
+            .id()
+    };
+    let invalid_entity = spawn(&mut scenario, -1);
+    let valid_entity = spawn(&mut scenario, 1);
+
+    // done once the Client has been corrected
+    let mut server_entities = Vec::new();
+    let mut inserted = Vec::new();
+    scenario.run_until(|scenario, step| {
+        let events = &mut step.server_events;
+        server_entities.extend(events.read::<SpawnEntityEvent>().map(|(_, e)| e));
+        inserted.extend(
+            events
+                .read::<InsertComponentEvent<Position>>()
+                .map(|(_, e)| e),
+        );
+        let client_world = scenario.clients[charlie].world.proxy();
+        server_entities.len() == 2 && !client_world.has_component::<Position>(&invalid_entity)
+    });
+
+    // the Server has both Entities, but only the valid Position
+    let server_positions: Vec<Option<i16>> = server_entities
+        .iter()
+        .map(|entity| scenario.read::<Position, _>(entity, |position| *position.x))
+        .collect();
+    assert!(server_positions.contains(&None));
+    assert!(server_positions.contains(&Some(1)));
+    assert_eq!(inserted.len(), 1);
+
+    // and the Client has had the invalid Position removed
+    let client_world = scenario.clients[charlie].world.proxy();
+    assert!(!client_world.has_component::<Position>(&invalid_entity));
+    assert!(client_world.has_component::<Position>(&valid_entity));
+}
+
+#[test]
+fn accepts_clamps_and_rejects_updates() {
+    let mut scenario = validating_scenario();
+    let charlie = scenario.connect("charlie");
+    scenario.run_until(|scenario, _| scenario.clients[charlie].client.is_connected());
+
+    let client_entity = {
+        let test_client = &mut scenario.clients[charlie];
+        test_client
+            .client
+            .spawn_entity(test_client.world.proxy_mut())
+            .insert_component(Position::new_complete(1))
+            .id()
+    };
+    let mut server_entity = None;
+    scenario.run_until(|_, step| {
+        server_entity = step
+            .server_events
+            .read::<SpawnEntityEvent>()
+            .map(|(_, e)| e)
+            .next()
+            .or(server_entity);
+        server_entity.is_some()
+    });
+    let server_entity = server_entity.unwrap();
+    // let the spawn be acknowledged, so that the Client sends its updates
+    scenario.run_for(50, |_, _| {});
+
+    // sets the Client's Position, and returns the Positions on the Server &
+    // Client once they have settled
+    let mut update = |scenario: &mut Scenario, x: i16| {
+        *scenario.clients[charlie]
+            .entity_mut(&client_entity)
+            .component::<Position>()
+            .unwrap()
+            .x = x;
+        scenario.run_for(100, |_, _| {});
+        let server_x = scenario.read::<Position, _>(&server_entity, |position| *position.x);
+        let client_x =
+            scenario.clients[charlie].read::<Position, _>(&client_entity, |position| *position.x);
+        (server_x.unwrap(), client_x.unwrap())
+    };
+
+    // accepted
+    assert_eq!(update(&mut scenario, 5), (5, 5));
+    // clamped, and the Client corrected
+    assert_eq!(update(&mut scenario, 50), (10, 10));
+    // rejected, and the Client corrected
+    assert_eq!(update(&mut scenario, -3), (10, 10));
+    // and updates are still accepted afterwards
+    assert_eq!(update(&mut scenario, 7), (7, 7));
+}
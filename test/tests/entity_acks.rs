@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use naia_demo_world::Entity;
+use naia_server::{ComponentInsertAckedEvent, EntitySpawnAckedEvent, UserKey};
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario, Step};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+#[derive(Replicate)]
+pub struct Health {
+    pub value: Property<u8>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .add_component::<Health>()
+        .build()
+}
+
+/// The acknowledgements the Server has received
+#[derive(Default)]
+struct Acks {
+    spawns: Vec<(UserKey, Entity)>,
+    positions: Vec<(UserKey, Entity)>,
+    healths: Vec<(UserKey, Entity)>,
+}
+
+impl Acks {
+    fn collect(&mut self, step: &mut Step) {
+        let events = &mut step.server_events;
+        self.spawns.extend(events.read::<EntitySpawnAckedEvent>());
+        self.positions
+            .extend(events.read::<ComponentInsertAckedEvent<Position>>());
+        self.healths
+            .extend(events.read::<ComponentInsertAckedEvent<Health>>());
+    }
+}
+
+#[test]
+fn spawns_and_inserts_are_acknowledged_once() {
+    let mut scenario = Scenario::new(protocol);
+    let entity = scenario.spawn(Position::new_complete(1));
+    scenario.connect("alice");
+
+    // once the Entity has been spawned, insert another Component
+    let mut acks = Acks::default();
+    scenario.run_until(|_, step| {
+        acks.collect(step);
+        !acks.spawns.is_empty()
+    });
+    scenario
+        .entity_mut(&entity)
+        .insert_component(Health::new_complete(100));
+    scenario.run_until(|_, step| {
+        acks.collect(step);
+        !acks.healths.is_empty()
+    });
+    scenario.run_for(50, |_, step| acks.collect(step));
+
+    let user = scenario.user_key("alice").unwrap();
+    assert!(acks.spawns == [(user, entity)]);
+    // Components spawned along with the Entity are acknowledged with it
+    assert!(acks.positions == [(user, entity)]);
+    assert!(acks.healths == [(user, entity)]);
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use naia_client::{AuthorityGrantedEvent, AuthorityRevokedEvent};
+use naia_demo_world::{Entity, WorldMutType};
+use naia_server::EntitySpawnAckedEvent;
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .enable_entity_authority()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .build()
+}
+
+/// Sets the Position on the Server, and returns the Positions on the Server &
+/// Client once they have settled
+fn server_update(scenario: &mut Scenario, entity: &Entity, alice: usize, x: i16) -> (i16, i16) {
+    *scenario
+        .entity_mut(entity)
+        .component::<Position>()
+        .unwrap()
+        .x = x;
+    settle(scenario, entity, alice)
+}
+
+/// Like `server_update`, setting the Position on the Client
+fn client_update(scenario: &mut Scenario, entity: &Entity, alice: usize, x: i16) -> (i16, i16) {
+    let client_entity = scenario.clients[alice].entities()[0];
+    *scenario.clients[alice]
+        .world
+        .proxy_mut()
+        .component_mut::<Position>(&client_entity)
+        .unwrap()
+        .x = x;
+    settle(scenario, entity, alice)
+}
+
+fn settle(scenario: &mut Scenario, entity: &Entity, alice: usize) -> (i16, i16) {
+    scenario.run_for(100, |_, _| {});
+    let client_entity = scenario.clients[alice].entities()[0];
+    let server_x = scenario.read::<Position, _>(entity, |position| *position.x);
+    let client_x =
+        scenario.clients[alice].read::<Position, _>(&client_entity, |position| *position.x);
+    (server_x.unwrap(), client_x.unwrap())
+}
+
+#[test]
+fn server_updates_reach_the_holder_during_and_after_authority() {
+    let mut scenario = Scenario::new(protocol);
+    let entity = scenario.spawn(Position::new_complete(0));
+    let alice = scenario.connect("alice");
+    scenario.run_until(|_, step| step.server_events.read::<EntitySpawnAckedEvent>().count() > 0);
+    let user_key = scenario.user_key("alice").unwrap();
+
+    // granted
+    scenario.server.grant_authority(&user_key, &entity);
+    scenario.run_until(|_, step| step.client(alice).read::<AuthorityGrantedEvent>().count() > 0);
+    assert_eq!(client_update(&mut scenario, &entity, alice, 3), (3, 3));
+    assert_eq!(server_update(&mut scenario, &entity, alice, 5), (5, 5));
+    assert_eq!(client_update(&mut scenario, &entity, alice, 7), (7, 7));
+
+    // revoked
+    scenario.server.revoke_authority(&entity);
+    scenario.run_until(|_, step| step.client(alice).read::<AuthorityRevokedEvent>().count() > 0);
+    assert_eq!(server_update(&mut scenario, &entity, alice, 9), (9, 9));
+
+    // granted again, and then released
+    scenario.server.grant_authority(&user_key, &entity);
+    scenario.run_until(|_, step| step.client(alice).read::<AuthorityGrantedEvent>().count() > 0);
+    assert_eq!(client_update(&mut scenario, &entity, alice, 11), (11, 11));
+    let client_entity = scenario.clients[alice].entities()[0];
+    scenario.clients[alice]
+        .client
+        .release_authority(&client_entity);
+    assert_eq!(server_update(&mut scenario, &entity, alice, 13), (13, 13));
+    assert!(scenario.server.entity_authority(&entity).is_none());
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario, TestClient};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .enable_entity_hierarchy()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .build()
+}
+
+/// The positions of every Entity a Client has, in order
+fn positions(client: &TestClient) -> Vec<i16> {
+    let mut positions: Vec<i16> = client
+        .entities()
+        .iter()
+        .filter_map(|entity| client.read::<Position, _>(entity, |position| *position.x))
+        .collect();
+    positions.sort();
+    positions
+}
+
+#[test]
+fn orphan_stays_only_in_scope_of_its_own_room() {
+    // the parent is in the Room every User joins, the child in a Room with
+    // only the first
+    let mut scenario = Scenario::new(protocol);
+    let own_room = scenario.server.make_room().key();
+    let parent = scenario.spawn(Position::new_complete(1));
+    let child = scenario
+        .server
+        .spawn_entity(scenario.server_world.proxy_mut())
+        .insert_component(Position::new_complete(2))
+        .id();
+    scenario.server.room_mut(&own_room).add_entity(&child);
+    scenario.server.set_entity_parent(&child, &parent);
+
+    let alice = scenario.connect("alice");
+    let bob = scenario.connect("bob");
+
+    // both Clients have the child through its parent, then detach it
+    scenario.run_until(|scenario, step| {
+        if let Some(alice_key) = scenario.user_key("alice") {
+            if step.connected.contains(&alice_key) {
+                scenario.server.room_mut(&own_room).add_user(&alice_key);
+            }
+        }
+        positions(&scenario.clients[alice]) == [1, 2] && positions(&scenario.clients[bob]) == [1, 2]
+    });
+    scenario.server.remove_entity_parent(&child);
+    scenario.run_for(50, |_, _| {});
+
+    assert!(scenario.server.entity_parent(&child).is_none());
+    // the first User shares the child's Room, the second only its parent's
+    assert_eq!(positions(&scenario.clients[alice]), [1, 2]);
+    assert_eq!(positions(&scenario.clients[bob]), [1]);
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use naia_demo_world::{Entity, WorldMutType};
+use naia_server::SpawnEntityEvent;
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .enable_client_authoritative_entities()
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .build()
+}
+
+/// Sets the owning Client's Position, and returns the Positions on the Server
+/// & the other Client once they have settled
+fn update(scenario: &mut Scenario, entity: &Entity, x: i16) -> (Option<i16>, Option<i16>) {
+    let [alice, bob] = [0, 1];
+    let client_entity = scenario.clients[alice].entities()[0];
+    *scenario.clients[alice]
+        .world
+        .proxy_mut()
+        .component_mut::<Position>(&client_entity)
+        .unwrap()
+        .x = x;
+    scenario.run_for(100, |_, _| {});
+
+    let server_x = scenario.read::<Position, _>(entity, |position| *position.x);
+    let bob_x = scenario.clients[bob].entities().first().and_then(|entity| {
+        scenario.clients[bob].read::<Position, _>(entity, |position| *position.x)
+    });
+    (server_x, bob_x)
+}
+
+#[test]
+fn published_entity_relays_owner_updates() {
+    let mut scenario = Scenario::new(protocol);
+    let alice = scenario.connect("alice");
+    let bob = scenario.connect("bob");
+    scenario.run_until(|scenario, _| {
+        scenario
+            .clients
+            .iter()
+            .all(|test_client| test_client.client.is_connected())
+    });
+
+    let test_client = &mut scenario.clients[alice];
+    test_client
+        .client
+        .spawn_entity(test_client.world.proxy_mut())
+        .insert_component(Position::new_complete(1));
+    let mut entity = None;
+    scenario.run_until(|_, step| {
+        entity = step
+            .server_events
+            .read::<SpawnEntityEvent>()
+            .map(|(_, entity)| entity)
+            .next();
+        entity.is_some()
+    });
+    let entity = entity.unwrap();
+    scenario.server.room_mut(&scenario.room).add_entity(&entity);
+
+    // published, Bob receives the Entity & its updates
+    scenario.entity_mut(&entity).publish();
+    scenario.run_until(|scenario, _| scenario.clients[bob].entities().len() == 1);
+    // let the spawn be acknowledged, so that Alice sends her updates
+    scenario.run_for(50, |_, _| {});
+    assert_eq!(update(&mut scenario, &entity, 5), (Some(5), Some(5)));
+
+    // unpublished, only the Server receives updates
+    scenario.entity_mut(&entity).unpublish();
+    assert_eq!(update(&mut scenario, &entity, 7), (Some(7), None));
+
+    // and published again
+    scenario.entity_mut(&entity).publish();
+    scenario.run_until(|scenario, _| scenario.clients[bob].entities().len() == 1);
+    assert_eq!(update(&mut scenario, &entity, 9), (Some(9), Some(9)));
+}
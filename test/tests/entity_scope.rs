@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use naia_server::{DisconnectEvent, EntityScopeEnterEvent, EntityScopeExitEvent};
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Position>()
+        .build()
+}
+
+#[test]
+fn entities_enter_and_exit_scope() {
+    let mut scenario = Scenario::new(protocol);
+    let removed = scenario.spawn(Position::new_complete(1));
+    let kept = scenario.spawn(Position::new_complete(2));
+    let alice = scenario.connect("alice");
+
+    let mut entered = Vec::new();
+    let mut exited = Vec::new();
+    // returns whether the User disconnected
+    let mut watch = |step: &mut naia_test::Step| {
+        let events = &mut step.server_events;
+        entered.extend(events.read::<EntityScopeEnterEvent>().map(|(_, e)| e));
+        exited.extend(events.read::<EntityScopeExitEvent>().map(|(_, e)| e));
+        events.read::<DisconnectEvent>().count() > 0
+    };
+
+    // once both Entities have been spawned, one leaves the Room, and once it
+    // has been despawned the Client leaves
+    scenario.run_until(|scenario, step| {
+        watch(step);
+        scenario.clients[alice].entities().len() == 2
+    });
+    scenario
+        .server
+        .room_mut(&scenario.room)
+        .remove_entity(&removed);
+    scenario.run_until(|scenario, step| {
+        watch(step);
+        scenario.clients[alice].entities().len() == 1
+    });
+    scenario.disconnect(alice);
+    scenario.run_until(|_, step| watch(step));
+
+    assert_eq!(entered.len(), 2);
+    assert!(entered.contains(&removed) && entered.contains(&kept));
+    // the Entity left in the Room exits scope along with its User
+    assert!(exited == [removed, kept]);
+}
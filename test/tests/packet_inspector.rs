@@ -0,0 +1,373 @@
+use std::time::Duration;
+
+use naia_client::{ClientTickEvent, ConnectEvent as ClientConnectEvent};
+use naia_server::{ComponentInsertAckedEvent, EntitySpawnAckedEvent};
+use naia_shared::{
+    default_channels::{TickBufferedChannel, UnorderedReliableChannel},
+    BitWriter, ChannelKind, DiffMask, EntityActionType, FakeEntityConverter, GameInstant, HostType,
+    InspectedAction, InspectedPacket, Instant, LocalEntity, Message, MessageIndex, PacketInspector,
+    PacketType, Property, Protocol, Replicate, Serde, StandardHeader, Tick,
+    UnsignedVariableInteger, MTU_SIZE_BITS,
+};
+use naia_test::{Auth, CaptureBuffer, Scenario};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+    pub y: Property<i16>,
+}
+
+#[derive(Replicate)]
+pub struct Health {
+    pub value: Property<u8>,
+}
+
+#[derive(Message)]
+pub struct Greeting {
+    pub text: String,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_message::<Greeting>()
+        .add_component::<Position>()
+        .add_component::<Health>()
+        .build()
+}
+
+#[test]
+fn inspects_server_data_packet() {
+    let protocol = protocol();
+    let mut writer = BitWriter::new();
+
+    // header, acking packets 5, 4 & 2
+    StandardHeader::new(PacketType::Data, 7, 5, 0b101).ser(&mut writer);
+    (100 as Tick).ser(&mut writer);
+    GameInstant::new(&Instant::now()).ser(&mut writer);
+
+    // messages
+    true.ser(&mut writer);
+    ChannelKind::of::<UnorderedReliableChannel>().ser(&protocol.channel_kinds, &mut writer);
+    for index in [3, 4] {
+        true.ser(&mut writer);
+        if index == 3 {
+            (index as MessageIndex).ser(&mut writer);
+        } else {
+            UnsignedVariableInteger::<3>::new(1).ser(&mut writer);
+        }
+        Auth::new("charlie", "1234567").write(
+            &protocol.message_kinds,
+            &mut writer,
+            &mut FakeEntityConverter,
+        );
+    }
+    false.ser(&mut writer);
+    false.ser(&mut writer);
+
+    // updates, of only the `y` field
+    let position = Position::new_complete(1, 2);
+    true.ser(&mut writer);
+    LocalEntity::new_host(2).host_ser(&mut writer);
+    true.ser(&mut writer);
+    position.kind().ser(&protocol.component_kinds, &mut writer);
+    let mut diff_mask = DiffMask::new(1);
+    diff_mask.set_bit(1, true);
+    position.write_update(&diff_mask, &mut writer, &mut FakeEntityConverter);
+    false.ser(&mut writer);
+    false.ser(&mut writer);
+
+    // actions
+    true.ser(&mut writer);
+    (9 as MessageIndex).ser(&mut writer);
+    EntityActionType::SpawnEntity.ser(&mut writer);
+    LocalEntity::new_host(3).host_ser(&mut writer);
+    UnsignedVariableInteger::<3>::new(1).ser(&mut writer);
+    position.write(
+        &protocol.component_kinds,
+        &mut writer,
+        &mut FakeEntityConverter,
+    );
+    false.ser(&mut writer);
+
+    let bytes = writer.to_bytes();
+    let packet = PacketInspector::new(&protocol)
+        .inspect(HostType::Server, &bytes)
+        .unwrap();
+
+    assert!(!packet.malformed);
+    assert_eq!(packet.acks, [5, 4, 2]);
+    assert_eq!(packet.tick, Some(100));
+
+    let messages: Vec<(&str, Option<MessageIndex>, &str)> = packet
+        .messages
+        .iter()
+        .map(|message| {
+            (
+                message.channel.as_str(),
+                message.index,
+                message.name.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            ("UnorderedReliableChannel", Some(3), "Auth"),
+            ("UnorderedReliableChannel", Some(4), "Auth")
+        ]
+    );
+
+    assert_eq!(packet.updates.len(), 1);
+    assert_eq!(packet.updates[0].entity.value(), 2);
+    assert_eq!(packet.updates[0].component, "Position");
+    assert_eq!(packet.updates[0].fields, ["y"]);
+
+    assert!(matches!(
+        packet.actions.as_slice(),
+        [InspectedAction::SpawnEntity(9, entity, components)]
+            if entity.value() == 3 && components == &["Position"]
+    ));
+}
+
+#[test]
+fn inspects_malformed_client_packet() {
+    let protocol = protocol();
+    let mut writer = BitWriter::new();
+
+    StandardHeader::new(PacketType::Data, 1, 0, 0).ser(&mut writer);
+    (50 as Tick).ser(&mut writer);
+
+    // a tick-buffered message for tick 48
+    true.ser(&mut writer);
+    ChannelKind::of::<TickBufferedChannel>().ser(&protocol.channel_kinds, &mut writer);
+    true.ser(&mut writer);
+    UnsignedVariableInteger::<3>::new(2).ser(&mut writer);
+    UnsignedVariableInteger::<3>::new(1).ser(&mut writer);
+    UnsignedVariableInteger::<2>::new(0).ser(&mut writer);
+    Auth::new("charlie", "1234567").write(
+        &protocol.message_kinds,
+        &mut writer,
+        &mut FakeEntityConverter,
+    );
+    false.ser(&mut writer);
+    false.ser(&mut writer);
+
+    // a message on a channel the Protocol doesn't have
+    let valid_bits = MTU_SIZE_BITS - writer.bits_free();
+    true.ser(&mut writer);
+    999_u16.ser(&mut writer);
+
+    let bytes = writer.to_bytes();
+    let packet = PacketInspector::new(&protocol)
+        .inspect(HostType::Client, &bytes)
+        .unwrap();
+
+    assert!(packet.malformed);
+    assert_eq!(packet.bits_read, valid_bits);
+    assert_eq!(packet.tick, Some(50));
+    assert_eq!(packet.tick_buffered_messages.len(), 1);
+    let message = &packet.tick_buffered_messages[0];
+    assert_eq!(message.channel, "TickBufferedChannel");
+    assert_eq!(message.tick, Some(48));
+    assert_eq!(message.name, "Auth");
+    assert!(packet.messages.is_empty());
+}
+
+// Round trips through the real writers
+
+/// Every packet sent by each host during a session, inspected
+struct Session {
+    server_packets: Vec<InspectedPacket>,
+    client_packets: Vec<InspectedPacket>,
+    /// The tick the Client's tick-buffered Greeting was sent for
+    buffered_tick: Tick,
+}
+
+/// The payloads of the packets a host sent
+fn outgoing_payloads(capture: &CaptureBuffer) -> Vec<Box<[u8]>> {
+    capture
+        .read()
+        .outgoing()
+        .map(|packet| packet.payload.clone())
+        .collect()
+}
+
+/// Runs a session in which both hosts greet each other, the Client sends a
+/// tick-buffered Greeting, and the Server spawns an Entity, updates it,
+/// inserts & removes a Component on it, and then despawns it
+fn run_session() -> Session {
+    let server_capture = CaptureBuffer::default();
+    let client_capture = CaptureBuffer::default();
+
+    let mut scenario = Scenario::new(protocol);
+    scenario
+        .server
+        .start_capture(server_capture.clone())
+        .unwrap();
+    let entity = scenario.spawn(Position::new_complete(1, 2));
+    let charlie = scenario.connect("charlie");
+    scenario.clients[charlie]
+        .client
+        .start_capture(client_capture.clone())
+        .unwrap();
+
+    let greeting = Greeting {
+        text: "hello".to_string(),
+    };
+    let mut buffered_tick = None;
+    let mut despawn_countdown: Option<u32> = None;
+    scenario.run_until(|scenario, step| {
+        for user_key in &step.connected {
+            scenario
+                .server
+                .send_message::<UnorderedReliableChannel, _>(user_key, &greeting);
+        }
+        let events = &mut step.server_events;
+        if events.read::<EntitySpawnAckedEvent>().count() > 0 {
+            let mut entity_mut = scenario.entity_mut(&entity);
+            *entity_mut.component::<Position>().unwrap().x = 3;
+            entity_mut.insert_component(Health::new_complete(100));
+        }
+        if events.read::<ComponentInsertAckedEvent<Health>>().count() > 0 {
+            scenario.entity_mut(&entity).remove_component::<Health>();
+            despawn_countdown = Some(50);
+        }
+
+        let events = step.client(charlie);
+        if events.read::<ClientConnectEvent>().count() > 0 {
+            scenario.clients[charlie]
+                .client
+                .send_message::<UnorderedReliableChannel, _>(&greeting);
+        }
+        for tick in events.read::<ClientTickEvent>() {
+            if buffered_tick.is_none() {
+                scenario.clients[charlie]
+                    .client
+                    .send_tick_buffer_message::<TickBufferedChannel, _>(&tick, &greeting);
+                buffered_tick = Some(tick);
+            }
+        }
+
+        // despawning in the same tick would take the place of the removal
+        let Some(countdown) = despawn_countdown.as_mut() else {
+            return false;
+        };
+        *countdown -= 1;
+        if *countdown > 0 {
+            return false;
+        }
+        scenario.entity_mut(&entity).despawn();
+        true
+    });
+    scenario.run_for(50, |_, _| {});
+    scenario.server.stop_capture();
+    scenario.clients[charlie].client.stop_capture();
+
+    let protocol = protocol();
+    let inspector = PacketInspector::new(&protocol);
+    let server_packets: Vec<InspectedPacket> = outgoing_payloads(&server_capture)
+        .iter()
+        .map(|payload| inspector.inspect(HostType::Server, payload).unwrap())
+        .collect();
+    let client_packets: Vec<InspectedPacket> = outgoing_payloads(&client_capture)
+        .iter()
+        .map(|payload| inspector.inspect(HostType::Client, payload).unwrap())
+        .collect();
+    for packet in server_packets.iter().chain(client_packets.iter()) {
+        assert!(!packet.malformed, "malformed packet:\n{}", packet);
+    }
+
+    Session {
+        server_packets,
+        client_packets,
+        buffered_tick: buffered_tick.unwrap(),
+    }
+}
+
+#[test]
+fn inspects_messages_from_the_real_writers() {
+    let session = run_session();
+
+    for packets in [&session.server_packets, &session.client_packets] {
+        let greetings: Vec<&str> = packets
+            .iter()
+            .flat_map(|packet| packet.messages.iter())
+            .filter(|message| message.name == "Greeting")
+            .map(|message| message.channel.as_str())
+            .collect();
+        assert!(!greetings.is_empty());
+        assert!(greetings
+            .iter()
+            .all(|channel| *channel == "UnorderedReliableChannel"));
+    }
+}
+
+#[test]
+fn inspects_tick_buffered_messages_from_the_real_writers() {
+    let session = run_session();
+
+    let messages: Vec<(&str, Option<Tick>, &str)> = session
+        .client_packets
+        .iter()
+        .flat_map(|packet| packet.tick_buffered_messages.iter())
+        .map(|message| {
+            (
+                message.channel.as_str(),
+                message.tick,
+                message.name.as_str(),
+            )
+        })
+        .collect();
+    assert!(!messages.is_empty());
+    assert!(messages.iter().all(|message| *message
+        == (
+            "TickBufferedChannel",
+            Some(session.buffered_tick),
+            "Greeting"
+        )));
+}
+
+#[test]
+fn inspects_entity_actions_from_the_real_writers() {
+    let session = run_session();
+
+    let actions: Vec<&InspectedAction> = session
+        .server_packets
+        .iter()
+        .flat_map(|packet| packet.actions.iter())
+        .collect();
+    assert!(actions.iter().any(|action| matches!(
+        action,
+        InspectedAction::SpawnEntity(_, _, components) if components == &["Position"]
+    )));
+    assert!(actions.iter().any(|action| matches!(
+        action,
+        InspectedAction::InsertComponent(_, _, component) if component == "Health"
+    )));
+    assert!(actions.iter().any(|action| matches!(
+        action,
+        InspectedAction::RemoveComponent(_, _, component) if component == "Health"
+    )));
+    assert!(actions
+        .iter()
+        .any(|action| matches!(action, InspectedAction::DespawnEntity(_, _))));
+}
+
+#[test]
+fn inspects_component_updates_from_the_real_writers() {
+    let session = run_session();
+
+    let updates: Vec<(&str, &[String])> = session
+        .server_packets
+        .iter()
+        .flat_map(|packet| packet.updates.iter())
+        .map(|update| (update.component.as_str(), update.fields.as_slice()))
+        .collect();
+    assert!(!updates.is_empty());
+    assert!(updates
+        .iter()
+        .all(|(component, fields)| *component == "Position" && *fields == ["x"]));
+}
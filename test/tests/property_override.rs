@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use naia_server::EntitySpawnAckedEvent;
+use naia_shared::{Property, Protocol, Replicate};
+use naia_test::{Auth, Scenario, TestClient};
+
+#[derive(Replicate)]
+pub struct Stats {
+    pub health: Property<u8>,
+    pub level: Property<u8>,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_component::<Stats>()
+        .build()
+}
+
+/// The health & level of the only Entity a Client has, if any
+fn stats(client: &TestClient) -> Option<(u8, u8)> {
+    let entity = *client.entities().first()?;
+    client.read::<Stats, _>(&entity, |stats| (*stats.health, *stats.level))
+}
+
+#[test]
+fn overridden_properties_differ_per_user() {
+    let mut scenario = Scenario::new(protocol);
+    let entity = scenario.spawn(Stats::new_complete(100, 1));
+    let alice = scenario.connect("alice");
+    let bob = scenario.connect("bob");
+
+    // only the first User sees their health halved
+    scenario.run_until(|scenario, step| {
+        let Some(alice_key) = scenario.user_key("alice") else {
+            return false;
+        };
+        if !step.connected.contains(&alice_key) {
+            return false;
+        }
+        scenario
+            .server
+            .set_property_override::<Stats>(&alice_key, &entity, |stats| {
+                *stats.health /= 2;
+            });
+        true
+    });
+
+    // once both Clients have the Entity, change a Property which isn't
+    // overridden
+    let mut spawn_acks = 0;
+    scenario.run_until(|_, step| {
+        spawn_acks += step.server_events.read::<EntitySpawnAckedEvent>().count();
+        spawn_acks == 2
+    });
+    *scenario
+        .entity_mut(&entity)
+        .component::<Stats>()
+        .unwrap()
+        .level = 2;
+    scenario.run_for(50, |_, _| {});
+    assert_eq!(stats(&scenario.clients[alice]), Some((50, 2)));
+    assert_eq!(stats(&scenario.clients[bob]), Some((100, 2)));
+
+    // without the override, the first User follows the Server's values again
+    let alice_key = scenario.user_key("alice").unwrap();
+    scenario
+        .server
+        .clear_property_override::<Stats>(&alice_key, &entity);
+    scenario.run_for(50, |_, _| {});
+    assert_eq!(stats(&scenario.clients[alice]), Some((100, 2)));
+    assert_eq!(stats(&scenario.clients[bob]), Some((100, 2)));
+}
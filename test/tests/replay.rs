@@ -0,0 +1,219 @@
+use std::{thread, time::Duration};
+
+use naia_client::Client;
+use naia_demo_world::{Entity, World};
+use naia_server::{RoomKey, Server, ServerConfig};
+use naia_shared::{
+    default_channels::UnorderedReliableChannel, Message, Property, Protocol, Replicate,
+};
+use naia_test::{quick_client_config, Auth, CaptureBuffer, LocalTransport, SimulatedClock};
+
+#[derive(Replicate)]
+pub struct Position {
+    pub x: Property<i16>,
+    pub y: Property<i16>,
+}
+
+#[derive(Message)]
+pub struct Greeting {
+    pub text: String,
+}
+
+fn protocol() -> Protocol {
+    Protocol::builder()
+        .tick_interval(Duration::from_millis(10))
+        .add_default_channels()
+        .add_message::<Auth>()
+        .add_message::<Greeting>()
+        .add_component::<Position>()
+        .build()
+}
+
+/// A Server which accepts Users into a Room, and moves an Entity in it to the
+/// right each tick once a Client has it, up to x = 20
+struct ServerApp {
+    server: Server<Entity>,
+    world: World,
+    room_key: RoomKey,
+    entity: Entity,
+    moving: bool,
+    greetings: Vec<String>,
+}
+
+impl ServerApp {
+    fn new() -> Self {
+        let mut server = Server::new(ServerConfig::default(), protocol());
+        let mut world = World::default();
+        let room_key = server.make_room().key();
+        let entity = server
+            .spawn_entity(world.proxy_mut())
+            .insert_component(Position::new_complete(0, 7))
+            .id();
+        server.room_mut(&room_key).add_entity(&entity);
+
+        Self {
+            server,
+            world,
+            room_key,
+            entity,
+            moving: false,
+            greetings: Vec::new(),
+        }
+    }
+
+    fn update(&mut self) {
+        let mut events = self.server.receive(self.world.proxy_mut());
+        for (user_key, auth) in events.read::<naia_server::AuthEvent<Auth>>() {
+            if auth.username == "charlie" && auth.password == "1234567" {
+                self.server.accept_connection(&user_key);
+            } else {
+                self.server.reject_connection(&user_key);
+            }
+        }
+        for user_key in events.read::<naia_server::ConnectEvent>() {
+            self.server.room_mut(&self.room_key).add_user(&user_key);
+        }
+        if events.has::<naia_server::EntitySpawnAckedEvent>() {
+            self.moving = true;
+        }
+        for (_, greeting) in
+            events.read::<naia_server::MessageEvent<UnorderedReliableChannel, Greeting>>()
+        {
+            self.greetings.push(greeting.text);
+        }
+        for _ in events.read::<naia_server::TickEvent>() {
+            if let Some(mut position) = self
+                .server
+                .entity_mut(self.world.proxy_mut(), &self.entity)
+                .component::<Position>()
+            {
+                if self.moving && *position.x < 20 {
+                    *position.x += 1;
+                }
+            }
+            for (_, user_key, entity) in self.server.scope_checks() {
+                self.server.user_scope(&user_key).include(&entity);
+            }
+            self.server.send_all_updates(self.world.proxy());
+        }
+    }
+}
+
+/// A Client which greets the Server once connected, and follows the
+/// Server's Entities
+struct ClientApp {
+    client: Client<Entity>,
+    world: World,
+}
+
+impl ClientApp {
+    fn new() -> Self {
+        let mut client = Client::new(quick_client_config(), protocol());
+        client.auth(Auth::new("charlie", "1234567"));
+        Self {
+            client,
+            world: World::default(),
+        }
+    }
+
+    fn update(&mut self) {
+        let mut events = self.client.receive(self.world.proxy_mut());
+        for _ in events.read::<naia_client::ConnectEvent>() {
+            self.client
+                .send_message::<UnorderedReliableChannel, _>(&Greeting {
+                    text: "hello".to_string(),
+                });
+        }
+    }
+
+    /// The positions of every Entity the Client has
+    fn positions(&self) -> Vec<(i16, i16)> {
+        self.client
+            .entities(&self.world.proxy())
+            .iter()
+            .filter_map(|entity| {
+                self.client
+                    .entity(self.world.proxy(), entity)
+                    .component::<Position>()
+                    .map(|position| (*position.x, *position.y))
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn replays_a_session_to_the_same_state() {
+    // record a session
+    let mut clock = SimulatedClock::new();
+    let transport = LocalTransport::new();
+    let server_capture = CaptureBuffer::default();
+    let client_capture = CaptureBuffer::default();
+
+    let mut server_app = ServerApp::new();
+    server_app.server.listen(transport.server_socket());
+    server_app
+        .server
+        .start_capture(server_capture.clone())
+        .unwrap();
+
+    let mut client_app = ClientApp::new();
+    client_app
+        .client
+        .start_capture(client_capture.clone())
+        .unwrap();
+    client_app.client.connect(transport.client_socket());
+
+    // run until the Entity has stopped & the greeting arrived, and then a
+    // little longer, so the capture ends in a settled state
+    let mut settling = 100;
+    for _ in 0..5000 {
+        server_app.update();
+        client_app.update();
+        let moved = client_app.positions() == [(20, 7)];
+        if moved && !server_app.greetings.is_empty() {
+            settling -= 1;
+            if settling == 0 {
+                break;
+            }
+        }
+        clock.advance(Duration::from_millis(1));
+    }
+    server_app.server.stop_capture();
+    client_app.client.stop_capture();
+
+    let greetings = server_app.greetings.clone();
+    let positions = client_app.positions();
+    assert_eq!(greetings, ["hello"]);
+    assert_eq!(positions, [(20, 7)]);
+
+    // replay each host on its own thread, as each replay simulates the clock
+    // of the thread it runs on
+    let server_capture = server_capture.read();
+    let replayed_greetings = thread::spawn(move || {
+        let passes = server_capture.packets().len() * 2;
+        let mut server_app = ServerApp::new();
+        server_app.server.replay(server_capture);
+        for _ in 0..passes {
+            server_app.update();
+        }
+        server_app.greetings
+    })
+    .join()
+    .unwrap();
+    assert_eq!(replayed_greetings, greetings);
+
+    let client_capture = client_capture.read();
+    let (connected, replayed_positions) = thread::spawn(move || {
+        let passes = client_capture.packets().len() * 2;
+        let mut client_app = ClientApp::new();
+        client_app.client.replay(client_capture);
+        for _ in 0..passes {
+            client_app.update();
+        }
+        (client_app.client.is_connected(), client_app.positions())
+    })
+    .join()
+    .unwrap();
+    assert!(connected);
+    assert_eq!(replayed_positions, positions);
+}
@@ -0,0 +1,25 @@
+use naia_server::{
+    transport::udp::{Socket, SocketOptions},
+    Server, ServerConfig,
+};
+use naia_shared::Protocol;
+
+#[test]
+fn reports_the_ports_bound() {
+    let socket = Socket::bind(
+        &["127.0.0.1:0".parse().unwrap()],
+        &SocketOptions::default(),
+        None,
+    )
+    .unwrap();
+    let socket_addrs = socket.local_addrs().unwrap();
+
+    let mut server = Server::<u32>::new(ServerConfig::default(), Protocol::builder().build());
+    assert!(server.local_addrs().is_empty());
+    server.listen(socket);
+
+    let local_addrs = server.local_addrs();
+    assert_eq!(local_addrs, socket_addrs);
+    assert_eq!(local_addrs.len(), 1);
+    assert_ne!(local_addrs[0].port(), 0);
+}